@@ -29,7 +29,7 @@ async fn main() -> anyhow::Result<()> {
     println!("Randomly selected file: {}", random_file_name);
 
     let random_file_read = files_client
-        .read(&format!("{}/{}", home_dir_path, random_file_name))
+        .read(format!("{}/{}", home_dir_path, random_file_name))
         .build()
         .await?;
     println!("Random file contents:\n{}", random_file_read.data());
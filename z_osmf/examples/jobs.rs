@@ -30,10 +30,7 @@ async fn main() -> anyhow::Result<()> {
         .iter()
         .choose(&mut rng)
         .context("failed to randomly select a job identifier")?;
-    println!(
-        "Random Job Identifier: {}",
-        random_job_identifier.to_string()
-    );
+    println!("Random Job Identifier: {}", random_job_identifier);
 
     let jcl_read = jobs_client
         .read_file(random_job_identifier.clone(), JobFileId::Jcl)
@@ -0,0 +1,204 @@
+use std::time::Duration;
+
+use crate::{Result, ZOsmf};
+
+/// # ZOsmfBuilder
+///
+/// Configures the HTTP client used by a [`ZOsmf`], for callers that do not need
+/// to assemble a [`reqwest::Client`] themselves.
+///
+/// ```
+/// # async fn example() -> anyhow::Result<()> {
+/// # use std::time::Duration;
+/// # use z_osmf::ZOsmf;
+/// let zosmf = ZOsmf::builder("https://zosmf.mainframe.my-company.com")
+///     .timeout(Duration::from_secs(30))
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct ZOsmfBuilder {
+    url: String,
+    timeout: Option<Duration>,
+    connect_timeout: Option<Duration>,
+    #[cfg(feature = "rustls-tls")]
+    root_certificates: Vec<Vec<u8>>,
+    #[cfg(feature = "rustls-tls")]
+    accept_invalid_certs: bool,
+    #[cfg(feature = "rustls-tls")]
+    identity: Option<Vec<u8>>,
+}
+
+impl ZOsmfBuilder {
+    pub(crate) fn new<U>(url: U) -> Self
+    where
+        U: std::fmt::Display,
+    {
+        ZOsmfBuilder {
+            url: url.to_string(),
+            timeout: None,
+            connect_timeout: None,
+            #[cfg(feature = "rustls-tls")]
+            root_certificates: Vec::new(),
+            #[cfg(feature = "rustls-tls")]
+            accept_invalid_certs: false,
+            #[cfg(feature = "rustls-tls")]
+            identity: None,
+        }
+    }
+
+    /// Set the timeout for each request, from connecting until the response body is read.
+    pub fn timeout(mut self, value: Duration) -> Self {
+        self.timeout = Some(value);
+
+        self
+    }
+
+    /// Set the timeout for establishing a connection.
+    pub fn connect_timeout(mut self, value: Duration) -> Self {
+        self.connect_timeout = Some(value);
+
+        self
+    }
+
+    /// Trust an additional root certificate, in PEM or DER format.
+    ///
+    /// Use this when the z/OSMF certificate is issued by a private certificate authority.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn example() -> anyhow::Result<()> {
+    /// # use z_osmf::ZOsmf;
+    /// let zosmf = ZOsmf::builder("https://zosmf.mainframe.my-company.com")
+    ///     .add_root_certificate(std::fs::read("my-company-ca.pem")?)
+    ///     .build()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "rustls-tls")]
+    pub fn add_root_certificate<C>(mut self, certificate: C) -> Self
+    where
+        C: Into<Vec<u8>>,
+    {
+        self.root_certificates.push(certificate.into());
+
+        self
+    }
+
+    /// Skip validation of the server certificate.
+    ///
+    /// <p style="background:rgba(255,181,77,0.16);padding:0.75em;">
+    /// <strong>Warning:</strong> Any server can then impersonate z/OSMF and receive your credentials.
+    /// Only use this with test systems.
+    /// </p>
+    #[cfg(feature = "rustls-tls")]
+    pub fn danger_accept_invalid_certs(mut self, value: bool) -> Self {
+        self.accept_invalid_certs = value;
+
+        self
+    }
+
+    /// Present a client certificate when connecting, for z/OSMF deployments that map
+    /// certificates to users. Authenticate with [`ZOsmf::login_with_certificate`].
+    ///
+    /// The identity is PEM encoded and holds the certificate, any intermediate
+    /// certificates and the unencrypted private key.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn example() -> anyhow::Result<()> {
+    /// # use z_osmf::ZOsmf;
+    /// let zosmf = ZOsmf::builder("https://zosmf.mainframe.my-company.com")
+    ///     .identity(std::fs::read("ibmuser.pem")?)
+    ///     .build()?;
+    ///
+    /// zosmf.login_with_certificate().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "rustls-tls")]
+    pub fn identity<I>(mut self, identity: I) -> Self
+    where
+        I: Into<Vec<u8>>,
+    {
+        self.identity = Some(identity.into());
+
+        self
+    }
+
+    /// Build the z/OSMF client.
+    ///
+    /// Fails with [`Error::Reqwest`](crate::Error::Reqwest) if a certificate or the identity
+    /// cannot be parsed.
+    pub fn build(self) -> Result<ZOsmf> {
+        let mut client_builder = reqwest::Client::builder();
+
+        if let Some(timeout) = self.timeout {
+            client_builder = client_builder.timeout(timeout);
+        }
+
+        if let Some(connect_timeout) = self.connect_timeout {
+            client_builder = client_builder.connect_timeout(connect_timeout);
+        }
+
+        #[cfg(feature = "rustls-tls")]
+        {
+            client_builder = client_builder
+                .use_rustls_tls()
+                .danger_accept_invalid_certs(self.accept_invalid_certs);
+
+            for certificate in self.root_certificates.iter() {
+                let certificate = if certificate.starts_with(b"-----BEGIN") {
+                    reqwest::Certificate::from_pem(certificate)?
+                } else {
+                    reqwest::Certificate::from_der(certificate)?
+                };
+
+                client_builder = client_builder.add_root_certificate(certificate);
+            }
+
+            if let Some(identity) = &self.identity {
+                client_builder = client_builder.identity(reqwest::Identity::from_pem(identity)?);
+            }
+        }
+
+        Ok(ZOsmf::new(client_builder.build()?, self.url))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn build() {
+        let zosmf = ZOsmfBuilder::new("https://test.com")
+            .timeout(Duration::from_secs(30))
+            .connect_timeout(Duration::from_secs(5))
+            .build()
+            .unwrap();
+
+        assert_eq!(zosmf.core.url.as_ref(), "https://test.com");
+    }
+
+    #[cfg(feature = "rustls-tls")]
+    #[test]
+    fn invalid_certificate() {
+        let result = ZOsmfBuilder::new("https://test.com")
+            .add_root_certificate(b"-----BEGIN CERTIFICATE-----\nnonsense\n".to_vec())
+            .build();
+
+        assert!(matches!(result, Err(crate::Error::Reqwest(_))));
+    }
+
+    #[cfg(feature = "rustls-tls")]
+    #[test]
+    fn invalid_identity() {
+        let result = ZOsmfBuilder::new("https://test.com")
+            .identity(b"-----BEGIN CERTIFICATE-----\nnonsense\n".to_vec())
+            .build();
+
+        assert!(matches!(result, Err(crate::Error::Reqwest(_))));
+    }
+}
@@ -1,6 +1,9 @@
+pub mod catalog;
+pub mod compress;
 pub mod copy;
 pub mod copy_file;
 pub mod create;
+pub mod create_vsam;
 pub mod delete;
 pub mod list;
 pub mod members;
@@ -8,26 +11,37 @@ pub mod migrate;
 pub mod read;
 pub mod recall;
 pub mod rename;
+pub mod search;
+pub mod uncatalog;
 pub mod write;
 
+use std::future::Future;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 
+use futures_core::Stream;
+use futures_util::{StreamExt, TryStreamExt};
 use reqwest::header::HeaderValue;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 use crate::restfiles::Etag;
-use crate::{ClientCore, Result};
+use crate::{ClientCore, Error, Result};
 
+use self::catalog::DatasetCatalogBuilder;
+use self::compress::DatasetCompressBuilder;
 use self::copy::DatasetCopyBuilder;
 use self::copy_file::DatasetCopyFileBuilder;
 use self::create::DatasetCreateBuilder;
+use self::create_vsam::DatasetCreateVsamBuilder;
 use self::delete::DatasetDeleteBuilder;
-use self::list::{DatasetAttributesName, DatasetList, DatasetListBuilder};
+use self::list::{DatasetAttributesBase, DatasetAttributesName, DatasetList, DatasetListBuilder};
 use self::members::{MemberAttributesName, MemberList, MemberListBuilder};
 use self::migrate::DatasetMigrateBuilder;
 use self::read::{DatasetRead, DatasetReadBuilder};
 use self::recall::DatasetRecallBuilder;
 use self::rename::DatasetRenameBuilder;
+use self::search::DatasetSearchMembersBuilder;
+use self::uncatalog::DatasetUncatalogBuilder;
 use self::write::DatasetWriteBuilder;
 
 #[derive(Clone, Debug)]
@@ -41,6 +55,89 @@ impl DatasetsClient {
         DatasetsClient { core }
     }
 
+    /// Retrieve the base attributes of a single dataset.
+    ///
+    /// Migrated datasets are not recalled; they are returned with
+    /// [`migrated`](DatasetAttributesBase::migrated) set and most attributes missing.
+    /// Fails with [`Error::InvalidValue`] if no dataset, or more than one, has exactly this name.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let attributes = zosmf
+    ///     .datasets()
+    ///     .attributes("JIAHJ.REST.TEST.DATASET")
+    ///     .await?;
+    ///
+    /// println!("{:?} {:?}", attributes.record_format(), attributes.record_length());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn attributes<D>(&self, dataset: D) -> Result<DatasetAttributesBase>
+    where
+        D: std::fmt::Display,
+    {
+        let dataset = dataset.to_string();
+
+        let dataset_list = self.list(&dataset).attributes_base().build().await?;
+
+        single_match(dataset_list.items(), dataset.trim())
+    }
+
+    /// # Examples
+    ///
+    /// Catalog a dataset on a volume:
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let catalog_dataset = zosmf
+    ///     .datasets()
+    ///     .catalog("JIAHJ.REST.TEST.DATASET", "ZMF046")
+    ///     .build()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn catalog<D, V>(&self, dataset: D, volume: V) -> DatasetCatalogBuilder<Etag>
+    where
+        D: std::fmt::Display,
+        V: std::fmt::Display,
+    {
+        DatasetCatalogBuilder::new(self.core.clone(), dataset, volume)
+    }
+
+    /// # Examples
+    ///
+    /// Compress a PDS:
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let compress_dataset = zosmf
+    ///     .datasets()
+    ///     .compress("JIAHJ.REST.TEST.PDS")
+    ///     .build()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Compress an uncataloged PDS:
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let compress_uncataloged = zosmf
+    ///     .datasets()
+    ///     .compress("JIAHJ.REST.TEST.PDS")
+    ///     .volume("ZMF046")
+    ///     .build()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn compress<D>(&self, dataset: D) -> DatasetCompressBuilder<Etag>
+    where
+        D: std::fmt::Display,
+    {
+        DatasetCompressBuilder::new(self.core.clone(), dataset)
+    }
+
     /// #Examples
     ///
     /// Copy a dataset:
@@ -68,7 +165,24 @@ impl DatasetsClient {
     /// # Ok(())
     /// # }
     /// ```
-    pub fn copy<F, T>(&self, from_dataset: F, to_dataset: T) -> DatasetCopyBuilder<String>
+    ///
+    /// Replace a member that already exists, holding an exclusive enqueue on the target:
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// # use z_osmf::datasets::copy::DatasetCopyEnqueue;
+    /// let copy_dataset = zosmf
+    ///     .datasets()
+    ///     .copy("MY.OLD.PDS", "MY.NEW.PDS")
+    ///     .from_member("OLD")
+    ///     .to_member("NEW")
+    ///     .enqueue(DatasetCopyEnqueue::Exclu)
+    ///     .replace(true)
+    ///     .build()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn copy<F, T>(&self, from_dataset: F, to_dataset: T) -> DatasetCopyBuilder<Etag>
     where
         F: std::fmt::Display,
         T: std::fmt::Display,
@@ -102,7 +216,7 @@ impl DatasetsClient {
     /// # Ok(())
     /// # }
     /// ```
-    pub fn copy_file<F, T>(&self, from_path: F, to_dataset: T) -> DatasetCopyFileBuilder<String>
+    pub fn copy_file<F, T>(&self, from_path: F, to_dataset: T) -> DatasetCopyFileBuilder<Etag>
     where
         F: std::fmt::Display,
         T: std::fmt::Display,
@@ -110,6 +224,77 @@ impl DatasetsClient {
         DatasetCopyFileBuilder::new(self.core.clone(), from_path, to_dataset)
     }
 
+    /// Copy a sequential dataset or a member by streaming its data through the client,
+    /// calling `on_progress` with the number of bytes copied so far after each chunk.
+    ///
+    /// Unlike [`copy`](Self::copy), which is a single request that gives no feedback,
+    /// this suits large copies where progress should be reported. Members are given
+    /// as `DATASET(MEMBER)`; a PDS or PDSE given without a member can not be read as one
+    /// stream, and fails with [`Error::InvalidValue`] before anything is copied.
+    ///
+    /// The data is read and written in record mode, where each record is prefixed with its
+    /// 4-byte length, so records of variable length keep their boundaries. The byte counts,
+    /// and the total returned, include these prefixes.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let copied = zosmf
+    ///     .datasets()
+    ///     .copy_via_download("IBMUSER.BIG.DATA", "IBMUSER.BIG.DATA.COPY", |bytes| {
+    ///         println!("copied {} bytes", bytes);
+    ///     })
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn copy_via_download<F, T, P>(
+        &self,
+        from_dataset: F,
+        to_dataset: T,
+        on_progress: P,
+    ) -> Result<u64>
+    where
+        F: std::fmt::Display,
+        T: std::fmt::Display,
+        P: Fn(u64) + Send + Sync + 'static,
+    {
+        let from_dataset = from_dataset.to_string();
+        let to_dataset = to_dataset.to_string();
+
+        let read = match split_member(&from_dataset) {
+            (dataset, Some(member)) => self.read(dataset).member(member),
+            (dataset, None) => {
+                let attributes = self.attributes(dataset).await?;
+                if is_partitioned(attributes.organization()) {
+                    return Err(Error::InvalidValue(format!(
+                        "{} is partitioned; copy its members one at a time as {}(MEMBER)",
+                        dataset, dataset
+                    )));
+                }
+
+                self.read(dataset)
+            }
+        };
+        let read_stream = read.record().stream().build().await?;
+
+        let write = match split_member(&to_dataset) {
+            (dataset, Some(member)) => self.write(dataset).member(member),
+            (dataset, None) => self.write(dataset),
+        };
+
+        let copied = Arc::new(AtomicU64::new(0));
+        let counter = copied.clone();
+        let body = read_stream.into_stream().inspect_ok(move |chunk| {
+            let len = chunk.len() as u64;
+            on_progress(counter.fetch_add(len, Ordering::Relaxed) + len);
+        });
+
+        write.body_stream(body).record().build().await?;
+
+        Ok(copied.load(Ordering::Relaxed))
+    }
+
     /// # Examples
     ///
     /// Create a sequential dataset:
@@ -180,13 +365,42 @@ impl DatasetsClient {
     /// # Ok(())
     /// # }
     /// ```
-    pub fn create<D>(&self, dataset: D) -> DatasetCreateBuilder<String>
+    pub fn create<D>(&self, dataset: D) -> DatasetCreateBuilder<Etag>
     where
         D: std::fmt::Display,
     {
         DatasetCreateBuilder::new(self.core.clone(), dataset)
     }
 
+    /// # Examples
+    ///
+    /// Create a KSDS:
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// # use z_osmf::datasets::create_vsam::VsamClusterType;
+    /// let create_ksds = zosmf
+    ///     .datasets()
+    ///     .create_vsam("JIAHJ.REST.TEST.KSDS")
+    ///     .cluster_type(VsamClusterType::Ksds)
+    ///     .space_allocation_unit("CYL")
+    ///     .primary_space(10)
+    ///     .secondary_space(5)
+    ///     .key_length(8)
+    ///     .key_offset(0)
+    ///     .average_record_size(80)
+    ///     .maximum_record_size(200)
+    ///     .build()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn create_vsam<D>(&self, dataset: D) -> DatasetCreateVsamBuilder<String>
+    where
+        D: std::fmt::Display,
+    {
+        DatasetCreateVsamBuilder::new(self.core.clone(), dataset)
+    }
+
     /// # Examples
     ///
     /// Delete a sequential dataset:
@@ -247,6 +461,90 @@ impl DatasetsClient {
         DatasetDeleteBuilder::new(self.core.clone(), dataset)
     }
 
+    /// Read many datasets as text, with at most `concurrency` reads in flight.
+    ///
+    /// Each dataset is yielded with its own result as soon as it has been read,
+    /// so a dataset that is migrated or inaccessible does not stop the others.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// use futures_util::StreamExt;
+    ///
+    /// let names = ["IBMUSER.JCL(BUILD)", "IBMUSER.DATA", "IBMUSER.README"];
+    /// let mut reads = std::pin::pin!(zosmf.datasets().download_many(names, 4));
+    ///
+    /// while let Some((name, read)) = reads.next().await {
+    ///     match read {
+    ///         Ok(read) => println!("{}: {} bytes", name, read.data().len()),
+    ///         Err(err) => eprintln!("{}: {}", name, err),
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn download_many<I>(
+        &self,
+        names: I,
+        concurrency: usize,
+    ) -> impl Stream<Item = (Arc<str>, Result<DatasetRead<Arc<str>>>)>
+    where
+        I: IntoIterator,
+        I::Item: std::fmt::Display,
+    {
+        let core = self.core.clone();
+
+        futures_util::stream::iter(names)
+            .map(move |name| {
+                let name: Arc<str> = name.to_string().into();
+                let read = match split_member(&name) {
+                    (dataset, Some(member)) => {
+                        DatasetReadBuilder::new(core.clone(), dataset).member(member)
+                    }
+                    (dataset, None) => DatasetReadBuilder::new(core.clone(), dataset),
+                };
+
+                async move { (name, read.build().await) }
+            })
+            .buffer_unordered(concurrency.max(1))
+    }
+
+    /// Check whether a dataset is cataloged.
+    ///
+    /// Migrated datasets and VSAM clusters remain in the catalog, so they are reported as existing.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// if !zosmf.datasets().exists("JIAHJ.REST.TEST.DATASET").await? {
+    ///     zosmf
+    ///         .datasets()
+    ///         .create("JIAHJ.REST.TEST.DATASET")
+    ///         .build()
+    ///         .await?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn exists<D>(&self, dataset: D) -> Result<bool>
+    where
+        D: std::fmt::Display,
+    {
+        let dataset = dataset.to_string();
+
+        let dataset_list = self
+            .list(&dataset)
+            .attributes_dsname()
+            .max_items(1)
+            .build()
+            .await?;
+
+        Ok(dataset_list
+            .items()
+            .iter()
+            .any(|item| item.name().eq_ignore_ascii_case(dataset.trim())))
+    }
+
     /// # Examples
     ///
     /// List datasets:
@@ -307,6 +605,21 @@ impl DatasetsClient {
     /// # Ok(())
     /// # }
     /// ```
+    ///
+    /// List PDS members along with the total member count:
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let list_members_total = zosmf
+    ///     .datasets()
+    ///     .members("NOTSYS1.PROCLIB")
+    ///     .attributes_member()
+    ///     .include_total(true)
+    ///     .build()
+    ///     .await?;
+    /// println!("{:?}", list_members_total.total_rows());
+    /// # Ok(())
+    /// # }
+    /// ```
     pub fn members<D>(&self, dataset: D) -> MemberListBuilder<MemberList<MemberAttributesName>>
     where
         D: std::fmt::Display,
@@ -400,6 +713,22 @@ impl DatasetsClient {
     /// # Ok(())
     /// # }
     /// ```
+    ///
+    /// Rename a member, holding an exclusive enqueue on the dataset:
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// # use z_osmf::datasets::DatasetEnqueue;
+    /// let rename_member = zosmf
+    ///     .datasets()
+    ///     .rename("MY.DATA.SET", "MY.DATA.SET")
+    ///     .from_member("OLDMEM")
+    ///     .to_member("NEWMEM")
+    ///     .enqueue(DatasetEnqueue::Exclu)
+    ///     .build()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
     pub fn rename<F, T>(&self, from_dataset: F, to_dataset: T) -> DatasetRenameBuilder<String>
     where
         F: std::fmt::Display,
@@ -408,6 +737,136 @@ impl DatasetsClient {
         DatasetRenameBuilder::new(self.core.clone(), from_dataset, to_dataset)
     }
 
+    /// # Examples
+    ///
+    /// Find the members of a PDS that mention a program:
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let matches = zosmf
+    ///     .datasets()
+    ///     .search_members("SYS1.PROCLIB", "PGM=IEFBR14")
+    ///     .concurrency(8)
+    ///     .build()
+    ///     .await?;
+    ///
+    /// for (member, search_match) in matches.iter() {
+    ///     match search_match {
+    ///         Ok(search_match) => println!("{}: {:?}", member, search_match.lines()),
+    ///         Err(err) => eprintln!("{}: {}", member, err),
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn search_members<D, P>(&self, dataset: D, pattern: P) -> DatasetSearchMembersBuilder
+    where
+        D: std::fmt::Display,
+        P: std::fmt::Display,
+    {
+        DatasetSearchMembersBuilder::new(
+            self.core.clone(),
+            dataset.to_string().into(),
+            pattern.to_string().into(),
+        )
+    }
+
+    /// # Examples
+    ///
+    /// Uncatalog a dataset:
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let uncatalog_dataset = zosmf
+    ///     .datasets()
+    ///     .uncatalog("JIAHJ.REST.TEST.DATASET")
+    ///     .build()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn uncatalog<D>(&self, dataset: D) -> DatasetUncatalogBuilder<Etag>
+    where
+        D: std::fmt::Display,
+    {
+        DatasetUncatalogBuilder::new(self.core.clone(), dataset)
+    }
+
+    /// Read a dataset or member as text, apply `f` to it and write the result back,
+    /// only if nothing else changed it in between.
+    ///
+    /// The read returns an etag that the write must match. When another writer got there
+    /// first, z/OSMF rejects the write with `412 Precondition Failed`, and the data is re-read
+    /// and `f` applied again, up to `attempts` times in total, where `0` counts as `1`.
+    /// When every attempt is rejected, the last [`Error::PreconditionFailed`] is returned
+    /// as [`Error::Retry`], along with the transaction IDs of the rejected writes.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let etag = zosmf
+    ///     .datasets()
+    ///     .update("SYS1.PARMLIB", Some("SMFPRM00"), 5, |data| {
+    ///         data.replace("INTVAL(30)", "INTVAL(15)")
+    ///     })
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn update<D, F>(
+        &self,
+        dataset: D,
+        member: Option<&str>,
+        attempts: u32,
+        f: F,
+    ) -> Result<Etag>
+    where
+        D: std::fmt::Display,
+        F: Fn(&str) -> String,
+    {
+        let dataset = dataset.to_string();
+
+        let mut attempt = 1;
+        let mut transaction_ids: Vec<Arc<str>> = Vec::new();
+        loop {
+            let read = match member {
+                Some(member) => self.read(&dataset).member(member),
+                None => self.read(&dataset),
+            };
+            let read = read.return_etag(true).build().await?;
+
+            let etag = read.etag().ok_or_else(|| {
+                Error::InvalidValue(format!("z/OSMF returned no etag for {}", dataset))
+            })?;
+
+            let write = match member {
+                Some(member) => self.write(&dataset).member(member),
+                None => self.write(&dataset),
+            };
+
+            match write.if_match(etag).text(f(read.data())).build().await {
+                Err(err @ Error::PreconditionFailed { .. }) => {
+                    if let Error::PreconditionFailed {
+                        transaction_id: Some(transaction_id),
+                        ..
+                    } = &err
+                    {
+                        transaction_ids.push(transaction_id.clone());
+                    }
+
+                    if attempt >= attempts {
+                        return Err(Error::Retry {
+                            attempts: attempt,
+                            transaction_ids: transaction_ids.into(),
+                            source: Box::new(err),
+                        });
+                    }
+
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+
     /// # Examples
     ///
     /// Write to a PDS member:
@@ -425,6 +884,31 @@ impl DatasetsClient {
     /// # Ok(())
     /// # }
     /// ```
+    ///
+    /// Re-read the dataset when it changed since its etag was obtained:
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// # let etag = "";
+    /// # let string_data = "";
+    /// match zosmf
+    ///     .datasets()
+    ///     .write("SYS1.PARMLIB")
+    ///     .member("SMFPRM00")
+    ///     .if_match(etag)
+    ///     .text(string_data)
+    ///     .build()
+    ///     .await
+    /// {
+    ///     Err(z_osmf::Error::PreconditionFailed { current_etag, .. }) => {
+    ///         println!("changed by someone else, now at {:?}", current_etag);
+    ///     }
+    ///     result => {
+    ///         result?;
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
     pub fn write<D>(&self, dataset: D) -> DatasetWriteBuilder<Etag>
     where
         D: std::fmt::Display,
@@ -501,6 +985,11 @@ impl From<DatasetMigratedRecall> for HeaderValue {
     }
 }
 
+/// An item of a dataset or member list, identified by the name a list can be resumed from.
+pub trait ListItem {
+    fn name(&self) -> &str;
+}
+
 #[derive(Deserialize, Serialize)]
 enum Unknown {
     #[serde(rename = "?")]
@@ -534,6 +1023,55 @@ where
         .transpose()
 }
 
+/// Follow the `start` cursor of a list until z/OSMF reports no more rows.
+///
+/// `fetch` is called with the name to resume from, which z/OSMF returns again
+/// as the first item of the next page, so it is skipped. Later pages should be
+/// requested with [`next_page_size`] to make up for it.
+fn paginate<U, F, Fut>(fetch: F) -> impl Stream<Item = Result<U>>
+where
+    U: ListItem + Clone,
+    F: Fn(Option<Arc<str>>) -> Fut,
+    Fut: Future<Output = Result<(Arc<[U]>, bool)>>,
+{
+    futures_util::stream::try_unfold(Some(None), move |cursor: Option<Option<Arc<str>>>| {
+        let page = cursor.map(|start| (fetch(start.clone()), start));
+
+        async move {
+            let Some((page, start)) = page else {
+                return Ok::<_, Error>(None);
+            };
+
+            let (items, more_rows) = page.await?;
+            let items: Vec<U> = items
+                .iter()
+                .filter(|item| start.as_deref() != Some(item.name()))
+                .cloned()
+                .collect();
+
+            let cursor = match items.last() {
+                Some(last) if more_rows => Some(Some(last.name().into())),
+                _ => None,
+            };
+
+            Ok(Some((
+                futures_util::stream::iter(items.into_iter().map(Ok)),
+                cursor,
+            )))
+        }
+    })
+    .try_flatten()
+}
+
+/// The page size to resume a list with, one more than `max_items`
+/// to hold the item resumed from. A size of zero, for every item, is left alone.
+fn next_page_size(max_items: Option<i32>) -> Option<i32> {
+    max_items.map(|max_items| match max_items {
+        1.. => max_items.saturating_add(1),
+        _ => max_items,
+    })
+}
+
 fn get_member(value: &Option<Arc<str>>) -> String {
     value
         .as_ref()
@@ -567,10 +1105,80 @@ where
     }
 }
 
+/// Split `DATASET(MEMBER)` into the dataset and member names.
+fn split_member(name: &str) -> (&str, Option<&str>) {
+    match name.split_once('(') {
+        Some((dataset, member)) => (dataset, Some(member.trim_end_matches(')'))),
+        None => (name, None),
+    }
+}
+
+/// Whether a dataset organization, like `PO` or `PO-E`, is that of a PDS or PDSE.
+fn is_partitioned(organization: Option<&str>) -> bool {
+    organization.is_some_and(|organization| organization.starts_with("PO"))
+}
+
+/// Pick the one item of a list named exactly `name`.
+fn single_match<T>(items: &[T], name: &str) -> Result<T>
+where
+    T: ListItem + Clone,
+{
+    let mut matches = items
+        .iter()
+        .filter(|item| item.name().eq_ignore_ascii_case(name));
+
+    match (matches.next(), matches.next()) {
+        (Some(item), None) => Ok(item.clone()),
+        (None, _) => Err(Error::InvalidValue(format!("no dataset named {}", name))),
+        (Some(_), Some(_)) => Err(Error::InvalidValue(format!(
+            "more than one dataset named {}",
+            name
+        ))),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn split_member() {
+        assert_eq!(
+            super::split_member("IBMUSER.JCL(BUILD)"),
+            ("IBMUSER.JCL", Some("BUILD"))
+        );
+        assert_eq!(super::split_member("IBMUSER.DATA"), ("IBMUSER.DATA", None));
+    }
+
+    #[test]
+    fn is_partitioned() {
+        assert!(super::is_partitioned(Some("PO")));
+        assert!(super::is_partitioned(Some("PO-E")));
+        assert!(!super::is_partitioned(Some("PS")));
+        assert!(!super::is_partitioned(None));
+    }
+
+    #[test]
+    fn single_match() {
+        let items: Vec<DatasetAttributesName> = serde_json::from_str(
+            r#"[{"dsname": "SYS1.PROCLIB"}, {"dsname": "SYS1.PROCLIB.OLD"}, {"dsname": "SYS2.X"}, {"dsname": "SYS2.X"}]"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            super::single_match(&items, "sys1.proclib").unwrap().name(),
+            "SYS1.PROCLIB"
+        );
+        assert!(matches!(
+            super::single_match(&items, "SYS1.LINKLIB"),
+            Err(Error::InvalidValue(_))
+        ));
+        assert!(matches!(
+            super::single_match(&items, "SYS2.X"),
+            Err(Error::InvalidValue(_))
+        ));
+    }
+
     #[test]
     fn display_data_type() {
         assert_eq!(format!("{}", DatasetDataType::Binary), "binary");
@@ -601,6 +1209,113 @@ mod tests {
         assert_eq!(header_value, HeaderValue::from_static("SHRW"));
     }
 
+    #[tokio::test]
+    async fn download_many_continues_after_errors() {
+        let zosmf = crate::ZOsmf::new(reqwest::Client::new(), "http://127.0.0.1:1");
+
+        let mut names: Vec<Arc<str>> = zosmf
+            .datasets()
+            .download_many(["IBMUSER.A", "IBMUSER.B(MEMBER)", "IBMUSER.C"], 2)
+            .map(|(name, read)| {
+                assert!(read.is_err());
+
+                name
+            })
+            .collect()
+            .await;
+        names.sort_unstable();
+
+        assert_eq!(
+            names,
+            vec![
+                Arc::from("IBMUSER.A"),
+                Arc::from("IBMUSER.B(MEMBER)"),
+                Arc::from("IBMUSER.C")
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_paginate() {
+        #[derive(Clone, Debug, PartialEq)]
+        struct Item(&'static str);
+
+        impl ListItem for Item {
+            fn name(&self) -> &str {
+                self.0
+            }
+        }
+
+        let names = ["A", "B", "C", "D", "E"];
+
+        let items: Vec<Item> = paginate(|start: Option<Arc<str>>| async move {
+            let skip = match start {
+                Some(start) => names.iter().position(|name| **name == *start).unwrap(),
+                None => 0,
+            };
+            let page: Arc<[Item]> = names
+                .iter()
+                .skip(skip)
+                .take(2)
+                .map(|name| Item(name))
+                .collect();
+
+            Ok((page, skip + 2 < names.len()))
+        })
+        .try_collect()
+        .await
+        .unwrap();
+
+        assert_eq!(items, names.map(Item));
+    }
+
+    #[tokio::test]
+    async fn test_paginate_single_item_pages() {
+        #[derive(Clone, Debug, PartialEq)]
+        struct Item(&'static str);
+
+        impl ListItem for Item {
+            fn name(&self) -> &str {
+                self.0
+            }
+        }
+
+        let names = ["A", "B", "C"];
+        let max_items = Some(1);
+
+        let items: Vec<Item> = paginate(|start: Option<Arc<str>>| async move {
+            let (skip, page_size) = match start {
+                Some(start) => (
+                    names.iter().position(|name| **name == *start).unwrap(),
+                    next_page_size(max_items),
+                ),
+                None => (0, max_items),
+            };
+            let page_size = page_size.unwrap() as usize;
+            let page: Arc<[Item]> = names
+                .iter()
+                .skip(skip)
+                .take(page_size)
+                .map(|name| Item(name))
+                .collect();
+
+            Ok((page, skip + page_size < names.len()))
+        })
+        .try_collect()
+        .await
+        .unwrap();
+
+        assert_eq!(items, names.map(Item));
+    }
+
+    #[test]
+    fn test_next_page_size() {
+        assert_eq!(next_page_size(Some(1)), Some(2));
+        assert_eq!(next_page_size(Some(100)), Some(101));
+        assert_eq!(next_page_size(Some(0)), Some(0));
+        assert_eq!(next_page_size(None), None);
+    }
+
     #[test]
     fn test_get_session_ref() {
         let response = reqwest::Response::from(
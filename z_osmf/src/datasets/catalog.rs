@@ -0,0 +1,79 @@
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use serde::Serialize;
+use z_osmf_macros::Endpoint;
+
+use crate::convert::TryFromResponse;
+use crate::ClientCore;
+
+#[derive(Clone, Debug, Endpoint)]
+#[endpoint(method = put, path = "/zosmf/restfiles/ds/{dataset}")]
+pub struct DatasetCatalogBuilder<T>
+where
+    T: TryFromResponse,
+{
+    core: Arc<ClientCore>,
+
+    #[endpoint(path)]
+    dataset: Arc<str>,
+    #[endpoint(builder_fn = build_body)]
+    volume: Arc<str>,
+
+    target_type: PhantomData<T>,
+}
+
+#[derive(Serialize)]
+struct RequestJson<'a> {
+    request: &'static str,
+    volser: &'a str,
+}
+
+fn build_body<T>(
+    request_builder: reqwest::RequestBuilder,
+    builder: &DatasetCatalogBuilder<T>,
+) -> reqwest::RequestBuilder
+where
+    T: TryFromResponse,
+{
+    request_builder.json(&RequestJson {
+        request: "catalog",
+        volser: &builder.volume,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::*;
+
+    #[test]
+    fn example_1() {
+        let zosmf = get_zosmf();
+
+        let json = serde_json::json!({
+            "request": "catalog",
+            "volser": "ZMF046"
+        });
+
+        let manual_request = zosmf
+            .core
+            .client
+            .put("https://test.com/zosmf/restfiles/ds/JIAHJ.REST.TEST.DATASET")
+            .json(&json)
+            .build()
+            .unwrap();
+
+        let catalog_dataset = zosmf
+            .datasets()
+            .catalog("JIAHJ.REST.TEST.DATASET", "ZMF046")
+            .get_request()
+            .unwrap();
+
+        assert_eq!(
+            format!("{:?}", manual_request),
+            format!("{:?}", catalog_dataset)
+        );
+
+        assert_eq!(manual_request.json(), catalog_dataset.json());
+    }
+}
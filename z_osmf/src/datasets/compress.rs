@@ -0,0 +1,113 @@
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use serde::Serialize;
+use z_osmf_macros::Endpoint;
+
+use crate::convert::TryFromResponse;
+use crate::ClientCore;
+
+use super::get_volume;
+
+#[derive(Clone, Debug, Endpoint)]
+#[endpoint(method = put, path = "/zosmf/restfiles/ds{volume}/{dataset}")]
+pub struct DatasetCompressBuilder<T>
+where
+    T: TryFromResponse,
+{
+    core: Arc<ClientCore>,
+
+    #[endpoint(path)]
+    dataset: Arc<str>,
+    #[endpoint(path, builder_fn = build_volume)]
+    volume: Option<Arc<str>>,
+
+    #[endpoint(builder_fn = build_body)]
+    target_type: PhantomData<T>,
+}
+
+#[derive(Serialize)]
+struct RequestJson {
+    request: &'static str,
+}
+
+fn build_body<T>(
+    request_builder: reqwest::RequestBuilder,
+    _builder: &DatasetCompressBuilder<T>,
+) -> reqwest::RequestBuilder
+where
+    T: TryFromResponse,
+{
+    request_builder.json(&RequestJson {
+        request: "compress",
+    })
+}
+
+fn build_volume<T>(builder: &DatasetCompressBuilder<T>) -> String
+where
+    T: TryFromResponse,
+{
+    get_volume(&builder.volume)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::*;
+
+    #[test]
+    fn example_1() {
+        let zosmf = get_zosmf();
+
+        let json = serde_json::json!({
+            "request": "compress"
+        });
+
+        let manual_request = zosmf
+            .core
+            .client
+            .put("https://test.com/zosmf/restfiles/ds/JIAHJ.REST.TEST.PDS")
+            .json(&json)
+            .build()
+            .unwrap();
+
+        let compress_dataset = zosmf
+            .datasets()
+            .compress("JIAHJ.REST.TEST.PDS")
+            .get_request()
+            .unwrap();
+
+        assert_eq!(
+            format!("{:?}", manual_request),
+            format!("{:?}", compress_dataset)
+        );
+
+        assert_eq!(manual_request.json(), compress_dataset.json());
+    }
+
+    #[test]
+    fn example_2() {
+        let zosmf = get_zosmf();
+
+        let manual_request = zosmf
+            .core
+            .client
+            .put("https://test.com/zosmf/restfiles/ds/-(ZMF046)/JIAHJ.REST.TEST.PDS")
+            .json(&serde_json::json!({
+                "request": "compress"
+            }))
+            .build()
+            .unwrap();
+
+        let compress_uncataloged = zosmf
+            .datasets()
+            .compress("JIAHJ.REST.TEST.PDS")
+            .volume("ZMF046")
+            .get_request()
+            .unwrap();
+
+        assert_eq!(
+            format!("{:?}", manual_request),
+            format!("{:?}", compress_uncataloged)
+        );
+    }
+}
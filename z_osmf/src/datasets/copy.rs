@@ -52,6 +52,7 @@ struct RequestJson<'a> {
     from_dataset: FromDataset<'a>,
     #[serde(skip_serializing_if = "Option::is_none")]
     enq: Option<DatasetCopyEnqueue>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     replace: Option<bool>,
 }
 
@@ -96,3 +97,77 @@ where
 {
     get_volume(&builder.volume)
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{get_zosmf, GetJson};
+
+    use super::*;
+
+    #[test]
+    fn enqueue_and_replace() {
+        let zosmf = get_zosmf();
+
+        let json = r#"
+        {
+            "request": "copy",
+            "from-dataset": {
+                "dsn": "MY.OLD.PDS",
+                "member": "OLD"
+            },
+            "enq": "SHR",
+            "replace": true
+        }
+        "#;
+        let manual_request = zosmf
+            .core
+            .client
+            .put("https://test.com/zosmf/restfiles/ds/MY.NEW.PDS(NEW)")
+            .json(&serde_json::from_str::<serde_json::Value>(json).unwrap())
+            .build()
+            .unwrap();
+
+        let request = zosmf
+            .datasets()
+            .copy("MY.OLD.PDS", "MY.NEW.PDS")
+            .from_member("OLD")
+            .to_member("NEW")
+            .enqueue(DatasetCopyEnqueue::Shr)
+            .replace(true)
+            .get_request()
+            .unwrap();
+
+        assert_eq!(format!("{:?}", manual_request), format!("{:?}", request));
+        assert_eq!(manual_request.json(), request.json());
+    }
+
+    #[test]
+    fn minimal_request() {
+        let zosmf = get_zosmf();
+
+        let json = r#"
+        {
+            "request": "copy",
+            "from-dataset": {
+                "dsn": "MY.OLD.DS"
+            }
+        }
+        "#;
+        let manual_request = zosmf
+            .core
+            .client
+            .put("https://test.com/zosmf/restfiles/ds/MY.NEW.DS")
+            .json(&serde_json::from_str::<serde_json::Value>(json).unwrap())
+            .build()
+            .unwrap();
+
+        let request = zosmf
+            .datasets()
+            .copy("MY.OLD.DS", "MY.NEW.DS")
+            .get_request()
+            .unwrap();
+
+        assert_eq!(format!("{:?}", manual_request), format!("{:?}", request));
+        assert_eq!(manual_request.json(), request.json());
+    }
+}
@@ -5,7 +5,11 @@ use serde::Serialize;
 use z_osmf_macros::Endpoint;
 
 use crate::convert::TryFromResponse;
-use crate::ClientCore;
+use crate::restfiles::Etag;
+use crate::{ClientCore, Result};
+
+use super::list::{DatasetAttributesBase, DatasetListBuilder};
+use super::single_match;
 
 #[derive(Clone, Debug, Endpoint)]
 #[endpoint(method = post, path = "/zosmf/restfiles/ds/{dataset}")]
@@ -54,6 +58,53 @@ where
     target_type: PhantomData<T>,
 }
 
+impl DatasetCreateBuilder<Etag> {
+    /// Once the dataset is created, retrieve its attributes, to confirm those
+    /// z/OSMF applied from a model dataset or SMS classes.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let attributes = zosmf
+    ///     .datasets()
+    ///     .create("JIAHJ.REST.TEST.NEWDS")
+    ///     .model_dataset("JIAHJ.REST.TEST.MODEL")
+    ///     .return_attributes()
+    ///     .build()
+    ///     .await?;
+    ///
+    /// println!("{:?}", attributes.record_format());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn return_attributes(self) -> DatasetCreateAttributesBuilder {
+        DatasetCreateAttributesBuilder { create: self }
+    }
+}
+
+/// Create a dataset, then retrieve its base attributes.
+#[derive(Clone, Debug)]
+pub struct DatasetCreateAttributesBuilder {
+    create: DatasetCreateBuilder<Etag>,
+}
+
+impl DatasetCreateAttributesBuilder {
+    pub async fn build(self) -> Result<DatasetAttributesBase> {
+        let DatasetCreateAttributesBuilder { create } = self;
+        let core = create.core.clone();
+        let dataset = create.dataset.clone();
+
+        create.build().await?;
+
+        let dataset_list = DatasetListBuilder::<()>::new(core, &dataset)
+            .attributes_base()
+            .build()
+            .await?;
+
+        single_match(dataset_list.items(), &dataset)
+    }
+}
+
 #[derive(Clone, Debug, Default, Serialize)]
 struct RequestJson<'a> {
     #[serde(rename = "volser", skip_serializing_if = "Option::is_none")]
@@ -143,6 +194,30 @@ where
 mod tests {
     use crate::tests::*;
 
+    #[test]
+    fn return_attributes() {
+        let zosmf = get_zosmf();
+
+        let create_dataset = zosmf
+            .datasets()
+            .create("JIAHJ.REST.TEST.NEWDS")
+            .model_dataset("JIAHJ.REST.TEST.MODEL");
+
+        let manual_request = create_dataset.get_request().unwrap();
+
+        let create_request = create_dataset
+            .return_attributes()
+            .create
+            .get_request()
+            .unwrap();
+
+        assert_eq!(
+            format!("{:?}", manual_request),
+            format!("{:?}", create_request)
+        );
+        assert_eq!(manual_request.json(), create_request.json());
+    }
+
     #[test]
     fn example_1() {
         let zosmf = get_zosmf();
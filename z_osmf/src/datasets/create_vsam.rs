@@ -0,0 +1,244 @@
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use z_osmf_macros::Endpoint;
+
+use crate::convert::TryFromResponse;
+use crate::ClientCore;
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum VsamClusterType {
+    Esds,
+    Ksds,
+    Linear,
+    Rrds,
+}
+
+#[derive(Clone, Debug, Endpoint)]
+#[endpoint(method = post, path = "/zosmf/restfiles/ds/{dataset}")]
+pub struct DatasetCreateVsamBuilder<T>
+where
+    T: TryFromResponse,
+{
+    core: Arc<ClientCore>,
+
+    #[endpoint(path)]
+    dataset: Arc<str>,
+
+    #[endpoint(builder_fn = build_body)]
+    cluster_type: Option<VsamClusterType>,
+    #[endpoint(skip_builder)]
+    volume: Option<Arc<str>>,
+    #[endpoint(skip_builder)]
+    space_allocation_unit: Option<Arc<str>>,
+    #[endpoint(skip_builder)]
+    primary_space: Option<i32>,
+    #[endpoint(skip_builder)]
+    secondary_space: Option<i32>,
+    #[endpoint(skip_builder)]
+    key_length: Option<i32>,
+    #[endpoint(skip_builder)]
+    key_offset: Option<i32>,
+    #[endpoint(skip_builder)]
+    average_record_size: Option<i32>,
+    #[endpoint(skip_builder)]
+    maximum_record_size: Option<i32>,
+    #[endpoint(skip_builder)]
+    control_interval_size: Option<i32>,
+    #[endpoint(skip_builder)]
+    share_options: Option<(i32, i32)>,
+    #[endpoint(skip_builder)]
+    storage_class: Option<Arc<str>>,
+    #[endpoint(skip_builder)]
+    management_class: Option<Arc<str>>,
+    #[endpoint(skip_builder)]
+    data_class: Option<Arc<str>>,
+
+    target_type: PhantomData<T>,
+}
+
+/// The body mirrors the IDCAMS `DEFINE CLUSTER` keywords,
+/// alongside the allocation keys shared with non-VSAM creates.
+#[derive(Clone, Debug, Serialize)]
+struct RequestJson<'a> {
+    dsorg: &'static str,
+    #[serde(rename = "vsamtype", skip_serializing_if = "Option::is_none")]
+    cluster_type: Option<&'a VsamClusterType>,
+    #[serde(rename = "volser", skip_serializing_if = "Option::is_none")]
+    volume: Option<&'a str>,
+    #[serde(rename = "alcunit", skip_serializing_if = "Option::is_none")]
+    space_allocation_unit: Option<&'a str>,
+    #[serde(rename = "primary", skip_serializing_if = "Option::is_none")]
+    primary_space: Option<&'a i32>,
+    #[serde(rename = "secondary", skip_serializing_if = "Option::is_none")]
+    secondary_space: Option<&'a i32>,
+    #[serde(rename = "keylen", skip_serializing_if = "Option::is_none")]
+    key_length: Option<&'a i32>,
+    #[serde(rename = "keyoff", skip_serializing_if = "Option::is_none")]
+    key_offset: Option<&'a i32>,
+    #[serde(rename = "avgrecl", skip_serializing_if = "Option::is_none")]
+    average_record_size: Option<&'a i32>,
+    #[serde(rename = "maxrecl", skip_serializing_if = "Option::is_none")]
+    maximum_record_size: Option<&'a i32>,
+    #[serde(rename = "cisize", skip_serializing_if = "Option::is_none")]
+    control_interval_size: Option<&'a i32>,
+    #[serde(rename = "shareoptions", skip_serializing_if = "Option::is_none")]
+    share_options: Option<&'a (i32, i32)>,
+    #[serde(rename = "storclass", skip_serializing_if = "Option::is_none")]
+    storage_class: Option<&'a str>,
+    #[serde(rename = "mgntclass", skip_serializing_if = "Option::is_none")]
+    management_class: Option<&'a str>,
+    #[serde(rename = "dataclass", skip_serializing_if = "Option::is_none")]
+    data_class: Option<&'a str>,
+}
+
+fn build_body<T>(
+    request_builder: reqwest::RequestBuilder,
+    builder: &DatasetCreateVsamBuilder<T>,
+) -> reqwest::RequestBuilder
+where
+    T: TryFromResponse,
+{
+    let DatasetCreateVsamBuilder {
+        cluster_type,
+        volume,
+        space_allocation_unit,
+        primary_space,
+        secondary_space,
+        key_length,
+        key_offset,
+        average_record_size,
+        maximum_record_size,
+        control_interval_size,
+        share_options,
+        storage_class,
+        management_class,
+        data_class,
+        ..
+    } = builder;
+
+    let request_json = RequestJson {
+        dsorg: "VSAM",
+        cluster_type: cluster_type.as_ref(),
+        volume: volume.as_deref(),
+        space_allocation_unit: space_allocation_unit.as_deref(),
+        primary_space: primary_space.as_ref(),
+        secondary_space: secondary_space.as_ref(),
+        key_length: key_length.as_ref(),
+        key_offset: key_offset.as_ref(),
+        average_record_size: average_record_size.as_ref(),
+        maximum_record_size: maximum_record_size.as_ref(),
+        control_interval_size: control_interval_size.as_ref(),
+        share_options: share_options.as_ref(),
+        storage_class: storage_class.as_deref(),
+        management_class: management_class.as_deref(),
+        data_class: data_class.as_deref(),
+    };
+
+    request_builder.json(&request_json)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::*;
+
+    use super::VsamClusterType;
+
+    #[test]
+    fn example_1() {
+        let zosmf = get_zosmf();
+
+        let raw_json = r#"
+        {
+            "dsorg": "VSAM",
+            "vsamtype": "KSDS",
+            "volser": "zmf046",
+            "alcunit": "CYL",
+            "primary": 10,
+            "secondary": 5,
+            "keylen": 8,
+            "keyoff": 0,
+            "avgrecl": 80,
+            "maxrecl": 200,
+            "cisize": 4096,
+            "shareoptions": [2, 3]
+        }
+        "#;
+        let json: serde_json::Value = serde_json::from_str(raw_json).unwrap();
+
+        let manual_request = zosmf
+            .core
+            .client
+            .post("https://test.com/zosmf/restfiles/ds/JIAHJ.REST.TEST.KSDS")
+            .json(&json)
+            .build()
+            .unwrap();
+
+        let create_ksds = zosmf
+            .datasets()
+            .create_vsam("JIAHJ.REST.TEST.KSDS")
+            .cluster_type(VsamClusterType::Ksds)
+            .volume("zmf046")
+            .space_allocation_unit("CYL")
+            .primary_space(10)
+            .secondary_space(5)
+            .key_length(8)
+            .key_offset(0)
+            .average_record_size(80)
+            .maximum_record_size(200)
+            .control_interval_size(4096)
+            .share_options((2, 3))
+            .get_request()
+            .unwrap();
+
+        assert_eq!(
+            format!("{:?}", manual_request),
+            format!("{:?}", create_ksds)
+        );
+
+        assert_eq!(manual_request.json(), create_ksds.json());
+    }
+
+    #[test]
+    fn example_2() {
+        let zosmf = get_zosmf();
+
+        let raw_json = r#"
+        {
+            "dsorg": "VSAM",
+            "vsamtype": "LINEAR",
+            "alcunit": "TRK",
+            "primary": 100,
+            "storclass": "SCTEST"
+        }
+        "#;
+        let json: serde_json::Value = serde_json::from_str(raw_json).unwrap();
+
+        let manual_request = zosmf
+            .core
+            .client
+            .post("https://test.com/zosmf/restfiles/ds/JIAHJ.REST.TEST.LDS")
+            .json(&json)
+            .build()
+            .unwrap();
+
+        let create_linear = zosmf
+            .datasets()
+            .create_vsam("JIAHJ.REST.TEST.LDS")
+            .cluster_type(VsamClusterType::Linear)
+            .space_allocation_unit("TRK")
+            .primary_space(100)
+            .storage_class("SCTEST")
+            .get_request()
+            .unwrap();
+
+        assert_eq!(
+            format!("{:?}", manual_request),
+            format!("{:?}", create_linear)
+        );
+
+        assert_eq!(manual_request.json(), create_linear.json());
+    }
+}
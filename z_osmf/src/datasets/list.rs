@@ -2,6 +2,7 @@ use std::marker::PhantomData;
 use std::sync::Arc;
 
 use chrono::NaiveDate;
+use futures_core::Stream;
 use reqwest::RequestBuilder;
 use serde::{Deserialize, Deserializer, Serialize};
 use z_osmf_macros::{Endpoint, Getters};
@@ -10,7 +11,7 @@ use crate::convert::TryFromResponse;
 use crate::restfiles::get_transaction_id;
 use crate::{ClientCore, Result};
 
-use super::{de_optional_y_n, ser_optional_y_n};
+use super::{de_optional_y_n, next_page_size, paginate, ser_optional_y_n, ListItem};
 
 #[derive(Clone, Debug, Deserialize, Eq, Getters, Hash, Ord, PartialEq, PartialOrd, Serialize)]
 pub struct DatasetAttributesBase {
@@ -76,12 +77,24 @@ pub struct DatasetAttributesBase {
     volumes: Option<Arc<str>>,
 }
 
+impl ListItem for DatasetAttributesBase {
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, Eq, Getters, Hash, Ord, PartialEq, PartialOrd, Serialize)]
 pub struct DatasetAttributesName {
     #[serde(rename = "dsname")]
     name: Arc<str>,
 }
 
+impl ListItem for DatasetAttributesName {
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, Eq, Getters, Hash, Ord, PartialEq, PartialOrd, Serialize)]
 pub struct DatasetAttributesVolume {
     #[serde(rename = "dsname")]
@@ -90,6 +103,12 @@ pub struct DatasetAttributesVolume {
     volume: DatasetVolume,
 }
 
+impl ListItem for DatasetAttributesVolume {
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, Eq, Getters, Hash, Ord, PartialEq, PartialOrd, Serialize)]
 pub struct DatasetList<T> {
     items: Arc<[T]>,
@@ -130,6 +149,39 @@ where
     }
 }
 
+impl<T> DatasetList<T>
+where
+    T: ListItem,
+{
+    /// The `start` of the next page when z/OSMF has more rows, which is the name of the last item.
+    ///
+    /// z/OSMF includes the `start` item in the next page, so it repeats as that page's first item.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let list = zosmf.datasets().list("IBMUSER.**").max_items(100).build().await?;
+    ///
+    /// if let Some(start) = list.next_start() {
+    ///     let next = zosmf
+    ///         .datasets()
+    ///         .list("IBMUSER.**")
+    ///         .max_items(100)
+    ///         .start(start)
+    ///         .build()
+    ///         .await?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn next_start(&self) -> Option<String> {
+        match self.more_rows {
+            Some(true) => self.items.last().map(|item| item.name().to_string()),
+            _ => None,
+        }
+    }
+}
+
 #[derive(Clone, Debug, Endpoint)]
 #[endpoint(method = get, path = "/zosmf/restfiles/ds")]
 pub struct DatasetListBuilder<T>
@@ -198,7 +250,54 @@ where
     }
 }
 
+impl<U> DatasetListBuilder<DatasetList<U>>
+where
+    U: ListItem + Clone + for<'de> Deserialize<'de>,
+{
+    /// Stream every matching dataset, requesting further pages as needed.
+    ///
+    /// The page size is taken from [`max_items`](Self::max_items).
+    ///
+    /// # Example
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// use futures_util::TryStreamExt;
+    ///
+    /// let mut datasets = std::pin::pin!(zosmf
+    ///     .datasets()
+    ///     .list("IBMUSER.**")
+    ///     .max_items(100)
+    ///     .into_stream());
+    ///
+    /// while let Some(dataset) = datasets.try_next().await? {
+    ///     println!("{}", dataset.name());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn into_stream(self) -> impl Stream<Item = Result<U>> {
+        paginate(move |start| {
+            let builder = match start {
+                Some(start) => DatasetListBuilder {
+                    max_items: next_page_size(self.max_items),
+                    ..self.clone().start(start)
+                },
+                None => self.clone(),
+            };
+
+            async move {
+                let DatasetList {
+                    items, more_rows, ..
+                } = builder.build().await?;
+
+                Ok((items, more_rows == Some(true)))
+            }
+        })
+    }
+}
+
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[non_exhaustive]
 pub enum DatasetVolume {
     Alias,
     Migrated,
@@ -439,6 +538,54 @@ mod tests {
         );
     }
 
+    #[test]
+    fn next_start() {
+        let list = |more_rows| DatasetList {
+            items: ["SYS1.PARMLIB", "SYS1.PROCLIB"]
+                .map(|name| DatasetAttributesName { name: name.into() })
+                .into(),
+            json_version: 1,
+            more_rows,
+            returned_rows: 2,
+            total_rows: None,
+            transaction_id: "".into(),
+        };
+
+        assert_eq!(
+            list(Some(true)).next_start().as_deref(),
+            Some("SYS1.PROCLIB")
+        );
+        assert_eq!(list(Some(false)).next_start(), None);
+        assert_eq!(list(None).next_start(), None);
+    }
+
+    #[test]
+    fn include_total() {
+        let zosmf = get_zosmf();
+
+        let manual_request = zosmf
+            .core
+            .client
+            .get("https://test.com/zosmf/restfiles/ds")
+            .query(&[("dslevel", "IBMUSER.**")])
+            .header("X-IBM-Attributes", "base,total")
+            .build()
+            .unwrap();
+
+        let list_datasets_total = zosmf
+            .datasets()
+            .list("IBMUSER.**")
+            .attributes_base()
+            .include_total(true)
+            .get_request()
+            .unwrap();
+
+        assert_eq!(
+            format!("{:?}", manual_request),
+            format!("{:?}", list_datasets_total)
+        );
+    }
+
     #[test]
     fn test_de_optional_yes_no() {
         #[derive(Debug, Deserialize, PartialEq)]
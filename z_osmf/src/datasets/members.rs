@@ -2,13 +2,16 @@ use std::marker::PhantomData;
 use std::sync::Arc;
 
 use chrono::NaiveDate;
+use futures_core::Stream;
 use serde::{Deserialize, Serialize};
 use z_osmf_macros::{Endpoint, Getters};
 
 use crate::convert::TryFromResponse;
 use crate::{ClientCore, Result};
 
-use super::{de_optional_y_n, ser_optional_y_n, DatasetMigratedRecall};
+use super::{
+    de_optional_y_n, next_page_size, paginate, ser_optional_y_n, DatasetMigratedRecall, ListItem,
+};
 
 #[derive(Clone, Debug, Deserialize, Eq, Getters, Hash, Ord, PartialEq, PartialOrd, Serialize)]
 pub struct MemberAttributesBase {
@@ -65,12 +68,24 @@ pub struct MemberAttributesBase {
     ssi: Option<Arc<str>>,
 }
 
+impl ListItem for MemberAttributesBase {
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, Eq, Getters, Hash, Ord, PartialEq, PartialOrd, Serialize)]
 pub struct MemberAttributesName {
     #[serde(rename = "member")]
     name: Arc<str>,
 }
 
+impl ListItem for MemberAttributesName {
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, Eq, Getters, Hash, Ord, PartialEq, PartialOrd, Serialize)]
 pub struct MemberList<T> {
     items: Arc<[T]>,
@@ -107,6 +122,39 @@ where
     }
 }
 
+impl<T> MemberList<T>
+where
+    T: ListItem,
+{
+    /// The `start` of the next page when z/OSMF has more rows, which is the name of the last item.
+    ///
+    /// z/OSMF includes the `start` item in the next page, so it repeats as that page's first item.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let list = zosmf.datasets().members("SYS1.PROCLIB").max_items(100).build().await?;
+    ///
+    /// if let Some(start) = list.next_start() {
+    ///     let next = zosmf
+    ///         .datasets()
+    ///         .members("SYS1.PROCLIB")
+    ///         .max_items(100)
+    ///         .start(start)
+    ///         .build()
+    ///         .await?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn next_start(&self) -> Option<String> {
+        match self.more_rows {
+            Some(true) => self.items.last().map(|item| item.name().to_string()),
+            _ => None,
+        }
+    }
+}
+
 #[derive(Clone, Debug, Endpoint)]
 #[endpoint(method = get, path = "/zosmf/restfiles/ds/{dataset}/member")]
 pub struct MemberListBuilder<T>
@@ -166,6 +214,52 @@ where
     }
 }
 
+impl<U> MemberListBuilder<MemberList<U>>
+where
+    U: ListItem + Clone + for<'de> Deserialize<'de>,
+{
+    /// Stream every matching member, requesting further pages as needed.
+    ///
+    /// The page size is taken from [`max_items`](Self::max_items).
+    ///
+    /// # Example
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// use futures_util::TryStreamExt;
+    ///
+    /// let mut members = std::pin::pin!(zosmf
+    ///     .datasets()
+    ///     .members("SYS1.PROCLIB")
+    ///     .max_items(100)
+    ///     .into_stream());
+    ///
+    /// while let Some(member) = members.try_next().await? {
+    ///     println!("{}", member.name());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn into_stream(self) -> impl Stream<Item = Result<U>> {
+        paginate(move |start| {
+            let builder = match start {
+                Some(start) => MemberListBuilder {
+                    max_items: next_page_size(self.max_items),
+                    ..self.clone().start(start)
+                },
+                None => self.clone(),
+            };
+
+            async move {
+                let MemberList {
+                    items, more_rows, ..
+                } = builder.build().await?;
+
+                Ok((items, more_rows == Some(true)))
+            }
+        })
+    }
+}
+
 #[derive(Clone, Copy, Debug)]
 enum Attrs {
     Base,
@@ -277,4 +371,82 @@ mod tests {
             format!("{:?}", list_members_base)
         );
     }
+
+    #[test]
+    fn include_total() {
+        let zosmf = get_zosmf();
+
+        let manual_request = zosmf
+            .core
+            .client
+            .get("https://test.com/zosmf/restfiles/ds/NOTSYS1.PROCLIB/member")
+            .header("X-IBM-Attributes", "member,total")
+            .build()
+            .unwrap();
+
+        let list_members_total = zosmf
+            .datasets()
+            .members("NOTSYS1.PROCLIB")
+            .attributes_member()
+            .include_total(true)
+            .get_request()
+            .unwrap();
+
+        assert_eq!(
+            format!("{:?}", manual_request),
+            format!("{:?}", list_members_total)
+        );
+    }
+
+    #[test]
+    fn extra_header_and_query() {
+        let zosmf = get_zosmf();
+
+        let manual_request = zosmf
+            .core
+            .client
+            .get("https://test.com/zosmf/restfiles/ds/NOTSYS1.PROCLIB/member")
+            .query(&[("pattern", "IEF*")])
+            .header("X-IBM-Max-Items", 10)
+            .header("X-CSRF-ZOSMF-HEADER", "true")
+            .query(&[("site", "A")])
+            .build()
+            .unwrap();
+
+        let list_members = zosmf
+            .datasets()
+            .members("NOTSYS1.PROCLIB")
+            .with_header("X-CSRF-ZOSMF-HEADER", "true")
+            .pattern("IEF*")
+            .with_query("site", "A")
+            .max_items(10)
+            .get_request()
+            .unwrap();
+
+        assert_eq!(
+            format!("{:?}", manual_request),
+            format!("{:?}", list_members)
+        );
+    }
+
+    #[test]
+    fn request_parts() {
+        let zosmf = get_zosmf();
+
+        let (method, url, headers, body) = zosmf
+            .datasets()
+            .members("NOTSYS1.PROCLIB")
+            .pattern("IEF*")
+            .attributes_base()
+            .into_request_parts()
+            .unwrap();
+
+        assert_eq!(method, reqwest::Method::GET);
+        assert_eq!(
+            url.as_str(),
+            "https://test.com/zosmf/restfiles/ds/NOTSYS1.PROCLIB/member?pattern=IEF*"
+        );
+        assert_eq!(headers["X-IBM-Attributes"], "base");
+        assert!(body.is_none());
+    }
 }
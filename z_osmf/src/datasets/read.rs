@@ -1,16 +1,20 @@
 pub use crate::utils::RecordRange;
 
 use std::marker::PhantomData;
+use std::path::Path;
 use std::sync::Arc;
 
 use bytes::Bytes;
+use futures_core::Stream;
+use futures_util::TryStreamExt;
 use reqwest::StatusCode;
 use serde::{Deserialize, Serialize};
+use tokio::io::AsyncWriteExt;
 use z_osmf_macros::{Endpoint, Getters};
 
 use crate::convert::TryFromResponse;
-use crate::restfiles::{get_etag, get_transaction_id};
-use crate::{ClientCore, Result};
+use crate::restfiles::{get_etag, get_transaction_id, Etag};
+use crate::{ClientCore, Error, ReadPayload, Result};
 
 use super::{
     get_member, get_session_ref, get_volume, DatasetDataType, DatasetEnqueue, DatasetMigratedRecall,
@@ -21,25 +25,69 @@ pub struct DatasetRead<T> {
     #[getter(skip)]
     data: T,
     etag: Option<Arc<str>>,
+    record_layout: Option<RecordLayout>,
     session_ref: Option<Arc<str>>,
     transaction_id: Arc<str>,
 }
 
+/// The record format and length of a dataset, used to split the data of a read into records.
+#[derive(Clone, Debug, Deserialize, Eq, Getters, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub struct RecordLayout {
+    record_format: Arc<str>,
+    record_length: u16,
+}
+
+impl RecordLayout {
+    /// Whether the records have a fixed length, like `F`, `FB` or `FBA` records.
+    pub fn is_fixed(&self) -> bool {
+        self.record_format.starts_with(['F', 'f'])
+    }
+}
+
+impl<T> ReadPayload for DatasetRead<T>
+where
+    T: ReadPayload,
+{
+    fn as_text(&self) -> Option<&str> {
+        self.data.as_text()
+    }
+
+    fn as_bytes(&self) -> &[u8] {
+        self.data.as_bytes()
+    }
+}
+
 impl DatasetRead<Arc<str>> {
     pub fn data(&self) -> &str {
         &self.data
     }
 }
 
+impl std::ops::Deref for DatasetRead<Arc<str>> {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        &self.data
+    }
+}
+
+impl AsRef<str> for DatasetRead<Arc<str>> {
+    fn as_ref(&self) -> &str {
+        &self.data
+    }
+}
+
 impl TryFromResponse for DatasetRead<Arc<str>> {
     async fn try_from_response(value: reqwest::Response) -> Result<Self> {
         let (etag, session_ref, transaction_id) = get_headers(&value)?;
 
+        let record_layout = value.extensions().get::<RecordLayout>().cloned();
         let data = value.text().await?.into();
 
         Ok(DatasetRead {
             data,
             etag,
+            record_layout,
             session_ref,
             transaction_id,
         })
@@ -50,75 +98,195 @@ impl DatasetRead<Bytes> {
     pub fn data(&self) -> &Bytes {
         &self.data
     }
+
+    /// Split data read in [`record`](DatasetReadBuilder::record) mode into logical records.
+    ///
+    /// Records of a fixed length are split every `fixed_lrecl` bytes, or every record length
+    /// of a fixed [`with_record_layout`](DatasetReadBuilder::with_record_layout) when `None`.
+    /// Otherwise each record is expected to start with a 4-byte record descriptor word,
+    /// which is removed. Iteration stops at the first malformed descriptor.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let read = zosmf
+    ///     .datasets()
+    ///     .read("JIAHJ.REST.SRVMP")
+    ///     .record()
+    ///     .build()
+    ///     .await?;
+    ///
+    /// for record in read.records(None) {
+    ///     println!("{} bytes", record.len());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn records(&self, fixed_lrecl: Option<u16>) -> impl Iterator<Item = &[u8]> {
+        let fixed_lrecl = fixed_lrecl.or_else(|| {
+            self.record_layout
+                .as_ref()
+                .filter(|layout| layout.is_fixed())
+                .map(|layout| layout.record_length)
+        });
+        let mut rest: &[u8] = &self.data;
+
+        std::iter::from_fn(move || {
+            if rest.is_empty() {
+                return None;
+            }
+
+            let record = match fixed_lrecl {
+                Some(lrecl) => {
+                    let (record, tail) = rest.split_at(rest.len().min(lrecl.max(1) as usize));
+                    rest = tail;
+
+                    record
+                }
+                None => {
+                    let length = u16::from_be_bytes([*rest.first()?, *rest.get(1)?]) as usize;
+                    let record = rest.get(4..length)?;
+                    rest = &rest[length..];
+
+                    record
+                }
+            };
+
+            Some(record)
+        })
+    }
+}
+
+impl std::ops::Deref for DatasetRead<Bytes> {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        &self.data
+    }
+}
+
+impl AsRef<[u8]> for DatasetRead<Bytes> {
+    fn as_ref(&self) -> &[u8] {
+        &self.data
+    }
 }
 
 impl TryFromResponse for DatasetRead<Bytes> {
     async fn try_from_response(value: reqwest::Response) -> Result<Self> {
         let (etag, session_ref, transaction_id) = get_headers(&value)?;
 
+        let record_layout = value.extensions().get::<RecordLayout>().cloned();
         let data = value.bytes().await?;
 
         Ok(DatasetRead {
             data,
             etag,
+            record_layout,
             session_ref,
             transaction_id,
         })
     }
 }
 
-impl DatasetRead<Option<Arc<str>>> {
-    pub fn data(&self) -> Option<&str> {
-        self.data.as_deref()
+/// The result of a read made with [`if_none_match`](DatasetReadBuilder::if_none_match).
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub enum DatasetReadIfNoneMatch<T> {
+    /// The dataset has changed since the etag was taken, so its data was returned.
+    Modified(DatasetRead<T>),
+    /// The dataset still matches the etag, and z/OSMF responded with `304 Not Modified`.
+    NotModified { transaction_id: Arc<str> },
+}
+
+impl<T> DatasetReadIfNoneMatch<T> {
+    /// The z/OSMF transaction ID of the response.
+    pub fn transaction_id(&self) -> &str {
+        match self {
+            DatasetReadIfNoneMatch::Modified(read) => read.transaction_id(),
+            DatasetReadIfNoneMatch::NotModified { transaction_id } => transaction_id,
+        }
     }
 }
 
-impl TryFromResponse for DatasetRead<Option<Arc<str>>> {
-    async fn try_from_response(value: reqwest::Response) -> Result<Self> {
-        let (etag, session_ref, transaction_id) = get_headers(&value)?;
+impl<T> ReadPayload for DatasetReadIfNoneMatch<T>
+where
+    T: ReadPayload,
+{
+    fn as_text(&self) -> Option<&str> {
+        match self {
+            DatasetReadIfNoneMatch::Modified(read) => read.as_text(),
+            DatasetReadIfNoneMatch::NotModified { .. } => None,
+        }
+    }
 
-        let data = if value.status() == StatusCode::NOT_MODIFIED {
-            None
-        } else {
-            Some(value.text().await?.into())
-        };
+    fn as_bytes(&self) -> &[u8] {
+        match self {
+            DatasetReadIfNoneMatch::Modified(read) => read.as_bytes(),
+            DatasetReadIfNoneMatch::NotModified { .. } => &[],
+        }
+    }
+}
 
-        Ok(DatasetRead {
-            data,
-            etag,
-            session_ref,
-            transaction_id,
-        })
+impl<T> TryFromResponse for DatasetReadIfNoneMatch<T>
+where
+    DatasetRead<T>: TryFromResponse,
+{
+    async fn try_from_response(value: reqwest::Response) -> Result<Self> {
+        if value.status() == StatusCode::NOT_MODIFIED {
+            return Ok(DatasetReadIfNoneMatch::NotModified {
+                transaction_id: get_transaction_id(&value)?,
+            });
+        }
+
+        Ok(DatasetReadIfNoneMatch::Modified(
+            DatasetRead::try_from_response(value).await?,
+        ))
     }
 }
 
-impl DatasetRead<Option<Bytes>> {
-    pub fn data(&self) -> Option<&Bytes> {
-        self.data.as_ref()
+/// A dataset read whose body has not been consumed yet.
+///
+/// The etag, session reference and transaction ID are available immediately,
+/// while the data is received in chunks as the stream is polled.
+#[derive(Debug, Getters)]
+pub struct DatasetReadStream {
+    etag: Option<Arc<str>>,
+    session_ref: Option<Arc<str>>,
+    transaction_id: Arc<str>,
+    #[getter(skip)]
+    response: reqwest::Response,
+}
+
+impl DatasetReadStream {
+    /// Receive the next chunk of data, or `None` once the body is exhausted.
+    pub async fn chunk(&mut self) -> Result<Option<Bytes>> {
+        Ok(self.response.chunk().await?)
+    }
+
+    /// Convert into a stream of the remaining chunks of data.
+    pub fn into_stream(self) -> impl Stream<Item = Result<Bytes>> {
+        self.response.bytes_stream().map_err(Error::from)
     }
 }
 
-impl TryFromResponse for DatasetRead<Option<Bytes>> {
+impl TryFromResponse for DatasetReadStream {
     async fn try_from_response(value: reqwest::Response) -> Result<Self> {
         let (etag, session_ref, transaction_id) = get_headers(&value)?;
 
-        let data = if value.status() == StatusCode::NOT_MODIFIED {
-            None
-        } else {
-            Some(value.bytes().await?)
-        };
-
-        Ok(DatasetRead {
-            data,
+        Ok(DatasetReadStream {
             etag,
             session_ref,
             transaction_id,
+            response: value,
         })
     }
 }
 
 #[derive(Clone, Debug, Endpoint)]
-#[endpoint(method = get, path = "/zosmf/restfiles/ds{volume}/{dataset}{member}")]
+#[endpoint(
+    method = get,
+    path = "/zosmf/restfiles/ds{volume}/{dataset}{member}",
+    response_fn = insert_record_layout
+)]
 pub struct DatasetReadBuilder<T>
 where
     T: TryFromResponse,
@@ -137,7 +305,7 @@ where
     regex_search: Option<Arc<str>>,
     #[endpoint(skip_builder)]
     search_is_regex: Option<bool>,
-    #[endpoint(builder_fn = build_search_case_sensitive)]
+    #[endpoint(skip_setter, builder_fn = build_search_case_sensitive)]
     search_case_sensitive: Option<bool>,
     #[endpoint(query = "maxreturnsize")]
     search_max_return: Option<i32>,
@@ -153,6 +321,8 @@ where
     migrated_recall: Option<DatasetMigratedRecall>,
     #[endpoint(header = "X-IBM-Record-Range")]
     record_range: Option<RecordRange>,
+    #[endpoint(skip_setter, skip_builder)]
+    record_layout: Option<RecordLayout>,
     #[endpoint(header = "X-IBM-Obtain-ENQ")]
     obtain_enq: Option<DatasetEnqueue>,
     #[endpoint(header = "X-IBM-Session-Ref")]
@@ -165,10 +335,65 @@ where
     target_type: PhantomData<T>,
 }
 
+impl<T> DatasetReadBuilder<T>
+where
+    T: TryFromResponse,
+{
+    /// Set whether `search` and `regex_search` ignore case, as z/OSMF does by default.
+    ///
+    /// This maps directly to the `insensitive` query parameter.
+    pub fn case_insensitive(mut self, value: bool) -> Self {
+        self.search_case_sensitive = Some(!value);
+
+        self
+    }
+
+    /// Set whether `search` and `regex_search` match case.
+    ///
+    /// `search_case_sensitive(true)` is the same as `case_insensitive(false)`,
+    /// and sends `insensitive=false`.
+    pub fn search_case_sensitive(self, value: bool) -> Self {
+        self.case_insensitive(!value)
+    }
+
+    /// Set the record format and length of the dataset, so that
+    /// [`records`](DatasetRead::records) can split the data without fetching its attributes.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let read = zosmf
+    ///     .datasets()
+    ///     .read("SYS1.PARMLIB")
+    ///     .member("SMFPRM00")
+    ///     .binary()
+    ///     .with_record_layout("FB", 80)
+    ///     .build()
+    ///     .await?;
+    ///
+    /// for record in read.records(None) {
+    ///     println!("{}", String::from_utf8_lossy(record));
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_record_layout<R>(mut self, record_format: R, record_length: u16) -> Self
+    where
+        R: std::fmt::Display,
+    {
+        self.record_layout = Some(RecordLayout {
+            record_format: record_format.to_string().into(),
+            record_length,
+        });
+
+        self
+    }
+}
+
 impl<U> DatasetReadBuilder<DatasetRead<U>>
 where
     DatasetRead<U>: TryFromResponse,
-    DatasetRead<Option<U>>: TryFromResponse,
+    DatasetReadIfNoneMatch<U>: TryFromResponse,
 {
     pub fn binary(self) -> DatasetReadBuilder<DatasetRead<Bytes>> {
         DatasetReadBuilder {
@@ -187,6 +412,7 @@ where
             return_etag: self.return_etag,
             migrated_recall: self.migrated_recall,
             record_range: self.record_range,
+            record_layout: self.record_layout,
             obtain_enq: self.obtain_enq,
             session_ref: self.session_ref,
             release_enq: self.release_enq,
@@ -212,6 +438,7 @@ where
             return_etag: self.return_etag,
             migrated_recall: self.migrated_recall,
             record_range: self.record_range,
+            record_layout: self.record_layout,
             obtain_enq: self.obtain_enq,
             session_ref: self.session_ref,
             release_enq: self.release_enq,
@@ -237,6 +464,7 @@ where
             return_etag: self.return_etag,
             migrated_recall: self.migrated_recall,
             record_range: self.record_range,
+            record_layout: self.record_layout,
             obtain_enq: self.obtain_enq,
             session_ref: self.session_ref,
             release_enq: self.release_enq,
@@ -245,7 +473,128 @@ where
         }
     }
 
-    pub fn if_none_match<E>(self, etag: E) -> DatasetReadBuilder<DatasetRead<Option<U>>>
+    /// Receive the data as a stream instead of buffering it in memory.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let mut read_stream = zosmf
+    ///     .datasets()
+    ///     .read("SYS1.PARMLIB")
+    ///     .member("SMFPRM00")
+    ///     .binary()
+    ///     .stream()
+    ///     .build()
+    ///     .await?;
+    ///
+    /// println!("{}", read_stream.transaction_id());
+    ///
+    /// while let Some(chunk) = read_stream.chunk().await? {
+    ///     println!("received {} bytes", chunk.len());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn stream(self) -> DatasetReadBuilder<DatasetReadStream> {
+        DatasetReadBuilder {
+            core: self.core,
+            dataset: self.dataset,
+            volume: self.volume,
+            member: self.member,
+            search: self.search,
+            regex_search: self.regex_search,
+            search_is_regex: self.search_is_regex,
+            search_case_sensitive: self.search_case_sensitive,
+            search_max_return: self.search_max_return,
+            if_none_match: self.if_none_match,
+            data_type: self.data_type,
+            encoding: self.encoding,
+            return_etag: self.return_etag,
+            migrated_recall: self.migrated_recall,
+            record_range: self.record_range,
+            record_layout: self.record_layout,
+            obtain_enq: self.obtain_enq,
+            session_ref: self.session_ref,
+            release_enq: self.release_enq,
+            dsname_encoding: self.dsname_encoding,
+            target_type: PhantomData,
+        }
+    }
+
+    /// Write the data to a file without holding it all in memory.
+    ///
+    /// The file is created, or truncated if it already exists. Text data is decoded as UTF-8,
+    /// while binary and record data is written as raw bytes.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let etag = zosmf
+    ///     .datasets()
+    ///     .read("JIAHJ.REST.SRVMP")
+    ///     .binary()
+    ///     .download_to("srvmp.bin")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn download_to<P>(self, path: P) -> Result<Etag>
+    where
+        P: AsRef<Path>,
+    {
+        let is_text = matches!(self.data_type, None | Some(DatasetDataType::Text));
+
+        let mut read_stream = self.stream().build().await?;
+        let mut file = tokio::fs::File::create(path).await?;
+
+        let mut pending = Vec::new();
+        while let Some(chunk) = read_stream.chunk().await? {
+            if is_text {
+                file.write_all(decode_text(&mut pending, &chunk).as_bytes())
+                    .await?;
+            } else {
+                file.write_all(&chunk).await?;
+            }
+        }
+        if !pending.is_empty() {
+            file.write_all(String::from_utf8_lossy(&pending).as_bytes())
+                .await?;
+        }
+        file.flush().await?;
+
+        let DatasetReadStream {
+            etag,
+            transaction_id,
+            ..
+        } = read_stream;
+
+        Ok(Etag::new(etag, transaction_id))
+    }
+
+    /// Only return the data if the dataset no longer matches `etag`.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// # let etag = "";
+    /// use z_osmf::datasets::read::DatasetReadIfNoneMatch;
+    ///
+    /// let read = zosmf
+    ///     .datasets()
+    ///     .read("SYS1.PARMLIB")
+    ///     .member("SMFPRM00")
+    ///     .if_none_match(etag)
+    ///     .build()
+    ///     .await?;
+    ///
+    /// match read {
+    ///     DatasetReadIfNoneMatch::Modified(read) => println!("{}", read.data()),
+    ///     DatasetReadIfNoneMatch::NotModified { .. } => println!("unchanged"),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn if_none_match<E>(self, etag: E) -> DatasetReadBuilder<DatasetReadIfNoneMatch<U>>
     where
         E: std::fmt::Display,
     {
@@ -265,6 +614,7 @@ where
             return_etag: self.return_etag,
             migrated_recall: self.migrated_recall,
             record_range: self.record_range,
+            record_layout: self.record_layout,
             obtain_enq: self.obtain_enq,
             session_ref: self.session_ref,
             release_enq: self.release_enq,
@@ -274,11 +624,11 @@ where
     }
 }
 
-impl<V> DatasetReadBuilder<DatasetRead<Option<V>>>
+impl<V> DatasetReadBuilder<DatasetReadIfNoneMatch<V>>
 where
-    DatasetRead<Option<V>>: TryFromResponse,
+    DatasetReadIfNoneMatch<V>: TryFromResponse,
 {
-    pub fn binary(self) -> DatasetReadBuilder<DatasetRead<Option<Bytes>>> {
+    pub fn binary(self) -> DatasetReadBuilder<DatasetReadIfNoneMatch<Bytes>> {
         DatasetReadBuilder {
             core: self.core,
             search: self.search,
@@ -295,6 +645,7 @@ where
             return_etag: self.return_etag,
             migrated_recall: self.migrated_recall,
             record_range: self.record_range,
+            record_layout: self.record_layout,
             obtain_enq: self.obtain_enq,
             session_ref: self.session_ref,
             release_enq: self.release_enq,
@@ -303,7 +654,7 @@ where
         }
     }
 
-    pub fn record(self) -> DatasetReadBuilder<DatasetRead<Option<Bytes>>> {
+    pub fn record(self) -> DatasetReadBuilder<DatasetReadIfNoneMatch<Bytes>> {
         DatasetReadBuilder {
             core: self.core,
             search: self.search,
@@ -320,6 +671,7 @@ where
             return_etag: self.return_etag,
             migrated_recall: self.migrated_recall,
             record_range: self.record_range,
+            record_layout: self.record_layout,
             obtain_enq: self.obtain_enq,
             session_ref: self.session_ref,
             release_enq: self.release_enq,
@@ -328,7 +680,7 @@ where
         }
     }
 
-    pub fn text(self) -> DatasetReadBuilder<DatasetRead<Option<Arc<str>>>> {
+    pub fn text(self) -> DatasetReadBuilder<DatasetReadIfNoneMatch<Arc<str>>> {
         DatasetReadBuilder {
             core: self.core,
             search: self.search,
@@ -345,6 +697,7 @@ where
             return_etag: self.return_etag,
             migrated_recall: self.migrated_recall,
             record_range: self.record_range,
+            record_layout: self.record_layout,
             obtain_enq: self.obtain_enq,
             session_ref: self.session_ref,
             release_enq: self.release_enq,
@@ -381,6 +734,15 @@ where
     }
 }
 
+fn insert_record_layout<T>(response: &mut reqwest::Response, builder: &DatasetReadBuilder<T>)
+where
+    T: TryFromResponse,
+{
+    if let Some(record_layout) = &builder.record_layout {
+        response.extensions_mut().insert(record_layout.clone());
+    }
+}
+
 fn build_release_enq<T>(
     request_builder: reqwest::RequestBuilder,
     builder: &DatasetReadBuilder<T>,
@@ -420,6 +782,42 @@ where
     }
 }
 
+/// Decode a chunk of UTF-8 text, holding back a trailing incomplete character
+/// in `pending` until the next chunk arrives.
+fn decode_text(pending: &mut Vec<u8>, chunk: &[u8]) -> String {
+    pending.extend_from_slice(chunk);
+
+    let mut text = String::new();
+    loop {
+        match std::str::from_utf8(pending) {
+            Ok(valid) => {
+                text.push_str(valid);
+                pending.clear();
+
+                break;
+            }
+            Err(err) => {
+                let (valid, rest) = pending.split_at(err.valid_up_to());
+                text.push_str(&String::from_utf8_lossy(valid));
+
+                match err.error_len() {
+                    Some(len) => {
+                        text.push(char::REPLACEMENT_CHARACTER);
+                        *pending = rest[len..].to_vec();
+                    }
+                    None => {
+                        *pending = rest.to_vec();
+
+                        break;
+                    }
+                }
+            }
+        }
+    }
+
+    text
+}
+
 type H = (Option<Arc<str>>, Option<Arc<str>>, Arc<str>);
 
 fn get_headers(response: &reqwest::Response) -> Result<H> {
@@ -448,6 +846,40 @@ where
 mod tests {
     use crate::tests::*;
 
+    use super::*;
+
+    #[tokio::test]
+    async fn if_none_match_response() {
+        let response = |status: u16, body: &'static str| {
+            reqwest::Response::from(
+                http::Response::builder()
+                    .status(status)
+                    .header("X-IBM-Txid", "TX0001")
+                    .body(body)
+                    .unwrap(),
+            )
+        };
+
+        let read = DatasetReadIfNoneMatch::<Arc<str>>::try_from_response(response(304, ""))
+            .await
+            .unwrap();
+        assert_eq!(
+            read,
+            DatasetReadIfNoneMatch::NotModified {
+                transaction_id: "TX0001".into()
+            }
+        );
+
+        let read = DatasetReadIfNoneMatch::<Arc<str>>::try_from_response(response(200, ""))
+            .await
+            .unwrap();
+        match &read {
+            DatasetReadIfNoneMatch::Modified(read) => assert_eq!(read.data(), ""),
+            other => panic!("unexpected read: {:?}", other),
+        }
+        assert_eq!(read.transaction_id(), "TX0001");
+    }
+
     #[test]
     fn example_1() {
         let zosmf = get_zosmf();
@@ -494,4 +926,112 @@ mod tests {
             format!("{:?}", read_dataset)
         )
     }
+
+    #[test]
+    fn stream() {
+        let zosmf = get_zosmf();
+
+        let manual_request = zosmf
+            .core
+            .client
+            .get("https://test.com/zosmf/restfiles/ds/JIAHJ.REST.SRVMP")
+            .header("X-IBM-Data-Type", "binary")
+            .build()
+            .unwrap();
+
+        let read_stream = zosmf
+            .datasets()
+            .read("JIAHJ.REST.SRVMP")
+            .binary()
+            .stream()
+            .get_request()
+            .unwrap();
+
+        assert_eq!(
+            format!("{:?}", manual_request),
+            format!("{:?}", read_stream)
+        )
+    }
+
+    #[test]
+    fn decode_text_chunks() {
+        let mut pending = Vec::new();
+        let bytes = "caf\u{e9} \u{2713}".as_bytes();
+
+        let mut text = decode_text(&mut pending, &bytes[..4]);
+        assert_eq!(pending, &bytes[3..4]);
+        text.push_str(&decode_text(&mut pending, &bytes[4..7]));
+        text.push_str(&decode_text(&mut pending, &bytes[7..]));
+        assert!(pending.is_empty());
+        assert_eq!(text, "caf\u{e9} \u{2713}");
+
+        assert_eq!(decode_text(&mut pending, b"a\xffb"), "a\u{fffd}b");
+        assert!(pending.is_empty());
+    }
+
+    #[test]
+    fn records() {
+        let read = |data: &'static [u8]| DatasetRead {
+            data: Bytes::from_static(data),
+            etag: None,
+            record_layout: None,
+            session_ref: None,
+            transaction_id: "".into(),
+        };
+
+        let variable = read(b"\x00\x07\x00\x00ABC\x00\x04\x00\x00\x00\x06\x00\x00DE");
+        assert_eq!(
+            variable.records(None).collect::<Vec<_>>(),
+            [&b"ABC"[..], b"", b"DE"]
+        );
+
+        let truncated = read(b"\x00\x07\x00\x00ABC\x00\x09\x00\x00DE");
+        assert_eq!(truncated.records(None).collect::<Vec<_>>(), [b"ABC"]);
+
+        let fixed = read(b"ABCDEFGH");
+        assert_eq!(
+            fixed.records(Some(3)).collect::<Vec<_>>(),
+            [&b"ABC"[..], b"DEF", b"GH"]
+        );
+
+        let layout = |record_format: &str| DatasetRead {
+            record_layout: Some(RecordLayout {
+                record_format: record_format.into(),
+                record_length: 4,
+            }),
+            ..read(b"\x00\x06\x00\x00ABCDEF")
+        };
+        assert_eq!(
+            layout("FB").records(None).collect::<Vec<_>>(),
+            [&b"\x00\x06\x00\x00"[..], b"ABCD", b"EF"]
+        );
+        assert_eq!(layout("FB").records(Some(5)).count(), 2);
+        assert_eq!(layout("VB").records(None).collect::<Vec<_>>(), [b"AB"]);
+    }
+
+    #[tokio::test]
+    async fn record_layout_response() {
+        let builder = get_zosmf()
+            .datasets()
+            .read("SYS1.PARMLIB")
+            .binary()
+            .with_record_layout("FB", 80);
+
+        let mut response = reqwest::Response::from(
+            http::Response::builder()
+                .header("X-IBM-Txid", "TX0001")
+                .body("")
+                .unwrap(),
+        );
+        insert_record_layout(&mut response, &builder);
+
+        let read = DatasetRead::<Bytes>::try_from_response(response)
+            .await
+            .unwrap();
+        assert_eq!(
+            read.record_layout().map(|layout| *layout.record_length()),
+            Some(80)
+        );
+        assert!(read.record_layout().unwrap().is_fixed());
+    }
 }
@@ -5,9 +5,9 @@ use serde::Serialize;
 use z_osmf_macros::Endpoint;
 
 use crate::convert::TryFromResponse;
-use crate::ClientCore;
+use crate::{ClientCore, Error, Result};
 
-use super::{get_member, DatasetEnqueue};
+use super::{get_member, DatasetEnqueue, DatasetsClient};
 
 #[derive(Clone, Debug, Endpoint)]
 #[endpoint(method = put, path = "/zosmf/restfiles/ds/{to_dataset}{to_member}")]
@@ -31,6 +31,81 @@ where
     target_type: PhantomData<T>,
 }
 
+impl DatasetRenameBuilder<String> {
+    /// Once the dataset or member is renamed, check that the new name exists
+    /// and the old one no longer does, failing with [`Error::Unexpected`] otherwise.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let rename_dataset = zosmf
+    ///     .datasets()
+    ///     .rename("MY.OLD.DSN", "MY.NEW.DSN")
+    ///     .and_verify()
+    ///     .build()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn and_verify(self) -> DatasetRenameVerifyBuilder {
+        DatasetRenameVerifyBuilder { rename: self }
+    }
+}
+
+/// Rename a dataset or member, then check that only the new name exists.
+#[derive(Clone, Debug)]
+pub struct DatasetRenameVerifyBuilder {
+    rename: DatasetRenameBuilder<String>,
+}
+
+impl DatasetRenameVerifyBuilder {
+    pub async fn build(self) -> Result<String> {
+        let DatasetRenameVerifyBuilder { rename } = self;
+        let datasets = DatasetsClient::new(ClientCore::clone(&rename.core));
+        let from = (rename.from_dataset.clone(), rename.from_member.clone());
+        let to = (rename.to_dataset.clone(), rename.to_member.clone());
+
+        let transaction_id = rename.build().await?;
+
+        let exists = |(dataset, member): (Arc<str>, Option<Arc<str>>)| {
+            let datasets = &datasets;
+
+            async move {
+                let exists = match &member {
+                    Some(member) => {
+                        let member_list =
+                            datasets.members(&dataset).pattern(member).build().await?;
+
+                        member_list
+                            .items()
+                            .iter()
+                            .any(|item| item.name().eq_ignore_ascii_case(member))
+                    }
+                    None => datasets.exists(&dataset).await?,
+                };
+
+                Ok::<_, Error>((format!("{}{}", dataset, get_member(&member)), exists))
+            }
+        };
+
+        if let (name, false) = exists(to).await? {
+            return Err(Error::Unexpected(format!(
+                "{} does not exist after the rename",
+                name
+            )));
+        }
+
+        if let (name, true) = exists(from).await? {
+            return Err(Error::Unexpected(format!(
+                "{} still exists after the rename",
+                name
+            )));
+        }
+
+        Ok(transaction_id)
+    }
+}
+
 #[derive(Serialize)]
 #[serde(rename_all = "kebab-case")]
 struct RequestJson<'a> {
@@ -70,3 +145,75 @@ where
 {
     get_member(&builder.to_member)
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{get_zosmf, GetJson};
+
+    use super::*;
+
+    #[test]
+    fn rename_dataset() {
+        let zosmf = get_zosmf();
+
+        let json = r#"
+        {
+            "request": "rename",
+            "from-dataset": {
+                "dsn": "MY.OLD.DSN"
+            }
+        }
+        "#;
+        let manual_request = zosmf
+            .core
+            .client
+            .put("https://test.com/zosmf/restfiles/ds/MY.NEW.DSN")
+            .json(&serde_json::from_str::<serde_json::Value>(json).unwrap())
+            .build()
+            .unwrap();
+
+        let request = zosmf
+            .datasets()
+            .rename("MY.OLD.DSN", "MY.NEW.DSN")
+            .get_request()
+            .unwrap();
+
+        assert_eq!(format!("{:?}", manual_request), format!("{:?}", request));
+        assert_eq!(manual_request.json(), request.json());
+    }
+
+    #[test]
+    fn rename_member() {
+        let zosmf = get_zosmf();
+
+        let json = r#"
+        {
+            "request": "rename",
+            "from-dataset": {
+                "dsn": "MY.DATA.SET",
+                "member": "OLDMEM"
+            },
+            "enq": "EXCLU"
+        }
+        "#;
+        let manual_request = zosmf
+            .core
+            .client
+            .put("https://test.com/zosmf/restfiles/ds/MY.OTHER.SET(NEWMEM)")
+            .json(&serde_json::from_str::<serde_json::Value>(json).unwrap())
+            .build()
+            .unwrap();
+
+        let request = zosmf
+            .datasets()
+            .rename("MY.DATA.SET", "MY.OTHER.SET")
+            .from_member("OLDMEM")
+            .to_member("NEWMEM")
+            .enqueue(DatasetEnqueue::Exclu)
+            .get_request()
+            .unwrap();
+
+        assert_eq!(format!("{:?}", manual_request), format!("{:?}", request));
+        assert_eq!(manual_request.json(), request.json());
+    }
+}
@@ -0,0 +1,259 @@
+use std::sync::Arc;
+
+use futures_util::{StreamExt, TryStreamExt};
+use serde::{Deserialize, Serialize};
+use z_osmf_macros::Getters;
+
+use crate::{ClientCore, Result};
+
+use super::members::{MemberAttributesName, MemberList, MemberListBuilder};
+use super::read::{DatasetRead, DatasetReadBuilder};
+
+/// The lines of a member that matched a search.
+#[derive(Clone, Debug, Deserialize, Eq, Getters, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub struct MemberSearchMatch {
+    member: Arc<str>,
+    lines: Arc<[Arc<str>]>,
+}
+
+/// Search every member of a partitioned dataset for a string or regular expression.
+///
+/// Each member is read in full and matched on the client, so every matching line is returned.
+/// Members are read concurrently, by default 4 at a time,
+/// and results are returned in member order. Each member has its own result,
+/// so a member that cannot be read does not stop the search of the others.
+#[derive(Clone, Debug)]
+pub struct DatasetSearchMembersBuilder {
+    core: ClientCore,
+    dataset: Arc<str>,
+    pattern: Arc<str>,
+    member_pattern: Option<Arc<str>>,
+    #[cfg(feature = "regex")]
+    regex: bool,
+    case_sensitive: bool,
+    concurrency: usize,
+}
+
+impl DatasetSearchMembersBuilder {
+    pub(super) fn new(core: ClientCore, dataset: Arc<str>, pattern: Arc<str>) -> Self {
+        DatasetSearchMembersBuilder {
+            core,
+            dataset,
+            pattern,
+            member_pattern: None,
+            #[cfg(feature = "regex")]
+            regex: false,
+            case_sensitive: false,
+            concurrency: 4,
+        }
+    }
+
+    /// Only search members whose names match a pattern, like `IEF*`.
+    pub fn member_pattern<P>(mut self, value: P) -> Self
+    where
+        P: std::fmt::Display,
+    {
+        self.member_pattern = Some(value.to_string().into());
+
+        self
+    }
+
+    /// Treat the search pattern as a regular expression, using the syntax of the
+    /// [`regex`](https://docs.rs/regex) crate.
+    #[cfg(feature = "regex")]
+    pub fn regex(mut self, value: bool) -> Self {
+        self.regex = value;
+
+        self
+    }
+
+    pub fn case_sensitive(mut self, value: bool) -> Self {
+        self.case_sensitive = value;
+
+        self
+    }
+
+    /// Set the maximum number of members read at the same time.
+    pub fn concurrency(mut self, value: usize) -> Self {
+        self.concurrency = value.max(1);
+
+        self
+    }
+
+    /// Search the members, returning the members that matched or could not be read.
+    pub async fn build(self) -> Result<Vec<(Arc<str>, Result<MemberSearchMatch>)>> {
+        let matcher = Arc::new(self.matcher()?);
+
+        let mut members = MemberListBuilder::<MemberList<MemberAttributesName>>::new(
+            self.core.clone(),
+            self.dataset.clone(),
+        );
+        if let Some(member_pattern) = &self.member_pattern {
+            members = members.pattern(member_pattern);
+        }
+        let members: Vec<MemberAttributesName> = members.into_stream().try_collect().await?;
+
+        let results: Vec<(Arc<str>, Result<MemberSearchMatch>)> =
+            futures_util::stream::iter(members)
+                .map(|member| {
+                    let read = DatasetReadBuilder::<DatasetRead<Arc<str>>>::new(
+                        self.core.clone(),
+                        self.dataset.clone(),
+                    )
+                    .member(member.name());
+                    let matcher = matcher.clone();
+
+                    async move {
+                        let member: Arc<str> = member.name().into();
+
+                        let lines = match read.build().await {
+                            Ok(read) => matching_lines(read.data(), &matcher),
+                            Err(err) => return Some((member, Err(err))),
+                        };
+
+                        (!lines.is_empty()).then(|| {
+                            let search_match = MemberSearchMatch {
+                                member: member.clone(),
+                                lines,
+                            };
+
+                            (member, Ok(search_match))
+                        })
+                    }
+                })
+                .buffered(self.concurrency)
+                .filter_map(std::future::ready)
+                .collect()
+                .await;
+
+        Ok(results)
+    }
+
+    fn matcher(&self) -> Result<Matcher> {
+        #[cfg(feature = "regex")]
+        if self.regex {
+            let regex = regex::RegexBuilder::new(&self.pattern)
+                .case_insensitive(!self.case_sensitive)
+                .build()
+                .map_err(|err| crate::Error::InvalidValue(err.to_string()))?;
+
+            return Ok(Matcher::Regex(regex));
+        }
+
+        Ok(if self.case_sensitive {
+            Matcher::Text(self.pattern.clone())
+        } else {
+            Matcher::TextInsensitive(self.pattern.to_lowercase())
+        })
+    }
+}
+
+#[derive(Debug)]
+enum Matcher {
+    Text(Arc<str>),
+    TextInsensitive(String),
+    #[cfg(feature = "regex")]
+    Regex(regex::Regex),
+}
+
+impl Matcher {
+    fn is_match(&self, line: &str) -> bool {
+        match self {
+            Matcher::Text(pattern) => line.contains(pattern.as_ref()),
+            Matcher::TextInsensitive(pattern) => line.to_lowercase().contains(pattern.as_str()),
+            #[cfg(feature = "regex")]
+            Matcher::Regex(regex) => regex.is_match(line),
+        }
+    }
+}
+
+/// The lines of a member that matched.
+fn matching_lines(data: &str, matcher: &Matcher) -> Arc<[Arc<str>]> {
+    data.lines()
+        .filter(|line| matcher.is_match(line))
+        .map(Arc::from)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::get_zosmf;
+
+    use super::*;
+
+    #[test]
+    fn test_matching_lines() {
+        let data =
+            "//STEP1 EXEC PGM=IEFBR14\n\n//DD1 DD DSN=SYS1.PROCLIB\n//STEP2 EXEC PGM=iefbr14\n";
+
+        assert_eq!(
+            matching_lines(data, &Matcher::TextInsensitive("pgm=iefbr14".into())),
+            [
+                Arc::from("//STEP1 EXEC PGM=IEFBR14"),
+                Arc::from("//STEP2 EXEC PGM=iefbr14")
+            ]
+            .into()
+        );
+        assert_eq!(
+            matching_lines(data, &Matcher::Text("PGM=IEFBR14".into())),
+            [Arc::from("//STEP1 EXEC PGM=IEFBR14")].into()
+        );
+        assert!(matching_lines("", &Matcher::Text("PGM=IEFBR14".into())).is_empty());
+    }
+
+    #[test]
+    fn matches_past_first_hundred_lines() {
+        let data = (1..=250)
+            .map(|n| format!("//STEP{} EXEC PGM=IEFBR14", n))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        assert_eq!(
+            matching_lines(&data, &Matcher::Text("PGM=IEFBR14".into())).len(),
+            250
+        );
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn regex_matcher() {
+        let zosmf = get_zosmf();
+        let data = "//STEP1 EXEC PGM=IEFBR14\n//STEP2 EXEC PROC=X\n//STEP3 EXEC pgm=IEFBR14\n";
+
+        let search = zosmf
+            .datasets()
+            .search_members("SYS1.PROCLIB", r"EXEC PGM=\w+")
+            .regex(true);
+        assert_eq!(matching_lines(data, &search.matcher().unwrap()).len(), 2);
+
+        let search = search.case_sensitive(true);
+        assert_eq!(
+            matching_lines(data, &search.matcher().unwrap()),
+            [Arc::from("//STEP1 EXEC PGM=IEFBR14")].into()
+        );
+
+        let search = zosmf
+            .datasets()
+            .search_members("SYS1.PROCLIB", "(")
+            .regex(true);
+        assert!(matches!(
+            search.matcher(),
+            Err(crate::Error::InvalidValue(_))
+        ));
+    }
+
+    #[test]
+    fn defaults() {
+        let zosmf = get_zosmf();
+
+        let search = zosmf
+            .datasets()
+            .search_members("SYS1.PROCLIB", "IEFBR14")
+            .concurrency(0);
+
+        assert_eq!(search.concurrency, 1);
+        #[cfg(feature = "regex")]
+        assert!(!search.regex);
+        assert!(!search.case_sensitive);
+    }
+}
@@ -0,0 +1,75 @@
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use serde::Serialize;
+use z_osmf_macros::Endpoint;
+
+use crate::convert::TryFromResponse;
+use crate::ClientCore;
+
+#[derive(Clone, Debug, Endpoint)]
+#[endpoint(method = put, path = "/zosmf/restfiles/ds/{dataset}")]
+pub struct DatasetUncatalogBuilder<T>
+where
+    T: TryFromResponse,
+{
+    core: Arc<ClientCore>,
+
+    #[endpoint(path)]
+    dataset: Arc<str>,
+
+    #[endpoint(builder_fn = build_body)]
+    target_type: PhantomData<T>,
+}
+
+#[derive(Serialize)]
+struct RequestJson {
+    request: &'static str,
+}
+
+fn build_body<T>(
+    request_builder: reqwest::RequestBuilder,
+    _builder: &DatasetUncatalogBuilder<T>,
+) -> reqwest::RequestBuilder
+where
+    T: TryFromResponse,
+{
+    request_builder.json(&RequestJson {
+        request: "uncatalog",
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::*;
+
+    #[test]
+    fn example_1() {
+        let zosmf = get_zosmf();
+
+        let json = serde_json::json!({
+            "request": "uncatalog"
+        });
+
+        let manual_request = zosmf
+            .core
+            .client
+            .put("https://test.com/zosmf/restfiles/ds/JIAHJ.REST.TEST.DATASET")
+            .json(&json)
+            .build()
+            .unwrap();
+
+        let uncatalog_dataset = zosmf
+            .datasets()
+            .uncatalog("JIAHJ.REST.TEST.DATASET")
+            .get_request()
+            .unwrap();
+
+        assert_eq!(
+            format!("{:?}", manual_request),
+            format!("{:?}", uncatalog_dataset)
+        );
+
+        assert_eq!(manual_request.json(), uncatalog_dataset.json());
+    }
+}
@@ -2,10 +2,12 @@ use std::marker::PhantomData;
 use std::sync::Arc;
 
 use bytes::Bytes;
+use futures_core::Stream;
 use z_osmf_macros::Endpoint;
 
-use crate::convert::TryFromResponse;
-use crate::ClientCore;
+use crate::convert::{TryFromResponse, TryIntoTarget};
+use crate::restfiles::{send_stream, with_stream_body};
+use crate::{ClientCore, Result, Transacted};
 
 use super::{get_member, get_volume, DatasetEnqueue, DatasetMigratedRecall};
 
@@ -59,6 +61,40 @@ where
         }
     }
 
+    /// Upload binary data from a stream, without holding it all in memory.
+    ///
+    /// This replaces any data set before, and returns a [`DatasetWriteStreamBuilder`] that owns
+    /// the stream and sends it once, when it is built, so the request is never retried.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let chunks: Vec<Result<z_osmf::Bytes, std::io::Error>> = vec![
+    ///     Ok("first chunk".into()),
+    ///     Ok("second chunk".into()),
+    /// ];
+    ///
+    /// let write_dataset = zosmf
+    ///     .datasets()
+    ///     .write("JIAHJ.REST.LARGE")
+    ///     .body_stream(futures_util::stream::iter(chunks))
+    ///     .build()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn body_stream<S, E>(self, stream: S) -> DatasetWriteStreamBuilder<T>
+    where
+        S: Stream<Item = std::result::Result<Bytes, E>> + Send + 'static,
+        E: Into<Box<dyn std::error::Error + Send + Sync>>,
+    {
+        DatasetWriteStreamBuilder {
+            builder: DatasetWriteBuilder { data: None, ..self },
+            body: reqwest::Body::wrap_stream(stream),
+            data_type: "binary",
+        }
+    }
+
     pub fn record<B>(self, data: B) -> Self
     where
         B: Into<Bytes>,
@@ -80,6 +116,81 @@ where
     }
 }
 
+/// # DatasetWriteStreamBuilder
+///
+/// A dataset write with a streamed body, created by [`DatasetWriteBuilder::body_stream`].
+/// Building it consumes the stream, so it can only be sent once.
+#[derive(Debug)]
+pub struct DatasetWriteStreamBuilder<T>
+where
+    T: TryFromResponse,
+{
+    builder: DatasetWriteBuilder<T>,
+    body: reqwest::Body,
+    data_type: &'static str,
+}
+
+impl<T> DatasetWriteStreamBuilder<T>
+where
+    T: TryFromResponse,
+{
+    /// Send the stream in record mode, as records each prefixed with their 4-byte length,
+    /// like the data of a [`record`](crate::datasets::read::DatasetReadBuilder::record) read.
+    pub fn record(self) -> Self {
+        DatasetWriteStreamBuilder {
+            data_type: "record",
+            ..self
+        }
+    }
+
+    /// Build the request, with the streamed body, without sending it.
+    pub fn into_request_parts(
+        self,
+    ) -> Result<(
+        reqwest::Method,
+        reqwest::Url,
+        reqwest::header::HeaderMap,
+        Option<reqwest::Body>,
+    )> {
+        let mut request = self.into_request()?;
+        let body = request.body_mut().take();
+
+        Ok((
+            request.method().clone(),
+            request.url().clone(),
+            std::mem::take(request.headers_mut()),
+            body,
+        ))
+    }
+
+    pub async fn build(self) -> Result<T> {
+        let core = self.builder.core.clone();
+
+        send_stream(&core, self.into_request()?)
+            .await?
+            .try_into_target()
+            .await
+    }
+
+    /// Send the request like `build`, keeping the z/OSMF transaction ID of the response.
+    pub async fn build_transacted(self) -> Result<Transacted<T>> {
+        let core = self.builder.core.clone();
+
+        send_stream(&core, self.into_request()?)
+            .await?
+            .try_into_target()
+            .await
+    }
+
+    fn into_request(self) -> Result<reqwest::Request> {
+        Ok(with_stream_body(
+            self.builder.get_request()?,
+            self.data_type,
+            self.body,
+        ))
+    }
+}
+
 #[derive(Clone, Debug)]
 enum Data {
     Binary(Bytes),
@@ -153,8 +264,49 @@ where
 
 #[cfg(test)]
 mod tests {
+    use bytes::Bytes;
+
     use crate::tests::*;
 
+    #[test]
+    fn body_stream() {
+        let zosmf = get_zosmf();
+
+        let manual_request = zosmf
+            .core
+            .client
+            .put("https://test.com/zosmf/restfiles/ds/JIAHJ.REST.LARGE")
+            .header("X-IBM-Data-Type", "binary")
+            .build()
+            .unwrap();
+
+        let chunks: Vec<Result<Bytes, std::io::Error>> =
+            vec![Ok("some ".into()), Ok("data".into())];
+        let (method, url, headers, body) = zosmf
+            .datasets()
+            .write("JIAHJ.REST.LARGE")
+            .record("replaced by the stream")
+            .body_stream(futures_util::stream::iter(chunks))
+            .into_request_parts()
+            .unwrap();
+
+        assert_eq!(&method, manual_request.method());
+        assert_eq!(&url, manual_request.url());
+        assert_eq!(&headers, manual_request.headers());
+        assert!(body.is_some_and(|body| body.as_bytes().is_none()));
+
+        let (_, _, headers, _) = zosmf
+            .datasets()
+            .write("JIAHJ.REST.LARGE")
+            .body_stream(futures_util::stream::iter(Vec::<
+                Result<Bytes, std::io::Error>,
+            >::new()))
+            .record()
+            .into_request_parts()
+            .unwrap();
+        assert_eq!(headers["X-IBM-Data-Type"], "record");
+    }
+
     #[test]
     fn example_1() {
         let zosmf = get_zosmf();
@@ -189,4 +341,36 @@ mod tests {
             write_dataset.body().unwrap().as_bytes().unwrap()
         )
     }
+
+    #[test]
+    fn encoding() {
+        let zosmf = get_zosmf();
+
+        let manual_request = zosmf
+            .core
+            .client
+            .put("https://test.com/zosmf/restfiles/ds/SYS1.PARMLIB(SMFPRM00)")
+            .header("X-IBM-Data-Type", "text;fileEncoding=IBM-1047")
+            .body("here is some text!")
+            .build()
+            .unwrap();
+
+        let write_dataset = zosmf
+            .datasets()
+            .write("SYS1.PARMLIB")
+            .member("SMFPRM00")
+            .encoding("IBM-1047")
+            .text("here is some text!")
+            .get_request()
+            .unwrap();
+
+        assert_eq!(
+            format!("{:?}", manual_request),
+            format!("{:?}", write_dataset)
+        );
+        assert_eq!(
+            write_dataset.headers()["X-IBM-Data-Type"],
+            "text;fileEncoding=IBM-1047"
+        );
+    }
 }
@@ -1,54 +1,147 @@
 use std::sync::Arc;
 
+use reqwest::StatusCode;
 use serde::Deserialize;
 use thiserror::Error;
+use z_osmf_macros::Getters;
 
 pub type Result<T> = std::result::Result<T, Error>;
 
 #[derive(Debug, Error)]
 pub enum Error {
+    #[error("environment variable {name} is not usable: {source}")]
+    Env {
+        name: &'static str,
+        source: std::env::VarError,
+    },
     #[error("data serialization failed: {0}")]
     Fmt(#[from] std::fmt::Error),
     #[error("invalid response format: {0:?}")]
     InvalidFormat(Arc<[Arc<str>]>),
+    #[error("I/O operation failed: {0}")]
+    Io(#[from] std::io::Error),
     #[error("invalid value: {0}")]
     InvalidValue(String),
+    #[error("multi-factor authentication rejected: {0}")]
+    MfaRejected(Arc<str>),
+    #[error("copied {from} to {to} but failed to delete {from}: {source}")]
+    MoveCleanup {
+        from: Arc<str>,
+        to: Arc<str>,
+        source: Box<Error>,
+    },
     #[error("missing etag")]
     NoEtag,
     #[error("missing transaction id")]
     NoTransactionId,
     #[error("failed to parse int: {0}")]
     NumParseInt(#[from] std::num::ParseIntError),
+    #[error("precondition failed, current etag: {current_etag:?}")]
+    PreconditionFailed {
+        current_etag: Option<Arc<str>>,
+        transaction_id: Option<Arc<str>>,
+    },
     #[error("invalid record range: {0}")]
     RecordRange(String),
+    #[error("failed to build request: {0}")]
+    RequestBuild(#[source] reqwest::Error),
     #[error("API call failed: {0}")]
-    Reqwest(#[from] reqwest::Error),
-    #[error("poisoned read-write lock: {0}")]
-    RwLockPoisonError(String),
+    Reqwest(#[source] reqwest::Error),
+    #[error("poisoned read-write lock")]
+    RwLockPoisoned(#[source] std::sync::PoisonError<()>),
     #[error("data deserialization failed: {0}")]
     SerdeDe(#[from] serde::de::value::Error),
+    #[error("JSON conversion failed: {0}")]
+    SerdeJson(#[from] serde_json::Error),
     #[error("header value to string failed: {0}")]
     ReqwestHeaderToString(#[from] reqwest::header::ToStrError),
-    #[error("z/OSMF error response: {0:?}")]
+    #[error("response of {size} bytes exceeds the maximum of {max} bytes")]
+    ResponseTooLarge { size: u64, max: u64 },
+    #[error("request failed after {attempts} attempts: {source}")]
+    Retry {
+        attempts: u32,
+        transaction_ids: Arc<[Arc<str>]>,
+        source: Box<Error>,
+    },
+    #[error("timed out after {0:?}")]
+    Timeout(std::time::Duration),
+    #[error("unexpected result: {0}")]
+    Unexpected(String),
+    #[error("z/OSMF error response: {0}")]
     ZOsmf(ZOsmfError),
+    #[error("z/OSMF error response ({status}) from {url}: {body}")]
+    ZOsmfText {
+        url: Arc<str>,
+        status: StatusCode,
+        body: Arc<str>,
+    },
 }
 
-#[derive(Debug)]
-pub enum ZOsmfError {
-    Json {
-        url: String,
-        status: reqwest::StatusCode,
-        category: i32,
-        return_code: i32,
-        reason: i32,
-        message: String,
-        details: Option<Vec<String>>,
-    },
-    Text {
-        url: String,
-        status: reqwest::StatusCode,
-        body: String,
-    },
+impl Error {
+    /// The error of this crate that caused this one, for the errors that wrap another,
+    /// like [`Error::Retry`] and [`Error::MoveCleanup`].
+    pub fn cause(&self) -> Option<&Error> {
+        match self {
+            Error::MoveCleanup { source, .. } | Error::Retry { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+impl<T> From<std::sync::PoisonError<T>> for Error {
+    fn from(_: std::sync::PoisonError<T>) -> Self {
+        Error::RwLockPoisoned(std::sync::PoisonError::new(()))
+    }
+}
+
+impl From<reqwest::Error> for Error {
+    fn from(value: reqwest::Error) -> Self {
+        let mut source = std::error::Error::source(&value);
+        while let Some(err) = source {
+            if let Some(crate::retry::BodyTooLarge { size, max }) = err.downcast_ref() {
+                return Error::ResponseTooLarge {
+                    size: *size,
+                    max: *max,
+                };
+            }
+
+            source = err.source();
+        }
+
+        Error::Reqwest(value)
+    }
+}
+
+impl From<std::convert::Infallible> for Error {
+    fn from(value: std::convert::Infallible) -> Self {
+        match value {}
+    }
+}
+
+/// The JSON error body returned by z/OSMF for a failed request.
+#[derive(Clone, Debug, Eq, Getters, PartialEq)]
+pub struct ZOsmfError {
+    url: Arc<str>,
+    #[getter(copy)]
+    status: StatusCode,
+    #[getter(copy)]
+    category: i32,
+    #[getter(copy)]
+    return_code: i32,
+    #[getter(copy)]
+    reason: i32,
+    message: Arc<str>,
+    details: Option<Arc<[Arc<str>]>>,
+}
+
+impl std::fmt::Display for ZOsmfError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} from {} (category: {}, rc: {}, reason: {}): {}",
+            self.status, self.url, self.category, self.return_code, self.reason, self.message
+        )
+    }
 }
 
 pub trait CheckStatus {
@@ -61,8 +154,21 @@ impl CheckStatus for reqwest::Response {
     async fn check_status(self) -> Result<Self> {
         match self.error_for_status_ref() {
             Ok(_) => {}
+            Err(_) if self.status() == StatusCode::PRECONDITION_FAILED => {
+                let header = |name: &str| {
+                    self.headers()
+                        .get(name)
+                        .and_then(|value| value.to_str().ok())
+                        .map(|value| value.into())
+                };
+
+                return Err(Error::PreconditionFailed {
+                    current_etag: header("Etag"),
+                    transaction_id: header("X-IBM-Txid"),
+                });
+            }
             Err(err) => {
-                let url = self.url().to_string();
+                let url: Arc<str> = self.url().as_str().into();
                 let status = self.status();
                 let body = self.text().await.map_err(|_| Error::Reqwest(err))?;
                 let ErrorJson {
@@ -71,15 +177,13 @@ impl CheckStatus for reqwest::Response {
                     reason,
                     message,
                     details,
-                } = serde_json::from_str(&body).map_err(|_| {
-                    Error::ZOsmf(ZOsmfError::Text {
-                        url: url.clone(),
-                        status,
-                        body,
-                    })
+                } = serde_json::from_str(&body).map_err(|_| Error::ZOsmfText {
+                    url: url.clone(),
+                    status,
+                    body: body.clone().into(),
                 })?;
 
-                return Err(Error::ZOsmf(ZOsmfError::Json {
+                return Err(Error::ZOsmf(ZOsmfError {
                     url,
                     status,
                     category,
@@ -101,7 +205,127 @@ struct ErrorJson {
     #[serde(rename = "rc")]
     return_code: i32,
     reason: i32,
-    message: String,
+    message: Arc<str>,
     #[serde(default)]
-    details: Option<Vec<String>>,
+    details: Option<Arc<[Arc<str>]>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn check_status_json() {
+        let response = reqwest::Response::from(
+            http::Response::builder()
+                .status(404)
+                .body(
+                    r#"{"category":1,"rc":4,"reason":8,"message":"data set not found","details":["ISRZ002"]}"#,
+                )
+                .unwrap(),
+        );
+
+        match response.check_status().await {
+            Err(Error::ZOsmf(err)) => {
+                assert_eq!(err.status(), StatusCode::NOT_FOUND);
+                assert_eq!(err.category(), 1);
+                assert_eq!(err.return_code(), 4);
+                assert_eq!(err.reason(), 8);
+                assert_eq!(err.message(), "data set not found");
+                assert_eq!(err.details(), Some(&["ISRZ002".into()][..]));
+            }
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn check_status_text() {
+        let response = reqwest::Response::from(
+            http::Response::builder()
+                .status(503)
+                .body("Service Unavailable")
+                .unwrap(),
+        );
+
+        match response.check_status().await {
+            Err(Error::ZOsmfText { status, body, .. }) => {
+                assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+                assert_eq!(body.as_ref(), "Service Unavailable");
+            }
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn check_status_precondition_failed() {
+        let response = reqwest::Response::from(
+            http::Response::builder()
+                .status(412)
+                .header("Etag", "B5C6454F783590AA8EC15BD88E29EA63")
+                .header("X-IBM-Txid", "ZOSMFAD/0000001/0")
+                .body("")
+                .unwrap(),
+        );
+
+        match response.check_status().await {
+            Err(Error::PreconditionFailed {
+                current_etag,
+                transaction_id,
+            }) => {
+                assert_eq!(
+                    current_etag.as_deref(),
+                    Some("B5C6454F783590AA8EC15BD88E29EA63")
+                );
+                assert_eq!(transaction_id.as_deref(), Some("ZOSMFAD/0000001/0"));
+            }
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn source_chain() {
+        use std::error::Error as _;
+
+        let err = Error::Retry {
+            attempts: 3,
+            transaction_ids: [].into(),
+            source: Box::new(Error::Timeout(std::time::Duration::from_secs(1))),
+        };
+        assert!(matches!(err.cause(), Some(Error::Timeout(_))));
+        assert_eq!(err.source().unwrap().to_string(), "timed out after 1s");
+        assert!(Error::NoEtag.cause().is_none());
+
+        let lock = std::sync::RwLock::new(());
+        let _ = std::panic::catch_unwind(|| {
+            let _write = lock.write().unwrap();
+            panic!("poison the lock");
+        });
+        let err = Error::from(lock.read().unwrap_err());
+        assert!(matches!(err, Error::RwLockPoisoned(_)));
+        assert!(err
+            .source()
+            .is_some_and(|source| source.is::<std::sync::PoisonError<()>>()));
+
+        let err = Error::RequestBuild(reqwest::Client::new().get("not a url").build().unwrap_err());
+        assert!(err
+            .source()
+            .is_some_and(|source| source.is::<reqwest::Error>()));
+
+        let err = Error::from(std::io::Error::other("disk full"));
+        assert_eq!(err.source().unwrap().to_string(), "disk full");
+
+        let err = Error::from(serde_json::from_str::<i32>("nonsense").unwrap_err());
+        assert!(err
+            .source()
+            .is_some_and(|source| source.is::<serde_json::Error>()));
+
+        assert!(Error::NoEtag.source().is_none());
+    }
+
+    #[tokio::test]
+    async fn check_status_ok() {
+        let response = reqwest::Response::from(http::Response::new(""));
+
+        assert!(response.check_status().await.is_ok());
+    }
 }
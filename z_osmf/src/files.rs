@@ -1,41 +1,54 @@
+pub mod acl;
+pub mod audit;
 pub mod copy;
 pub mod copy_dataset;
 pub mod create;
 pub mod delete;
 pub mod extra_attributes;
+pub mod filesystems;
 pub mod link;
 pub mod list;
 pub mod mode;
+pub mod mount;
 pub mod owner;
 pub mod read;
 pub mod rename;
 pub mod tags;
+pub mod time;
 pub mod unlink;
 pub mod write;
 
 use std::sync::Arc;
 
+use futures_core::Stream;
 use serde::{Deserialize, Serialize};
 
+use crate::error::ZOsmfError;
 use crate::restfiles::Etag;
-use crate::{ClientCore, Result};
+use crate::{ClientCore, Error, Result};
 
-use self::copy::FileCopyBuilder;
+use self::acl::set::FileAclSetBuilder;
+use self::acl::{FileAclList, FileAclListBuilder};
+use self::audit::FileChangeAuditBuilder;
+use self::copy::{FileCopyBuilder, FileCopyPreserve};
 use self::copy_dataset::FileCopyDatasetBuilder;
 use self::create::FileCreateBuilder;
 use self::delete::FileDeleteBuilder;
 use self::extra_attributes::reset::FileExtraAttributesResetBuilder;
 use self::extra_attributes::set::FileExtraAttributesSetBuilder;
 use self::extra_attributes::{FileExtraAttributeList, FileExtraAttributeListBuilder};
+use self::filesystems::{FileSystemList, FileSystemListBuilder};
 use self::link::{FileLinkBuilder, FileLinkType};
-use self::list::{FileList, FileListBuilder};
-use self::mode::FileChangeModeBuilder;
+use self::list::{FileAttributes, FileList, FileListBuilder};
+use self::mode::{FileChangeModeBuilder, FileMode};
+use self::mount::{FileSystemMountBuilder, FileSystemUnmountBuilder};
 use self::owner::FileChangeOwnerBuilder;
 use self::read::{FileRead, FileReadBuilder};
 use self::rename::FileRenameBuilder;
 use self::tags::remove::FileTagsRemoveBuilder;
 use self::tags::set::FileTagsSetBuilder;
 use self::tags::{FileTagList, FileTagListBuilder};
+use self::time::FileChangeTimeBuilder;
 use self::unlink::FileUnlinkBuilder;
 use self::write::FileWriteBuilder;
 
@@ -50,6 +63,44 @@ impl FilesClient {
         FilesClient { core }
     }
 
+    /// # Examples
+    ///
+    /// Audit failed reads and writes of a file:
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// # use z_osmf::files::audit::FileAuditClass;
+    /// let change_audit = zosmf
+    ///     .files()
+    ///     .change_audit("/u/jiahj/test.txt")
+    ///     .read(FileAuditClass::Failure)
+    ///     .write(FileAuditClass::Failure)
+    ///     .build()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Set the auditor audit options of a directory and the files within:
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// # use z_osmf::files::audit::FileAuditClass;
+    /// let change_audit = zosmf
+    ///     .files()
+    ///     .change_audit("/u/jiahj/testDir")
+    ///     .auditor_write(FileAuditClass::All)
+    ///     .recursive(true)
+    ///     .build()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn change_audit<P>(&self, path: P) -> FileChangeAuditBuilder<String>
+    where
+        P: std::fmt::Display,
+    {
+        FileChangeAuditBuilder::new(self.core.clone(), path)
+    }
+
     /// # Examples
     ///
     /// Change the mode (permissions) of a file:
@@ -57,7 +108,7 @@ impl FilesClient {
     /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
     /// let change_mode = zosmf
     ///     .files()
-    ///     .change_mode("/u/jiahj/test.txt", "755")
+    ///     .change_mode("/u/jiahj/test.txt", 0o755)?
     ///     .build()
     ///     .await?;
     /// # Ok(())
@@ -69,19 +120,35 @@ impl FilesClient {
     /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
     /// let change_mode = zosmf
     ///     .files()
-    ///     .change_mode("/u/jiahj/testDir", "755")
+    ///     .change_mode("/u/jiahj/testDir", 0o755)?
     ///     .recursive(true)
     ///     .build()
     ///     .await?;
     /// # Ok(())
     /// # }
     /// ```
-    pub fn change_mode<P, M>(&self, path: P, mode: M) -> FileChangeModeBuilder<String>
+    ///
+    /// Change the mode using its octal or symbolic form,
+    /// failing with [`Error::InvalidValue`] if it is not a valid mode:
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let change_mode = zosmf
+    ///     .files()
+    ///     .change_mode("/u/jiahj/test.txt", "rwxr-x---")?
+    ///     .build()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn change_mode<P, M>(&self, path: P, mode: M) -> Result<FileChangeModeBuilder<String>>
     where
         P: std::fmt::Display,
-        M: std::fmt::Display,
+        M: TryInto<FileMode>,
+        M::Error: Into<Error>,
     {
-        FileChangeModeBuilder::new(self.core.clone(), path, mode)
+        let mode = mode.try_into().map_err(Into::into)?;
+
+        Ok(FileChangeModeBuilder::new(self.core.clone(), path, mode))
     }
 
     /// # Examples
@@ -131,6 +198,44 @@ impl FilesClient {
         FileChangeOwnerBuilder::new(self.core.clone(), path, owner)
     }
 
+    /// # Examples
+    ///
+    /// Set the modification time of a file:
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// # use chrono::NaiveDate;
+    /// let change_time = zosmf
+    ///     .files()
+    ///     .change_time("/u/jiahj/test.txt")
+    ///     .mtime(NaiveDate::from_ymd_opt(2024, 3, 1).unwrap().and_hms_opt(8, 30, 0).unwrap())
+    ///     .build()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Set the access and modification times of a directory and the files within:
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let now = chrono::Local::now().naive_local();
+    /// let change_time = zosmf
+    ///     .files()
+    ///     .change_time("/u/jiahj/testDir")
+    ///     .atime(now)
+    ///     .mtime(now)
+    ///     .recursive(true)
+    ///     .build()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn change_time<P>(&self, path: P) -> FileChangeTimeBuilder<String>
+    where
+        P: std::fmt::Display,
+    {
+        FileChangeTimeBuilder::new(self.core.clone(), path)
+    }
+
     /// # Examples
     ///
     /// Copy a file:
@@ -157,7 +262,7 @@ impl FilesClient {
     /// # Ok(())
     /// # }
     /// ```
-    pub fn copy<F, T>(&self, from_path: F, to_path: T) -> FileCopyBuilder<String>
+    pub fn copy<F, T>(&self, from_path: F, to_path: T) -> FileCopyBuilder<Etag>
     where
         F: std::fmt::Display,
         T: std::fmt::Display,
@@ -191,7 +296,7 @@ impl FilesClient {
     /// # Ok(())
     /// # }
     /// ```
-    pub fn copy_dataset<F, T>(&self, from_dataset: F, to_path: T) -> FileCopyDatasetBuilder<String>
+    pub fn copy_dataset<F, T>(&self, from_dataset: F, to_path: T) -> FileCopyDatasetBuilder<Etag>
     where
         F: std::fmt::Display,
         T: std::fmt::Display,
@@ -209,7 +314,7 @@ impl FilesClient {
     ///     .files()
     ///     .create("/u/jiahj/text.txt")
     ///     .file_type(FileCreateType::File)
-    ///     .mode("RWXRW-RW-")
+    ///     .mode("RWXRW-RW-")?
     ///     .build()
     ///     .await?;
     /// # Ok(())
@@ -224,13 +329,13 @@ impl FilesClient {
     ///     .files()
     ///     .create("/u/jiahj/testDir")
     ///     .file_type(FileCreateType::Directory)
-    ///     .mode("rwxr-xrwx")
+    ///     .mode(0o757)?
     ///     .build()
     ///     .await?;
     /// # Ok(())
     /// # }
     /// ```
-    pub fn create<P>(&self, path: P) -> FileCreateBuilder<String>
+    pub fn create<P>(&self, path: P) -> FileCreateBuilder<Etag>
     where
         P: std::fmt::Display,
     {
@@ -262,6 +367,19 @@ impl FilesClient {
     /// # Ok(())
     /// # }
     /// ```
+    ///
+    /// Delete a directory and everything within it:
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let delete_file = zosmf
+    ///     .files()
+    ///     .delete("/u/jiahj/testDir")
+    ///     .recursive(true)
+    ///     .build()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
     pub fn delete<P>(&self, path: P) -> FileDeleteBuilder<String>
     where
         P: std::fmt::Display,
@@ -269,6 +387,35 @@ impl FilesClient {
         FileDeleteBuilder::new(self.core.clone(), path)
     }
 
+    /// Check whether a file or directory exists, without listing a directory's contents.
+    ///
+    /// A path that z/OSMF reports as not found is `false`, while other failures,
+    /// like being denied permission to search a parent directory, are returned as errors.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// if !zosmf.files().exists("/u/jiahj/testDir").await? {
+    ///     zosmf
+    ///         .files()
+    ///         .create("/u/jiahj/testDir")
+    ///         .build()
+    ///         .await?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn exists<P>(&self, path: P) -> Result<bool>
+    where
+        P: std::fmt::Display,
+    {
+        match self.list(path).lstat(true).limit(1).get_response().await {
+            Ok(_) => Ok(true),
+            Err(err) if is_not_found(&err) => Ok(false),
+            Err(err) => Err(err),
+        }
+    }
+
     /// # Examples
     ///
     /// Get the extra attributes of a file:
@@ -290,6 +437,25 @@ impl FilesClient {
             .await
     }
 
+    /// # Examples
+    ///
+    /// List the access control list of a file:
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let acl = zosmf.files().get_acl("/u/jiahj/text.txt").build().await?;
+    /// for entry in acl.entries().iter() {
+    ///     println!("{}", entry);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_acl<P>(&self, path: P) -> FileAclListBuilder<FileAclList>
+    where
+        P: std::fmt::Display,
+    {
+        FileAclListBuilder::new(self.core.clone(), path)
+    }
+
     /// # Examples
     ///
     /// Link a file or directory:
@@ -362,6 +528,51 @@ impl FilesClient {
         FileListBuilder::new(self.core.clone(), path)
     }
 
+    /// # Examples
+    ///
+    /// List all mounted file systems:
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let list_filesystems = zosmf.files().list_filesystems().build().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Find the file system mounted at a path:
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let list_filesystems = zosmf
+    ///     .files()
+    ///     .list_filesystems()
+    ///     .path("/u/ibmuser")
+    ///     .build()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_filesystems(&self) -> FileSystemListBuilder<FileSystemList> {
+        FileSystemListBuilder::new(self.core.clone())
+    }
+
+    /// Recursively list every entry below a directory, breadth-first,
+    /// without crossing mount points or following symbolic links.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// use futures_util::TryStreamExt;
+    ///
+    /// let files: Vec<_> = zosmf.files().walk("/u/ibmuser").try_collect().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn walk<P>(&self, path: P) -> impl Stream<Item = Result<FileAttributes>>
+    where
+        P: std::fmt::Display,
+    {
+        self.list(path).walk()
+    }
+
     /// # Examples
     ///
     /// List the tag of a file:
@@ -395,6 +606,80 @@ impl FilesClient {
         FileTagListBuilder::new(self.core.clone(), path)
     }
 
+    /// # Examples
+    ///
+    /// Mount a zFS file system read-only:
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// # use z_osmf::files::mount::FileSystemMountMode;
+    /// let mount = zosmf
+    ///     .files()
+    ///     .mount("IBMUSER.TEST.ZFS", "/u/ibmuser/mnt")
+    ///     .mode(FileSystemMountMode::Rdonly)
+    ///     .build()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn mount<F, M>(&self, file_system_name: F, mount_point: M) -> FileSystemMountBuilder<String>
+    where
+        F: std::fmt::Display,
+        M: std::fmt::Display,
+    {
+        FileSystemMountBuilder::new(self.core.clone(), file_system_name, mount_point)
+    }
+
+    /// Move a file or directory, even to a different file system.
+    ///
+    /// The path is first moved with [`rename`](Self::rename). When z/OSMF refuses because
+    /// the paths are on different file systems, the path is instead copied recursively,
+    /// preserving its attributes, and the original is then deleted.
+    /// If only the delete fails, the error is an [`Error::MoveCleanup`]
+    /// and both paths exist.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let move_path = zosmf
+    ///     .files()
+    ///     .move_path("/tmp/report.txt", "/u/jiahj/report.txt")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn move_path<F, T>(&self, from_path: F, to_path: T) -> Result<String>
+    where
+        F: std::fmt::Display,
+        T: std::fmt::Display,
+    {
+        let from_path: Arc<str> = from_path.to_string().into();
+        let to_path: Arc<str> = to_path.to_string().into();
+
+        match self.rename(&from_path, &to_path).build().await {
+            Err(Error::ZOsmf(err)) if is_cross_device(&err) => {}
+            result => return result,
+        }
+
+        self.copy(&from_path, &to_path)
+            .recursive(true)
+            .preserve(FileCopyPreserve::All)
+            .build()
+            .await?;
+
+        self.delete(&from_path)
+            .recursive(true)
+            .build()
+            .await
+            .map_err(|err| Error::MoveCleanup {
+                from: from_path,
+                to: to_path,
+                source: Box::new(err),
+            })
+    }
+
+    /// z/OSMF always follows a symbolic link when reading it, so the content of its target is read.
+    /// Use [`stat`](Self::stat) and [`FileAttributes::link_target`] to get the link itself.
+    ///
     /// # Examples
     ///
     /// Read a file:
@@ -408,6 +693,23 @@ impl FilesClient {
     /// # Ok(())
     /// # }
     /// ```
+    ///
+    /// Read the target of a link only when it points inside a directory:
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let attributes = zosmf.files().stat("/u/ibmuser/config").await?;
+    ///
+    /// match attributes.link_target() {
+    ///     Some(target) if !target.starts_with("/u/ibmuser/") => {
+    ///         println!("not following link to {}", target);
+    ///     }
+    ///     _ => {
+    ///         let read_file = zosmf.files().read("/u/ibmuser/config").build().await?;
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
     pub fn read<P>(&self, path: P) -> FileReadBuilder<FileRead<Arc<str>>>
     where
         P: std::fmt::Display,
@@ -519,6 +821,24 @@ impl FilesClient {
     /// # Ok(())
     /// # }
     /// ```
+    ///
+    /// Set some extra attributes and reset others in one request:
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// # use z_osmf::files::extra_attributes::FileExtraAttributes;
+    /// let file_set_extra_attributes = zosmf
+    ///     .files()
+    ///     .set_extra_attributes("/u/jiahj/testFile.txt")
+    ///     .set(
+    ///         FileExtraAttributes::default()
+    ///             .apf_authorized(true)
+    ///             .shared_library(false),
+    ///     )
+    ///     .build()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
     pub fn set_extra_attributes<P>(&self, path: P) -> FileExtraAttributesSetBuilder<String>
     where
         P: std::fmt::Display,
@@ -565,6 +885,55 @@ impl FilesClient {
         FileTagsSetBuilder::new(self.core.clone(), path)
     }
 
+    /// # Examples
+    ///
+    /// Grant a user access to a directory and everything within:
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let set_acl = zosmf
+    ///     .files()
+    ///     .set_acl("/u/jiahj/testDir")
+    ///     .modify(["user:IBMUSER:rwx".parse()?])
+    ///     .recursive(true)
+    ///     .build()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_acl<P>(&self, path: P) -> FileAclSetBuilder<String>
+    where
+        P: std::fmt::Display,
+    {
+        FileAclSetBuilder::new(self.core.clone(), path)
+    }
+
+    /// Get the attributes of a single file or directory, without following a symbolic link.
+    ///
+    /// The name of the returned attributes is the full path. A path that does not exist
+    /// fails with the z/OSMF error response, and a listing with no entry for the path itself
+    /// fails with [`Error::InvalidValue`].
+    ///
+    /// # Example
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let attributes = zosmf.files().stat("/u/ibmuser/myFile.txt").await?;
+    ///
+    /// println!("{:?} bytes", attributes.size());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn stat<P>(&self, path: P) -> Result<FileAttributes>
+    where
+        P: std::fmt::Display,
+    {
+        let path = path.to_string();
+
+        let file_list = self.list(&path).lstat(true).build().await?;
+
+        list::stat_entry(&path, file_list.items())
+            .ok_or_else(|| Error::InvalidValue(format!("no such file or directory: {}", path)))
+    }
+
     /// # Examples
     ///
     /// Unlink a file or directory:
@@ -586,6 +955,26 @@ impl FilesClient {
             .await
     }
 
+    /// # Examples
+    ///
+    /// Unmount a zFS file system:
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let unmount = zosmf
+    ///     .files()
+    ///     .unmount("IBMUSER.TEST.ZFS")
+    ///     .build()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn unmount<F>(&self, file_system_name: F) -> FileSystemUnmountBuilder<String>
+    where
+        F: std::fmt::Display,
+    {
+        FileSystemUnmountBuilder::new(self.core.clone(), file_system_name)
+    }
+
     /// # Examples
     ///
     /// Write to a file:
@@ -613,6 +1002,7 @@ impl FilesClient {
 #[serde(rename_all = "lowercase")]
 pub enum FileDataType {
     Binary,
+    Record,
     Text,
 }
 
@@ -623,6 +1013,7 @@ impl std::fmt::Display for FileDataType {
             "{}",
             match self {
                 FileDataType::Binary => "binary",
+                FileDataType::Record => "record",
                 FileDataType::Text => "text",
             }
         )
@@ -637,14 +1028,118 @@ pub enum FileTagType {
     Text,
 }
 
+/// Whether a request failed because the path does not exist, either with a `404 Not Found`
+/// or with `ENOENT`, reported by the C runtime as `EDC5129I No such file or directory`.
+fn is_not_found(err: &Error) -> bool {
+    let no_such_file = |text: &str| text.contains("EDC5129I");
+
+    match err {
+        Error::ZOsmf(err) => {
+            err.status() == reqwest::StatusCode::NOT_FOUND
+                || no_such_file(err.message())
+                || err
+                    .details()
+                    .is_some_and(|details| details.iter().any(|detail| no_such_file(detail)))
+        }
+        Error::ZOsmfText { status, body, .. } => {
+            *status == reqwest::StatusCode::NOT_FOUND || no_such_file(body)
+        }
+        _ => false,
+    }
+}
+
+/// Whether a request failed with `EXDEV`, reported by the C runtime as `EDC5145I Improper link`,
+/// because it would have linked or moved a path across file systems.
+fn is_cross_device(err: &ZOsmfError) -> bool {
+    let improper_link = |text: &str| text.contains("EDC5145I");
+
+    improper_link(err.message())
+        || err
+            .details()
+            .is_some_and(|details| details.iter().any(|detail| improper_link(detail)))
+}
+
 #[cfg(test)]
 mod tests {
+    use crate::error::CheckStatus;
+
     use super::*;
 
     #[test]
     fn data_type_display() {
         assert_eq!(format!("{}", FileDataType::Binary), "binary");
 
+        assert_eq!(format!("{}", FileDataType::Record), "record");
+
         assert_eq!(format!("{}", FileDataType::Text), "text");
     }
+
+    #[tokio::test]
+    async fn cross_device() {
+        let error = |body: &'static str| async move {
+            match reqwest::Response::from(http::Response::builder().status(500).body(body).unwrap())
+                .check_status()
+                .await
+            {
+                Err(Error::ZOsmf(err)) => err,
+                other => panic!("unexpected result: {:?}", other),
+            }
+        };
+
+        let err = error(
+            r#"{"category":1,"rc":8,"reason":0,"message":"move failed","details":["EDC5145I Improper link. (errno2=0x5B4B0002)"]}"#,
+        )
+        .await;
+        assert!(is_cross_device(&err));
+
+        let err = error(
+            r#"{"category":1,"rc":8,"reason":0,"message":"move failed","details":["EDC5129I No such file or directory."]}"#,
+        )
+        .await;
+        assert!(!is_cross_device(&err));
+    }
+
+    #[tokio::test]
+    async fn not_found() {
+        let error = |status: u16, body: &'static str| async move {
+            match reqwest::Response::from(
+                http::Response::builder().status(status).body(body).unwrap(),
+            )
+            .check_status()
+            .await
+            {
+                Err(err) => err,
+                other => panic!("unexpected result: {:?}", other),
+            }
+        };
+
+        let err = error(
+            404,
+            r#"{"category":1,"rc":4,"reason":8,"message":"File not found: /u/jiahj/missing"}"#,
+        )
+        .await;
+        assert!(matches!(err, Error::ZOsmf(_)));
+        assert!(is_not_found(&err));
+
+        let err = error(404, "<html><body>Not Found</body></html>").await;
+        assert!(matches!(err, Error::ZOsmfText { .. }));
+        assert!(is_not_found(&err));
+
+        let err = error(500, "<html><body>Internal Server Error</body></html>").await;
+        assert!(!is_not_found(&err));
+
+        let err = error(
+            500,
+            r#"{"category":1,"rc":8,"reason":0,"message":"list failed","details":["EDC5129I No such file or directory."]}"#,
+        )
+        .await;
+        assert!(is_not_found(&err));
+
+        let err = error(
+            500,
+            r#"{"category":1,"rc":8,"reason":0,"message":"list failed","details":["EDC5111I Permission denied."]}"#,
+        )
+        .await;
+        assert!(!is_not_found(&err));
+    }
 }
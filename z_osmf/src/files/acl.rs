@@ -0,0 +1,277 @@
+pub mod set;
+
+use std::marker::PhantomData;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use z_osmf_macros::{Endpoint, Getters};
+
+use crate::convert::TryFromResponse;
+use crate::restfiles::get_transaction_id;
+use crate::{ClientCore, Error, Result};
+
+#[derive(Clone, Debug, Deserialize, Eq, Getters, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub struct FileAclEntry {
+    #[getter(copy)]
+    acl_type: FileAclType,
+    #[getter(copy)]
+    entry_type: FileAclEntryType,
+    id: Option<Arc<str>>,
+    #[getter(copy)]
+    read: bool,
+    #[getter(copy)]
+    write: bool,
+    #[getter(copy)]
+    execute: bool,
+}
+
+impl std::str::FromStr for FileAclEntry {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let invalid = || Error::InvalidValue(format!("invalid ACL entry: {}", s));
+
+        let (acl_type, entry) = match s.split_once(':') {
+            Some(("default", entry)) => (FileAclType::Dir, entry),
+            Some(("fdefault", entry)) => (FileAclType::File, entry),
+            _ => (FileAclType::Access, s),
+        };
+
+        let mut fields = entry.split(':');
+        let entry_type = match fields.next() {
+            Some("user" | "u") => FileAclEntryType::User,
+            Some("group" | "g") => FileAclEntryType::Group,
+            Some("other" | "o") => FileAclEntryType::Other,
+            Some("mask" | "m") => FileAclEntryType::Mask,
+            _ => return Err(invalid()),
+        };
+        let id = match fields.next() {
+            Some("") => None,
+            Some(id) => Some(id.into()),
+            None => return Err(invalid()),
+        };
+        let permissions = fields.next().ok_or_else(invalid)?.as_bytes();
+        if fields.next().is_some() || permissions.len() != 3 {
+            return Err(invalid());
+        }
+
+        Ok(FileAclEntry {
+            acl_type,
+            entry_type,
+            id,
+            read: permissions[0] == b'r',
+            write: permissions[1] == b'w',
+            execute: permissions[2] == b'x',
+        })
+    }
+}
+
+impl std::fmt::Display for FileAclEntry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let prefix = match self.acl_type {
+            FileAclType::Access => "",
+            FileAclType::Dir => "default:",
+            FileAclType::File => "fdefault:",
+        };
+        let entry_type = match self.entry_type {
+            FileAclEntryType::User => "user",
+            FileAclEntryType::Group => "group",
+            FileAclEntryType::Other => "other",
+            FileAclEntryType::Mask => "mask",
+        };
+
+        write!(
+            f,
+            "{}{}:{}:{}{}{}",
+            prefix,
+            entry_type,
+            self.id.as_deref().unwrap_or_default(),
+            if self.read { 'r' } else { '-' },
+            if self.write { 'w' } else { '-' },
+            if self.execute { 'x' } else { '-' }
+        )
+    }
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FileAclEntryType {
+    Group,
+    Mask,
+    Other,
+    User,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FileAclLinks {
+    Follow,
+    Suppress,
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, Getters, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub struct FileAclList {
+    entries: Arc<[FileAclEntry]>,
+    transaction_id: Arc<str>,
+}
+
+impl TryFromResponse for FileAclList {
+    async fn try_from_response(value: reqwest::Response) -> Result<Self> {
+        let transaction_id = get_transaction_id(&value)?;
+
+        let FileAclResponseJson { stdout } = value.json().await?;
+        let entries = stdout
+            .iter()
+            .map(|line| line.trim())
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .map(FileAclEntry::from_str)
+            .collect::<Result<Arc<[FileAclEntry]>>>()?;
+
+        Ok(FileAclList {
+            entries,
+            transaction_id,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Endpoint)]
+#[endpoint(method = put, path = "/zosmf/restfiles/fs{path}")]
+pub struct FileAclListBuilder<T>
+where
+    T: TryFromResponse,
+{
+    core: Arc<ClientCore>,
+
+    #[endpoint(path)]
+    path: Arc<str>,
+    #[endpoint(builder_fn = build_acl_body)]
+    acl_type: Option<FileAclType>,
+    #[endpoint(skip_builder)]
+    user: Option<Arc<str>>,
+    #[endpoint(skip_builder)]
+    use_numeric_ids: Option<bool>,
+
+    target_type: PhantomData<T>,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FileAclType {
+    Access,
+    Dir,
+    File,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "kebab-case")]
+struct FileAclRequestJson<'a> {
+    request: &'static str,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    acl_type: Option<FileAclType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    user: Option<&'a str>,
+    use_numeric_ids: bool,
+}
+
+#[derive(Deserialize)]
+struct FileAclResponseJson {
+    stdout: Arc<[Arc<str>]>,
+}
+
+fn build_acl_body<T>(
+    request_builder: reqwest::RequestBuilder,
+    builder: &FileAclListBuilder<T>,
+) -> reqwest::RequestBuilder
+where
+    T: TryFromResponse,
+{
+    request_builder.json(&FileAclRequestJson {
+        request: "getfacl",
+        acl_type: builder.acl_type,
+        user: builder.user.as_deref(),
+        use_numeric_ids: builder.use_numeric_ids == Some(true),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{from_str, Value};
+
+    use crate::tests::{get_zosmf, GetJson};
+
+    use super::*;
+
+    #[test]
+    fn file_acl_entry_from_str() {
+        assert_eq!(
+            FileAclEntry::from_str("user::rwx").unwrap(),
+            FileAclEntry {
+                acl_type: FileAclType::Access,
+                entry_type: FileAclEntryType::User,
+                id: None,
+                read: true,
+                write: true,
+                execute: true,
+            }
+        );
+
+        assert_eq!(
+            FileAclEntry::from_str("default:group:SYS1:r-x").unwrap(),
+            FileAclEntry {
+                acl_type: FileAclType::Dir,
+                entry_type: FileAclEntryType::Group,
+                id: Some("SYS1".into()),
+                read: true,
+                write: false,
+                execute: true,
+            }
+        );
+
+        assert_eq!(
+            FileAclEntry::from_str("fdefault:user:IBMUSER:rw-")
+                .unwrap()
+                .to_string(),
+            "fdefault:user:IBMUSER:rw-"
+        );
+
+        assert!(FileAclEntry::from_str("some nonsense").is_err());
+        assert!(FileAclEntry::from_str("user:IBMUSER:rwxrwx").is_err());
+    }
+
+    #[test]
+    fn maximal_request() {
+        let zosmf = get_zosmf();
+
+        let json: Value = from_str(
+            r#"
+            {
+                "request": "getfacl",
+                "type": "dir",
+                "user": "IBMUSER",
+                "use-numeric-ids": true
+            }
+        "#,
+        )
+        .unwrap();
+        let manual_request = zosmf
+            .core
+            .client
+            .put("https://test.com/zosmf/restfiles/fs/u/jiahj/testDir")
+            .json(&json)
+            .build()
+            .unwrap();
+
+        let request = zosmf
+            .files()
+            .get_acl("/u/jiahj/testDir")
+            .acl_type(FileAclType::Dir)
+            .user("IBMUSER")
+            .use_numeric_ids(true)
+            .get_request()
+            .unwrap();
+
+        assert_eq!(format!("{:?}", manual_request), format!("{:?}", request));
+        assert_eq!(manual_request.json(), request.json());
+    }
+}
@@ -0,0 +1,197 @@
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use serde::Serialize;
+use z_osmf_macros::Endpoint;
+
+use crate::convert::TryFromResponse;
+use crate::ClientCore;
+
+use super::{FileAclEntry, FileAclLinks};
+
+#[derive(Clone, Debug, Endpoint)]
+#[endpoint(method = put, path = "/zosmf/restfiles/fs{path}")]
+pub struct FileAclSetBuilder<T>
+where
+    T: TryFromResponse,
+{
+    core: Arc<ClientCore>,
+
+    #[endpoint(path)]
+    path: Arc<str>,
+    #[endpoint(skip_setter, builder_fn = build_body)]
+    replace: Option<Arc<[FileAclEntry]>>,
+    #[endpoint(skip_setter, skip_builder)]
+    modify: Option<Arc<[FileAclEntry]>>,
+    #[endpoint(skip_setter, skip_builder)]
+    delete: Option<Arc<[FileAclEntry]>>,
+    #[endpoint(skip_builder)]
+    links: Option<FileAclLinks>,
+    #[endpoint(skip_builder)]
+    recursive: Option<bool>,
+
+    target_type: PhantomData<T>,
+}
+
+impl<T> FileAclSetBuilder<T>
+where
+    T: TryFromResponse,
+{
+    /// Add entries to the ACL, or update the permissions of matching entries.
+    pub fn modify<I>(mut self, entries: I) -> Self
+    where
+        I: IntoIterator<Item = FileAclEntry>,
+    {
+        self.modify = Some(entries.into_iter().collect());
+
+        self
+    }
+
+    /// Delete matching entries from the ACL.
+    pub fn delete<I>(mut self, entries: I) -> Self
+    where
+        I: IntoIterator<Item = FileAclEntry>,
+    {
+        self.delete = Some(entries.into_iter().collect());
+
+        self
+    }
+
+    /// Replace the entire ACL with the given entries.
+    pub fn replace<I>(mut self, entries: I) -> Self
+    where
+        I: IntoIterator<Item = FileAclEntry>,
+    {
+        self.replace = Some(entries.into_iter().collect());
+
+        self
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct RequestJson {
+    request: &'static str,
+    #[serde(rename = "set", skip_serializing_if = "Option::is_none")]
+    replace: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    modify: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    delete: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    links: Option<FileAclLinks>,
+    recursive: bool,
+}
+
+fn build_body<T>(
+    request_builder: reqwest::RequestBuilder,
+    builder: &FileAclSetBuilder<T>,
+) -> reqwest::RequestBuilder
+where
+    T: TryFromResponse,
+{
+    let join = |entries: &Option<Arc<[FileAclEntry]>>| {
+        entries.as_ref().map(|entries| {
+            entries
+                .iter()
+                .map(|entry| entry.to_string())
+                .collect::<Vec<_>>()
+                .join(",")
+        })
+    };
+
+    request_builder.json(&RequestJson {
+        request: "setfacl",
+        replace: join(&builder.replace),
+        modify: join(&builder.modify),
+        delete: join(&builder.delete),
+        links: builder.links,
+        recursive: builder.recursive == Some(true),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{from_str, Value};
+
+    use crate::tests::{get_zosmf, GetJson};
+
+    use super::*;
+
+    #[test]
+    fn modify_and_delete() {
+        let zosmf = get_zosmf();
+
+        let json: Value = from_str(
+            r#"
+            {
+                "request": "setfacl",
+                "modify": "user:IBMUSER:rwx,default:group:SYS1:r-x",
+                "delete": "user:OLDUSER:---",
+                "links": "suppress",
+                "recursive": true
+            }
+        "#,
+        )
+        .unwrap();
+        let manual_request = zosmf
+            .core
+            .client
+            .put("https://test.com/zosmf/restfiles/fs/u/jiahj/testDir")
+            .json(&json)
+            .build()
+            .unwrap();
+
+        let request = zosmf
+            .files()
+            .set_acl("/u/jiahj/testDir")
+            .modify([
+                "user:IBMUSER:rwx".parse().unwrap(),
+                "default:group:SYS1:r-x".parse().unwrap(),
+            ])
+            .delete(["user:OLDUSER:---".parse().unwrap()])
+            .links(FileAclLinks::Suppress)
+            .recursive(true)
+            .get_request()
+            .unwrap();
+
+        assert_eq!(format!("{:?}", manual_request), format!("{:?}", request));
+        assert_eq!(manual_request.json(), request.json());
+    }
+
+    #[test]
+    fn replace() {
+        let zosmf = get_zosmf();
+
+        let json: Value = from_str(
+            r#"
+            {
+                "request": "setfacl",
+                "set": "user::rw-,group::r--,other::---",
+                "recursive": false
+            }
+        "#,
+        )
+        .unwrap();
+        let manual_request = zosmf
+            .core
+            .client
+            .put("https://test.com/zosmf/restfiles/fs/u/jiahj/text.txt")
+            .json(&json)
+            .build()
+            .unwrap();
+
+        let request = zosmf
+            .files()
+            .set_acl("/u/jiahj/text.txt")
+            .replace([
+                "user::rw-".parse().unwrap(),
+                "group::r--".parse().unwrap(),
+                "other::---".parse().unwrap(),
+            ])
+            .get_request()
+            .unwrap();
+
+        assert_eq!(format!("{:?}", manual_request), format!("{:?}", request));
+        assert_eq!(manual_request.json(), request.json());
+    }
+}
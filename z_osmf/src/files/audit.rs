@@ -0,0 +1,177 @@
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use z_osmf_macros::Endpoint;
+
+use crate::convert::TryFromResponse;
+use crate::ClientCore;
+
+#[derive(Clone, Debug, Endpoint)]
+#[endpoint(method = put, path = "/zosmf/restfiles/fs{path}")]
+pub struct FileChangeAuditBuilder<T>
+where
+    T: TryFromResponse,
+{
+    core: Arc<ClientCore>,
+
+    #[endpoint(path)]
+    path: Arc<str>,
+    #[endpoint(builder_fn = build_body)]
+    read: Option<FileAuditClass>,
+    #[endpoint(skip_builder)]
+    write: Option<FileAuditClass>,
+    #[endpoint(skip_builder)]
+    execute: Option<FileAuditClass>,
+    #[endpoint(skip_builder)]
+    auditor_read: Option<FileAuditClass>,
+    #[endpoint(skip_builder)]
+    auditor_write: Option<FileAuditClass>,
+    #[endpoint(skip_builder)]
+    auditor_execute: Option<FileAuditClass>,
+    #[endpoint(skip_builder)]
+    recursive: Option<bool>,
+
+    target_type: PhantomData<T>,
+}
+
+/// Which accesses of a file are audited: none, successful, failed, or all of them.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub enum FileAuditClass {
+    #[serde(rename = "n")]
+    None,
+    #[serde(rename = "s")]
+    Success,
+    #[serde(rename = "f")]
+    Failure,
+    #[serde(rename = "sf")]
+    All,
+}
+
+impl std::fmt::Display for FileAuditClass {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                FileAuditClass::None => "n",
+                FileAuditClass::Success => "s",
+                FileAuditClass::Failure => "f",
+                FileAuditClass::All => "sf",
+            }
+        )
+    }
+}
+
+#[derive(Serialize)]
+struct RequestJson {
+    request: &'static str,
+    #[serde(rename = "user-audit", skip_serializing_if = "Option::is_none")]
+    user_audit: Option<String>,
+    #[serde(rename = "auditor-audit", skip_serializing_if = "Option::is_none")]
+    auditor_audit: Option<String>,
+    recursive: bool,
+}
+
+/// The audit options in the form of the `chaudit` command, like `r=sf,w=f`.
+fn audit_options(
+    read: Option<FileAuditClass>,
+    write: Option<FileAuditClass>,
+    execute: Option<FileAuditClass>,
+) -> Option<String> {
+    let options: Vec<String> = [("r", read), ("w", write), ("x", execute)]
+        .into_iter()
+        .filter_map(|(access, class)| class.map(|class| format!("{}={}", access, class)))
+        .collect();
+
+    (!options.is_empty()).then(|| options.join(","))
+}
+
+fn build_body<T>(
+    request_builder: reqwest::RequestBuilder,
+    builder: &FileChangeAuditBuilder<T>,
+) -> reqwest::RequestBuilder
+where
+    T: TryFromResponse,
+{
+    request_builder.json(&RequestJson {
+        request: "chaudit",
+        user_audit: audit_options(builder.read, builder.write, builder.execute),
+        auditor_audit: audit_options(
+            builder.auditor_read,
+            builder.auditor_write,
+            builder.auditor_execute,
+        ),
+        recursive: builder.recursive == Some(true),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{get_zosmf, GetJson};
+
+    use super::*;
+
+    #[test]
+    fn maximal_request() {
+        let zosmf = get_zosmf();
+
+        let json = r#"
+        {
+            "request": "chaudit",
+            "user-audit": "r=sf,w=f,x=n",
+            "auditor-audit": "w=s",
+            "recursive": true
+        }
+        "#;
+        let manual_request = zosmf
+            .core
+            .client
+            .put("https://test.com/zosmf/restfiles/fs/u/jiahj/testDir")
+            .json(&serde_json::from_str::<serde_json::Value>(json).unwrap())
+            .build()
+            .unwrap();
+
+        let request = zosmf
+            .files()
+            .change_audit("/u/jiahj/testDir")
+            .read(FileAuditClass::All)
+            .write(FileAuditClass::Failure)
+            .execute(FileAuditClass::None)
+            .auditor_write(FileAuditClass::Success)
+            .recursive(true)
+            .get_request()
+            .unwrap();
+
+        assert_eq!(format!("{:?}", manual_request), format!("{:?}", request));
+        assert_eq!(manual_request.json(), request.json());
+    }
+
+    #[test]
+    fn minimal_request() {
+        let zosmf = get_zosmf();
+
+        let json = r#"
+        {
+            "request": "chaudit",
+            "recursive": false
+        }
+        "#;
+        let manual_request = zosmf
+            .core
+            .client
+            .put("https://test.com/zosmf/restfiles/fs/u/jiahj/text.txt")
+            .json(&serde_json::from_str::<serde_json::Value>(json).unwrap())
+            .build()
+            .unwrap();
+
+        let request = zosmf
+            .files()
+            .change_audit("/u/jiahj/text.txt")
+            .get_request()
+            .unwrap();
+
+        assert_eq!(format!("{:?}", manual_request), format!("{:?}", request));
+        assert_eq!(manual_request.json(), request.json());
+    }
+}
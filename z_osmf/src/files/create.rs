@@ -6,7 +6,9 @@ use serde::{Deserialize, Serialize};
 use z_osmf_macros::Endpoint;
 
 use crate::convert::TryFromResponse;
-use crate::ClientCore;
+use crate::{ClientCore, Error, Result};
+
+use super::mode::FileMode;
 
 #[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
 #[serde(rename_all = "lowercase")]
@@ -28,18 +30,35 @@ where
 
     #[endpoint(builder_fn = build_body)]
     file_type: Option<FileCreateType>,
-    #[endpoint(skip_builder)]
-    mode: Option<Arc<str>>,
+    #[endpoint(skip_setter, skip_builder)]
+    mode: Option<FileMode>,
 
     target_type: PhantomData<T>,
 }
 
+impl<T> FileCreateBuilder<T>
+where
+    T: TryFromResponse,
+{
+    /// Set the mode of the new file, in its octal or symbolic form,
+    /// failing with [`Error::InvalidValue`] if it is not a valid mode.
+    pub fn mode<M>(mut self, value: M) -> Result<Self>
+    where
+        M: TryInto<FileMode>,
+        M::Error: Into<Error>,
+    {
+        self.mode = Some(value.try_into().map_err(Into::into)?);
+
+        Ok(self)
+    }
+}
+
 #[derive(Serialize)]
 struct RequestJson<'a> {
     #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
     file_type: Option<&'a FileCreateType>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    mode: Option<&'a str>,
+    mode: Option<String>,
 }
 
 fn build_body<T>(
@@ -51,7 +70,7 @@ where
 {
     request_builder.json(&RequestJson {
         file_type: builder.file_type.as_ref(),
-        mode: builder.mode.as_deref(),
+        mode: builder.mode.map(|mode| mode.symbolic()),
     })
 }
 
@@ -68,7 +87,7 @@ mod tests {
         let raw_json = r#"
         {
             "type": "file",
-            "mode": "RWXRW-RW-"
+            "mode": "rwxrw-rw-"
         }
         "#;
         let json: serde_json::Value = serde_json::from_str(raw_json).unwrap();
@@ -86,6 +105,7 @@ mod tests {
             .create("/u/jiahj/text.txt")
             .file_type(FileCreateType::File)
             .mode("RWXRW-RW-")
+            .unwrap()
             .get_request()
             .unwrap();
 
@@ -122,6 +142,7 @@ mod tests {
             .create("/u/jiahj/testDir")
             .file_type(FileCreateType::Directory)
             .mode("rwxr-xrwx")
+            .unwrap()
             .get_request()
             .unwrap();
 
@@ -132,4 +153,24 @@ mod tests {
 
         assert_eq!(manual_request.json(), create_file.json())
     }
+
+    #[test]
+    fn mode_forms() {
+        let zosmf = get_zosmf();
+        let create = || zosmf.files().create("/u/jiahj/testDir");
+
+        assert_eq!(
+            create().mode(0o757).unwrap().get_request().unwrap().json(),
+            create()
+                .mode("rwxr-xrwx")
+                .unwrap()
+                .get_request()
+                .unwrap()
+                .json()
+        );
+        assert!(matches!(
+            create().mode("rwxr-xr-q"),
+            Err(Error::InvalidValue(message)) if message == "invalid file mode: rwxr-xr-q"
+        ));
+    }
 }
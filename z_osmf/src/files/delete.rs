@@ -86,4 +86,28 @@ mod tests {
             format!("{:?}", delete_file)
         )
     }
+
+    #[test]
+    fn not_recursive() {
+        let zosmf = get_zosmf();
+
+        let manual_request = zosmf
+            .core
+            .client
+            .delete("https://test.com/zosmf/restfiles/fs/u/jiahj/testDir")
+            .build()
+            .unwrap();
+
+        let delete_file = zosmf
+            .files()
+            .delete("/u/jiahj/testDir")
+            .recursive(false)
+            .get_request()
+            .unwrap();
+
+        assert_eq!(
+            format!("{:?}", manual_request),
+            format!("{:?}", delete_file)
+        )
+    }
 }
@@ -21,6 +21,19 @@ pub struct FileExtraAttributeList {
     transaction_id: Arc<str>,
 }
 
+impl FileExtraAttributeList {
+    /// The four extra attributes, in the form accepted by
+    /// [`FileExtraAttributesSetBuilder::set`](set::FileExtraAttributesSetBuilder::set).
+    pub fn attributes(&self) -> FileExtraAttributes {
+        FileExtraAttributes {
+            apf_authorized: Some(self.apf_authorized),
+            program_controlled: Some(self.program_controlled),
+            shared_address_space: Some(self.shared_address_space),
+            shared_library: Some(self.shared_library),
+        }
+    }
+}
+
 impl TryFromResponse for FileExtraAttributeList {
     async fn try_from_response(value: reqwest::Response) -> Result<Self> {
         let transaction_id = get_transaction_id(&value)?;
@@ -47,6 +60,52 @@ impl TryFromResponse for FileExtraAttributeList {
     }
 }
 
+/// The extra attributes of a file to change together, where an attribute that is not given
+/// is left as it is.
+///
+/// # Example
+/// ```
+/// # use z_osmf::files::extra_attributes::FileExtraAttributes;
+/// let attributes = FileExtraAttributes::default()
+///     .apf_authorized(true)
+///     .program_controlled(false);
+/// ```
+#[derive(
+    Clone, Copy, Debug, Default, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize,
+)]
+pub struct FileExtraAttributes {
+    apf_authorized: Option<bool>,
+    program_controlled: Option<bool>,
+    shared_address_space: Option<bool>,
+    shared_library: Option<bool>,
+}
+
+impl FileExtraAttributes {
+    pub fn apf_authorized(mut self, value: bool) -> Self {
+        self.apf_authorized = Some(value);
+
+        self
+    }
+
+    pub fn program_controlled(mut self, value: bool) -> Self {
+        self.program_controlled = Some(value);
+
+        self
+    }
+
+    pub fn shared_address_space(mut self, value: bool) -> Self {
+        self.shared_address_space = Some(value);
+
+        self
+    }
+
+    pub fn shared_library(mut self, value: bool) -> Self {
+        self.shared_library = Some(value);
+
+        self
+    }
+}
+
 #[derive(Clone, Debug, Endpoint)]
 #[endpoint(method = put, path = "/zosmf/restfiles/fs{path}")]
 pub(crate) struct FileExtraAttributeListBuilder<T>
@@ -6,7 +6,7 @@ use z_osmf_macros::Endpoint;
 use crate::convert::TryFromResponse;
 use crate::ClientCore;
 
-use super::RequestJson;
+use super::{FileExtraAttributes, RequestJson};
 
 #[derive(Clone, Debug, Endpoint)]
 #[endpoint(method = put, path = "/zosmf/restfiles/fs{path}")]
@@ -26,10 +26,55 @@ where
     program_controlled: Option<bool>,
     #[endpoint(skip_builder)]
     shared_address_space: Option<bool>,
+    #[endpoint(skip_setter, skip_builder)]
+    reset: Option<FileExtraAttributes>,
 
     target_type: PhantomData<T>,
 }
 
+impl<T> FileExtraAttributesSetBuilder<T>
+where
+    T: TryFromResponse,
+{
+    /// Change every attribute given in `attributes` in one request,
+    /// setting those that are `true` and resetting those that are `false`.
+    ///
+    /// The setters of this builder, like those of
+    /// [`FileExtraAttributesResetBuilder`](super::reset::FileExtraAttributesResetBuilder),
+    /// leave an attribute as it is when given `false`, so only `set` resets attributes.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let attributes = zosmf
+    ///     .files()
+    ///     .get_extra_attributes("/u/jiahj/template")
+    ///     .await?
+    ///     .attributes();
+    ///
+    /// zosmf
+    ///     .files()
+    ///     .set_extra_attributes("/u/jiahj/copy")
+    ///     .set(attributes)
+    ///     .build()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set(self, attributes: FileExtraAttributes) -> Self {
+        FileExtraAttributesSetBuilder {
+            apf_authorized: attributes.apf_authorized.or(self.apf_authorized),
+            shared_library: attributes.shared_library.or(self.shared_library),
+            program_controlled: attributes.program_controlled.or(self.program_controlled),
+            shared_address_space: attributes
+                .shared_address_space
+                .or(self.shared_address_space),
+            reset: Some(attributes),
+            ..self
+        }
+    }
+}
+
 fn build_body<T>(
     request_builder: reqwest::RequestBuilder,
     builder: &FileExtraAttributesSetBuilder<T>,
@@ -37,25 +82,111 @@ fn build_body<T>(
 where
     T: TryFromResponse,
 {
-    let mut set = Vec::new();
+    let resets = builder.reset.unwrap_or_default();
+    let mut set = String::new();
+    let mut reset = String::new();
 
-    if builder.apf_authorized == Some(true) {
-        set.push('a');
-    }
-    if builder.shared_library == Some(true) {
-        set.push('l');
+    for (flag, value, reset_value) in [
+        ('a', builder.apf_authorized, resets.apf_authorized),
+        ('l', builder.shared_library, resets.shared_library),
+        ('p', builder.program_controlled, resets.program_controlled),
+        (
+            's',
+            builder.shared_address_space,
+            resets.shared_address_space,
+        ),
+    ] {
+        match (value, reset_value) {
+            (Some(true), _) => set.push(flag),
+            (_, Some(false)) => reset.push(flag),
+            _ => {}
+        }
     }
-    if builder.program_controlled == Some(true) {
-        set.push('p');
-    }
-    if builder.shared_address_space == Some(true) {
-        set.push('s');
-    }
-    let set = Some(set.into_iter().collect::<String>().into());
 
     request_builder.json(&RequestJson {
         request: "extattr",
-        set,
-        reset: None,
+        set: Some(set.into()),
+        reset: (!reset.is_empty()).then(|| reset.into()),
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{get_zosmf, GetJson};
+
+    use super::*;
+
+    #[test]
+    fn set_attributes() {
+        let zosmf = get_zosmf();
+
+        let json = r#"
+        {
+            "request": "extattr",
+            "set": "ap",
+            "reset": "l"
+        }
+        "#;
+        let manual_request = zosmf
+            .core
+            .client
+            .put("https://test.com/zosmf/restfiles/fs/u/jiahj/testFile.txt")
+            .json(&serde_json::from_str::<serde_json::Value>(json).unwrap())
+            .build()
+            .unwrap();
+
+        let attributes = FileExtraAttributes::default()
+            .apf_authorized(true)
+            .program_controlled(true)
+            .shared_library(false);
+
+        let request = zosmf
+            .files()
+            .set_extra_attributes("/u/jiahj/testFile.txt")
+            .set(attributes)
+            .get_request()
+            .unwrap();
+
+        assert_eq!(format!("{:?}", manual_request), format!("{:?}", request));
+        assert_eq!(manual_request.json(), request.json());
+    }
+
+    #[test]
+    fn setters_only_set() {
+        let zosmf = get_zosmf();
+
+        let json = r#"
+        {
+            "request": "extattr",
+            "set": "a"
+        }
+        "#;
+        let manual_request = zosmf
+            .core
+            .client
+            .put("https://test.com/zosmf/restfiles/fs/u/jiahj/testFile.txt")
+            .json(&serde_json::from_str::<serde_json::Value>(json).unwrap())
+            .build()
+            .unwrap();
+
+        let request = zosmf
+            .files()
+            .set_extra_attributes("/u/jiahj/testFile.txt")
+            .apf_authorized(true)
+            .shared_library(false)
+            .get_request()
+            .unwrap();
+
+        assert_eq!(manual_request.json(), request.json());
+
+        let request = zosmf
+            .files()
+            .set_extra_attributes("/u/jiahj/testFile.txt")
+            .set(FileExtraAttributes::default().apf_authorized(false))
+            .apf_authorized(true)
+            .get_request()
+            .unwrap();
+
+        assert_eq!(manual_request.json(), request.json());
+    }
+}
@@ -0,0 +1,175 @@
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use z_osmf_macros::{Endpoint, Getters};
+
+use crate::convert::TryFromResponse;
+use crate::restfiles::get_transaction_id;
+use crate::{ClientCore, Result};
+
+#[derive(Clone, Debug, Deserialize, Eq, Getters, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub struct FileSystemAttributes {
+    name: Arc<str>,
+    #[serde(rename = "mountpoint")]
+    mount_point: Arc<str>,
+    #[serde(default, rename = "fstname")]
+    file_system_type: Option<Arc<str>>,
+    #[serde(default)]
+    status: Option<Arc<str>>,
+    #[serde(default)]
+    mode: Arc<[Arc<str>]>,
+    #[getter(copy)]
+    #[serde(default, rename = "bsize")]
+    block_size: Option<i64>,
+    #[getter(copy)]
+    #[serde(default, rename = "bavail")]
+    available_blocks: Option<i64>,
+    #[getter(copy)]
+    #[serde(default, rename = "blocks")]
+    total_blocks: Option<i64>,
+    #[serde(default, rename = "sysname")]
+    system_name: Option<Arc<str>>,
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, Getters, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub struct FileSystemList {
+    items: Arc<[FileSystemAttributes]>,
+    #[getter(copy)]
+    returned_rows: i32,
+    #[getter(copy)]
+    total_rows: i32,
+    #[getter(copy)]
+    json_version: i32,
+    transaction_id: Arc<str>,
+}
+
+impl TryFromResponse for FileSystemList {
+    async fn try_from_response(value: reqwest::Response) -> Result<Self> {
+        let transaction_id = get_transaction_id(&value)?;
+
+        let ResponseJson {
+            items,
+            returned_rows,
+            total_rows,
+            json_version,
+        } = value.json().await?;
+
+        Ok(FileSystemList {
+            items,
+            returned_rows,
+            total_rows,
+            json_version,
+            transaction_id,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Endpoint)]
+#[endpoint(method = get, path = "/zosmf/restfiles/mfs")]
+pub struct FileSystemListBuilder<T>
+where
+    T: TryFromResponse,
+{
+    core: Arc<ClientCore>,
+
+    #[endpoint(query = "path")]
+    path: Option<Arc<str>>,
+    #[endpoint(query = "fsname")]
+    file_system_name: Option<Arc<str>>,
+    #[endpoint(header = "X-IBM-Max-Items")]
+    max_items: Option<i32>,
+
+    target_type: PhantomData<T>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ResponseJson {
+    items: Arc<[FileSystemAttributes]>,
+    returned_rows: i32,
+    total_rows: i32,
+    #[serde(rename = "JSONversion")]
+    json_version: i32,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::get_zosmf;
+
+    use super::*;
+
+    #[test]
+    fn minimal_request() {
+        let zosmf = get_zosmf();
+
+        let manual_request = zosmf
+            .core
+            .client
+            .get("https://test.com/zosmf/restfiles/mfs")
+            .build()
+            .unwrap();
+
+        let request = zosmf.files().list_filesystems().get_request().unwrap();
+
+        assert_eq!(format!("{:?}", manual_request), format!("{:?}", request))
+    }
+
+    #[test]
+    fn maximal_request() {
+        let zosmf = get_zosmf();
+
+        let manual_request = zosmf
+            .core
+            .client
+            .get("https://test.com/zosmf/restfiles/mfs")
+            .query(&[("path", "/u/ibmuser"), ("fsname", "OMVS.USER.ZFS")])
+            .header("X-IBM-Max-Items", "10")
+            .build()
+            .unwrap();
+
+        let request = zosmf
+            .files()
+            .list_filesystems()
+            .path("/u/ibmuser")
+            .file_system_name("OMVS.USER.ZFS")
+            .max_items(10)
+            .get_request()
+            .unwrap();
+
+        assert_eq!(format!("{:?}", manual_request), format!("{:?}", request))
+    }
+
+    #[test]
+    fn deserialize_attributes() {
+        let json = r#"
+        {
+            "name": "OMVS.USER.ZFS",
+            "mountpoint": "/u/ibmuser",
+            "fstname": "ZFS",
+            "status": "active",
+            "mode": ["acl", "rdwr", "nosecurity"],
+            "dev": 41,
+            "fstype": 1,
+            "bsize": 1024,
+            "bavail": 12345,
+            "blocks": 72000,
+            "sysname": "SY1",
+            "readibc": 0,
+            "writeibc": 0,
+            "diribc": 0
+        }
+        "#;
+
+        let attributes: FileSystemAttributes = serde_json::from_str(json).unwrap();
+
+        assert_eq!(attributes.name(), "OMVS.USER.ZFS");
+        assert_eq!(attributes.mount_point(), "/u/ibmuser");
+        assert_eq!(attributes.file_system_type(), Some("ZFS"));
+        assert_eq!(attributes.status(), Some("active"));
+        assert_eq!(attributes.mode().len(), 3);
+        assert_eq!(attributes.block_size(), Some(1024));
+        assert_eq!(attributes.available_blocks(), Some(12345));
+        assert_eq!(attributes.total_blocks(), Some(72000));
+    }
+}
@@ -1,13 +1,18 @@
+use std::collections::{HashSet, VecDeque};
 use std::marker::PhantomData;
 use std::sync::Arc;
 
 use chrono::NaiveDateTime;
+use futures_core::Stream;
+use futures_util::TryStreamExt;
 use serde::{Deserialize, Serialize};
 use z_osmf_macros::{Endpoint, Getters};
 
 use crate::convert::TryFromResponse;
 use crate::restfiles::get_transaction_id;
-use crate::{ClientCore, Result};
+use crate::{ClientCore, Error, Result};
+
+use super::mode::FileMode;
 
 #[derive(Clone, Debug, Deserialize, Eq, Getters, Hash, Ord, PartialEq, PartialOrd, Serialize)]
 pub struct FileAttributes {
@@ -24,10 +29,46 @@ pub struct FileAttributes {
     group: Option<Arc<str>>,
     #[getter(copy)]
     mtime: Option<NaiveDateTime>,
+    #[getter(copy)]
+    #[serde(default)]
+    atime: Option<NaiveDateTime>,
+    #[getter(copy)]
+    #[serde(default)]
+    ctime: Option<NaiveDateTime>,
     #[serde(default)]
     target: Option<Arc<str>>,
 }
 
+impl FileAttributes {
+    /// The permission bits parsed from [`mode`](Self::mode), like `drwxr-xr-x`.
+    pub fn permissions(&self) -> Option<FileMode> {
+        self.mode.as_deref()?.parse().ok()
+    }
+
+    /// The path a symbolic link points to, when the entry was listed without following links.
+    ///
+    /// Returns `None` for anything other than a [`FileType::SymbolicLink`].
+    pub fn link_target(&self) -> Option<&str> {
+        match self.file_type() {
+            Some(FileType::SymbolicLink) => self.target.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// The type of the file, from the first character of [`mode`](Self::mode).
+    pub fn file_type(&self) -> Option<FileType> {
+        match self.mode.as_deref()?.chars().next()? {
+            'c' => Some(FileType::CharacterSpecialFile),
+            'd' => Some(FileType::Directory),
+            'p' => Some(FileType::FIFO),
+            '-' => Some(FileType::File),
+            's' => Some(FileType::Socket),
+            'l' => Some(FileType::SymbolicLink),
+            _ => None,
+        }
+    }
+}
+
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub enum FileFilter<T>
 where
@@ -154,6 +195,71 @@ where
     target_type: PhantomData<T>,
 }
 
+impl FileListBuilder<FileList> {
+    /// Recursively list every entry below the path, breadth-first.
+    ///
+    /// The name of each entry is its full path. Filters apply to directories too,
+    /// so a directory that does not match them is not walked. Mount points are not crossed and
+    /// symbolic links are not followed unless [`file_system`](Self::file_system)
+    /// or [`symlinks`](Self::symlinks) say otherwise.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// use futures_util::TryStreamExt;
+    ///
+    /// # use z_osmf::files::list::FileSystem;
+    /// let mut files = std::pin::pin!(zosmf
+    ///     .files()
+    ///     .list("/u/ibmuser")
+    ///     .file_system(FileSystem::All)
+    ///     .walk());
+    ///
+    /// while let Some(file) = files.try_next().await? {
+    ///     println!("{}", file.name());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn walk(self) -> impl Stream<Item = Result<FileAttributes>> {
+        let builder = FileListBuilder {
+            depth: Some(2),
+            file_system: self.file_system.or(Some(FileSystem::Same)),
+            symlinks: self.symlinks.or(Some(FileSymLinks::Report)),
+            ..self
+        };
+        let queue = VecDeque::from([builder.path.clone()]);
+
+        futures_util::stream::try_unfold(queue, move |mut queue| {
+            let builder = builder.clone();
+
+            async move {
+                let Some(path) = queue.pop_front() else {
+                    return Ok::<_, Error>(None);
+                };
+
+                let builder = FileListBuilder {
+                    path: path.clone(),
+                    ..builder
+                };
+                let mut file_list = builder.clone().build().await?;
+                if file_list.total_rows > file_list.returned_rows {
+                    file_list = builder.limit(file_list.total_rows).build().await?;
+                }
+
+                let (entries, directories) = walk_entries(&path, &file_list.items);
+                queue.extend(directories);
+
+                Ok(Some((
+                    futures_util::stream::iter(entries.into_iter().map(Ok)),
+                    queue,
+                )))
+            }
+        })
+        .try_flatten()
+    }
+}
+
 // TODO: impl serde?
 #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub enum FileSize {
@@ -204,6 +310,7 @@ pub enum FileSystem {
 }
 
 #[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[non_exhaustive]
 pub enum FileType {
     #[serde(rename = "c")]
     CharacterSpecialFile,
@@ -215,8 +322,13 @@ pub enum FileType {
     File,
     #[serde(rename = "s")]
     Socket,
+    /// A symbolic link, listed when links are not followed.
+    /// Its target is available from [`FileAttributes::link_target`].
     #[serde(rename = "l")]
     SymbolicLink,
+    /// Any other file type reported by z/OSMF.
+    #[serde(other)]
+    Unknown,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -229,6 +341,53 @@ struct ResponseJson {
     json_version: i32,
 }
 
+/// Pick the entry describing `path` itself from a listing of `path`, named by full path.
+///
+/// A file lists as its only entry, while a directory lists itself as `.`.
+pub(crate) fn stat_entry(path: &str, items: &[FileAttributes]) -> Option<FileAttributes> {
+    let item = match items {
+        [item] => item,
+        items => items.iter().find(|item| item.name.as_ref() == ".")?,
+    };
+
+    Some(FileAttributes {
+        name: path.into(),
+        ..item.clone()
+    })
+}
+
+/// Split a two-level listing of `path` into its direct entries, named by full path,
+/// and the subdirectories with contents on the same file system, which are walked next.
+fn walk_entries(path: &str, items: &[FileAttributes]) -> (Vec<FileAttributes>, Vec<Arc<str>>) {
+    let parent = path.trim_end_matches('/');
+
+    let nonempty: HashSet<&str> = items
+        .iter()
+        .filter_map(|item| item.name.split_once('/').map(|(directory, _)| directory))
+        .collect();
+
+    let mut entries = Vec::new();
+    let mut directories = Vec::new();
+    for item in items
+        .iter()
+        .filter(|item| !item.name.contains('/') && !matches!(item.name.as_ref(), "." | ".."))
+    {
+        let name: Arc<str> = format!("{}/{}", parent, item.name).into();
+
+        let is_directory = item.file_type() == Some(FileType::Directory);
+        if is_directory && nonempty.contains(item.name.as_ref()) {
+            directories.push(name.clone());
+        }
+
+        entries.push(FileAttributes {
+            name,
+            ..item.clone()
+        });
+    }
+
+    (entries, directories)
+}
+
 fn build_lstat<T>(
     request_builder: reqwest::RequestBuilder,
     builder: &FileListBuilder<T>,
@@ -354,4 +513,137 @@ mod tests {
 
         assert_eq!(format!("{:?}", manual_request), format!("{:?}", request))
     }
+
+    #[test]
+    fn test_walk_entries() {
+        let items: Vec<FileAttributes> = serde_json::from_str(
+            r#"[
+                {"name": ".", "mode": "drwxr-xr-x"},
+                {"name": "..", "mode": "drwxr-xr-x"},
+                {"name": "bin", "mode": "drwxr-xr-x"},
+                {"name": "bin/tool", "mode": "-rwxr-xr-x"},
+                {"name": "empty", "mode": "drwxr-xr-x"},
+                {"name": "link", "mode": "lrwxrwxrwx", "target": "/etc"},
+                {"name": "notes.txt", "mode": "-rw-r--r--"}
+            ]"#,
+        )
+        .unwrap();
+
+        let (entries, directories) = walk_entries("/u/ibmuser/", &items);
+
+        assert_eq!(
+            entries.iter().map(|entry| entry.name()).collect::<Vec<_>>(),
+            [
+                "/u/ibmuser/bin",
+                "/u/ibmuser/empty",
+                "/u/ibmuser/link",
+                "/u/ibmuser/notes.txt"
+            ]
+        );
+        assert_eq!(directories, [Arc::from("/u/ibmuser/bin")]);
+    }
+
+    #[test]
+    fn test_stat_entry() {
+        let file: Vec<FileAttributes> =
+            serde_json::from_str(r#"[{"name": "notes.txt", "mode": "-rw-r--r--", "size": 12}]"#)
+                .unwrap();
+
+        let entry = stat_entry("/u/ibmuser/notes.txt", &file).unwrap();
+        assert_eq!(entry.name(), "/u/ibmuser/notes.txt");
+        assert_eq!(entry.size(), Some(12));
+
+        let directory: Vec<FileAttributes> = serde_json::from_str(
+            r#"[
+                {"name": ".", "mode": "drwxr-xr-x"},
+                {"name": "..", "mode": "drwxr-xr-x"},
+                {"name": "notes.txt", "mode": "-rw-r--r--"}
+            ]"#,
+        )
+        .unwrap();
+
+        let entry = stat_entry("/u/ibmuser", &directory).unwrap();
+        assert_eq!(entry.name(), "/u/ibmuser");
+        assert_eq!(entry.file_type(), Some(FileType::Directory));
+
+        assert_eq!(stat_entry("/u/ibmuser", &[]), None);
+
+        let link: Vec<FileAttributes> =
+            serde_json::from_str(r#"[{"name": "link", "mode": "lrwxrwxrwx", "target": "/etc"}]"#)
+                .unwrap();
+
+        let entry = stat_entry("/u/ibmuser/link", &link).unwrap();
+        assert_eq!(entry.link_target(), Some("/etc"));
+        assert_eq!(
+            stat_entry("/u/ibmuser", &directory).unwrap().link_target(),
+            None
+        );
+    }
+
+    #[test]
+    fn timestamps() {
+        let items: Vec<FileAttributes> = serde_json::from_str(
+            r#"[
+                {
+                    "name": "notes.txt",
+                    "mode": "-rw-r--r--",
+                    "mtime": "2024-03-01T10:15:00",
+                    "atime": "2024-03-02T08:00:30",
+                    "ctime": "2024-03-01T10:15:01"
+                },
+                {"name": "old.txt", "mode": "-rw-r--r--", "mtime": "2024-03-01T10:15:00"}
+            ]"#,
+        )
+        .unwrap();
+
+        let time = |s: &str| s.parse::<NaiveDateTime>().unwrap();
+
+        assert_eq!(items[0].mtime(), Some(time("2024-03-01T10:15:00")));
+        assert_eq!(items[0].atime(), Some(time("2024-03-02T08:00:30")));
+        assert_eq!(items[0].ctime(), Some(time("2024-03-01T10:15:01")));
+        assert_eq!(items[1].atime(), None);
+        assert_eq!(items[1].ctime(), None);
+    }
+
+    #[test]
+    fn permissions_and_file_type() {
+        let items: Vec<FileAttributes> = serde_json::from_str(
+            r#"[
+                {"name": "bin", "mode": "drwxr-xr-x"},
+                {"name": "notes.txt", "mode": "-rw-r--r--"},
+                {"name": "link", "mode": "lrwxrwxrwx"},
+                {"name": "su", "mode": "-rwsr-xr-x"},
+                {"name": "shared", "mode": "drwxrws---"},
+                {"name": "tmp", "mode": "drwxrwxrwt"},
+                {"name": "odd", "mode": "-rwSr-Sr-T"},
+                {"name": "null", "mode": "crw-rw-rw-"},
+                {"name": "pipe", "mode": "prw-------"},
+                {"name": "sock", "mode": "srwxrwxrwx"},
+                {"name": "none"}
+            ]"#,
+        )
+        .unwrap();
+
+        let attributes: Vec<_> = items
+            .iter()
+            .map(|item| (item.file_type(), item.permissions().map(|mode| mode.bits())))
+            .collect();
+
+        assert_eq!(
+            attributes,
+            [
+                (Some(FileType::Directory), Some(0o755)),
+                (Some(FileType::File), Some(0o644)),
+                (Some(FileType::SymbolicLink), Some(0o777)),
+                (Some(FileType::File), Some(0o4755)),
+                (Some(FileType::Directory), Some(0o2770)),
+                (Some(FileType::Directory), Some(0o1777)),
+                (Some(FileType::File), Some(0o7644)),
+                (Some(FileType::CharacterSpecialFile), Some(0o666)),
+                (Some(FileType::FIFO), Some(0o600)),
+                (Some(FileType::Socket), Some(0o777)),
+                (None, None),
+            ]
+        );
+    }
 }
@@ -5,7 +5,7 @@ use serde::{Deserialize, Serialize};
 use z_osmf_macros::Endpoint;
 
 use crate::convert::TryFromResponse;
-use crate::ClientCore;
+use crate::{ClientCore, Error, Result};
 
 #[derive(Clone, Debug, Endpoint)]
 #[endpoint(method = put, path = "/zosmf/restfiles/fs{path}")]
@@ -18,7 +18,7 @@ where
     #[endpoint(path)]
     path: Arc<str>,
     #[endpoint(builder_fn = build_body)]
-    mode: Arc<str>,
+    mode: FileMode,
     #[endpoint(skip_builder)]
     links: Option<FileChangeModeLinks>,
     #[endpoint(skip_builder)]
@@ -34,10 +34,174 @@ pub enum FileChangeModeLinks {
     Suppress,
 }
 
+/// The permission bits of a file, including the set-user-ID, set-group-ID and sticky bits.
+///
+/// A mode can be parsed from its octal form, like `"755"` or `"4755"`,
+/// or from its symbolic form, like `"rwxr-xr-x"` or `"drwsr-xr-x"`.
+/// It is displayed in octal form.
+///
+/// # Example
+/// ```
+/// # use z_osmf::files::mode::FileMode;
+/// let mode: FileMode = "rwsr-xr-x".parse().unwrap();
+///
+/// assert_eq!(mode, FileMode::from(0o4755));
+/// assert_eq!(mode.to_string(), "4755");
+/// assert_eq!(mode.symbolic(), "rwsr-xr-x");
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct FileMode(u16);
+
+impl FileMode {
+    /// The permission bits as a number, like `0o755`.
+    pub fn bits(&self) -> u16 {
+        self.0
+    }
+
+    pub fn setuid(&self) -> bool {
+        self.0 & 0o4000 != 0
+    }
+
+    pub fn setgid(&self) -> bool {
+        self.0 & 0o2000 != 0
+    }
+
+    pub fn sticky(&self) -> bool {
+        self.0 & 0o1000 != 0
+    }
+
+    /// The mode in nine-character symbolic form, like `"rwxr-xr-x"`.
+    pub fn symbolic(&self) -> String {
+        let mut symbolic = String::with_capacity(9);
+
+        for (shift, special, special_char) in [
+            (6, self.setuid(), 's'),
+            (3, self.setgid(), 's'),
+            (0, self.sticky(), 't'),
+        ] {
+            let bits = (self.0 >> shift) & 0o7;
+
+            symbolic.push(if bits & 0o4 != 0 { 'r' } else { '-' });
+            symbolic.push(if bits & 0o2 != 0 { 'w' } else { '-' });
+            symbolic.push(match (bits & 0o1 != 0, special) {
+                (true, true) => special_char,
+                (false, true) => special_char.to_ascii_uppercase(),
+                (true, false) => 'x',
+                (false, false) => '-',
+            });
+        }
+
+        symbolic
+    }
+}
+
+impl From<u16> for FileMode {
+    /// Create a mode from its permission bits, ignoring any bits above `0o7777`.
+    fn from(value: u16) -> Self {
+        FileMode(value & 0o7777)
+    }
+}
+
+impl std::fmt::Display for FileMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.0 > 0o777 {
+            write!(f, "{:04o}", self.0)
+        } else {
+            write!(f, "{:03o}", self.0)
+        }
+    }
+}
+
+impl TryFrom<&str> for FileMode {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self> {
+        value.parse()
+    }
+}
+
+impl TryFrom<String> for FileMode {
+    type Error = Error;
+
+    fn try_from(value: String) -> Result<Self> {
+        value.parse()
+    }
+}
+
+impl std::str::FromStr for FileMode {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let invalid = || Error::InvalidValue(format!("invalid file mode: {}", s));
+
+        if s.bytes().all(|b| b.is_ascii_digit()) {
+            if !(3..=4).contains(&s.len()) {
+                return Err(invalid());
+            }
+
+            return u16::from_str_radix(s, 8)
+                .map(FileMode)
+                .map_err(|_| invalid());
+        }
+
+        let symbolic = match s.len() {
+            9 => s,
+            10 if matches!(
+                s.as_bytes()[0],
+                b'-' | b'b' | b'c' | b'd' | b'l' | b'p' | b's'
+            ) =>
+            {
+                &s[1..]
+            }
+            _ => return Err(invalid()),
+        };
+
+        let mut bits = 0;
+        for (i, c) in symbolic.chars().enumerate() {
+            let shift = 6 - 3 * (i / 3) as u16;
+            let special = 0o4000 >> (i / 3);
+
+            bits |= match (i % 3, c) {
+                (_, '-') => 0,
+                (0, 'r' | 'R') => 0o4 << shift,
+                (1, 'w' | 'W') => 0o2 << shift,
+                (2, 'x' | 'X') => 0o1 << shift,
+                (2, 's') if i < 6 => special | 0o1 << shift,
+                (2, 'S') if i < 6 => special,
+                (2, 't') if i == 8 => special | 0o1,
+                (2, 'T') if i == 8 => special,
+                _ => return Err(invalid()),
+            };
+        }
+
+        Ok(FileMode(bits))
+    }
+}
+
+impl<'de> Deserialize<'de> for FileMode {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+impl Serialize for FileMode {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
 #[derive(Serialize)]
-struct RequestJson<'a> {
+struct RequestJson {
     request: &'static str,
-    mode: &'a str,
+    mode: FileMode,
     #[serde(skip_serializing_if = "Option::is_none")]
     links: Option<FileChangeModeLinks>,
     recursive: bool,
@@ -52,7 +216,7 @@ where
 {
     request_builder.json(&RequestJson {
         request: "chmod",
-        mode: &builder.mode,
+        mode: builder.mode,
         links: builder.links,
         recursive: builder.recursive == Some(true),
     })
@@ -86,7 +250,8 @@ mod tests {
 
         let request = zosmf
             .files()
-            .change_mode("/u/jiahj/text.txt", "755")
+            .change_mode("/u/jiahj/text.txt", 0o755)
+            .unwrap()
             .links(FileChangeModeLinks::Suppress)
             .recursive(true)
             .get_request()
@@ -117,11 +282,80 @@ mod tests {
 
         let request = zosmf
             .files()
-            .change_mode("/u/jiahj/text.txt", "755")
+            .change_mode("/u/jiahj/text.txt", 0o755)
+            .unwrap()
             .get_request()
             .unwrap();
 
         assert_eq!(format!("{:?}", manual_request), format!("{:?}", request));
         assert_eq!(manual_request.json(), request.json());
     }
+
+    #[test]
+    fn mode_from_str() {
+        let zosmf = get_zosmf();
+
+        let request = zosmf
+            .files()
+            .change_mode("/u/jiahj/text.txt", "755")
+            .unwrap()
+            .get_request()
+            .unwrap();
+        assert_eq!(
+            request.json(),
+            Some(serde_json::json!({"request": "chmod", "mode": "755", "recursive": false}))
+        );
+
+        let request = zosmf
+            .files()
+            .change_mode("/u/jiahj/text.txt", String::from("rwxr-x---"))
+            .unwrap()
+            .get_request()
+            .unwrap();
+        assert_eq!(request.json().unwrap()["mode"], "750");
+
+        assert!(matches!(
+            zosmf.files().change_mode("/u/jiahj/text.txt", "rwxr-xr-q"),
+            Err(Error::InvalidValue(message)) if message == "invalid file mode: rwxr-xr-q"
+        ));
+    }
+
+    #[test]
+    fn parse_file_mode() {
+        for (s, bits) in [
+            ("755", 0o755),
+            ("0644", 0o644),
+            ("4755", 0o4755),
+            ("rwxr-xr-x", 0o755),
+            ("RWXRW-RW-", 0o766),
+            ("-rw-r--r--", 0o644),
+            ("drwxrwxrwt", 0o1777),
+            ("rwsr-sr-x", 0o6755),
+            ("rwSr-Sr-T", 0o7644),
+        ] {
+            assert_eq!(s.parse::<FileMode>().unwrap().bits(), bits, "{}", s);
+        }
+
+        for s in [
+            "75",
+            "12345",
+            "789",
+            "rwxr-xr-",
+            "rwtr-xr-x",
+            "rwxr-xr-s",
+            "xrwr-xr-x",
+            "ürwxr-xr-",
+            "ürwxr-x",
+        ] {
+            assert!(s.parse::<FileMode>().is_err(), "{}", s);
+        }
+    }
+
+    #[test]
+    fn display_file_mode() {
+        assert_eq!(FileMode::from(0o755).to_string(), "755");
+        assert_eq!(FileMode::from(0o4755).to_string(), "4755");
+        assert_eq!(FileMode::from(0o640).symbolic(), "rw-r-----");
+        assert_eq!(FileMode::from(0o7644).symbolic(), "rwSr-Sr-T");
+    }
 }
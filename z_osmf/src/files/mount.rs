@@ -0,0 +1,194 @@
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use z_osmf_macros::Endpoint;
+
+use crate::convert::TryFromResponse;
+use crate::ClientCore;
+
+#[derive(Clone, Debug, Endpoint)]
+#[endpoint(method = put, path = "/zosmf/restfiles/mfs/{file_system_name}")]
+pub struct FileSystemMountBuilder<T>
+where
+    T: TryFromResponse,
+{
+    core: Arc<ClientCore>,
+
+    #[endpoint(path)]
+    file_system_name: Arc<str>,
+    #[endpoint(builder_fn = build_mount_body)]
+    mount_point: Arc<str>,
+    #[endpoint(skip_builder)]
+    file_system_type: Option<Arc<str>>,
+    #[endpoint(skip_builder)]
+    mode: Option<FileSystemMountMode>,
+    #[endpoint(skip_builder)]
+    setuid: Option<bool>,
+
+    target_type: PhantomData<T>,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FileSystemMountMode {
+    Rdonly,
+    Rdwr,
+}
+
+#[derive(Clone, Debug, Endpoint)]
+#[endpoint(method = put, path = "/zosmf/restfiles/mfs/{file_system_name}")]
+pub struct FileSystemUnmountBuilder<T>
+where
+    T: TryFromResponse,
+{
+    core: Arc<ClientCore>,
+
+    #[endpoint(path)]
+    file_system_name: Arc<str>,
+
+    #[endpoint(builder_fn = build_unmount_body)]
+    target_type: PhantomData<T>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "kebab-case")]
+struct MountJson<'a> {
+    action: &'static str,
+    mount_point: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    fs_type: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mode: Option<FileSystemMountMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    setuid: Option<&'static str>,
+}
+
+#[derive(Serialize)]
+struct UnmountJson {
+    action: &'static str,
+}
+
+fn build_mount_body<T>(
+    request_builder: reqwest::RequestBuilder,
+    builder: &FileSystemMountBuilder<T>,
+) -> reqwest::RequestBuilder
+where
+    T: TryFromResponse,
+{
+    request_builder.json(&MountJson {
+        action: "mount",
+        mount_point: &builder.mount_point,
+        fs_type: builder.file_system_type.as_deref(),
+        mode: builder.mode,
+        setuid: builder
+            .setuid
+            .map(|setuid| if setuid { "setuid" } else { "nosetuid" }),
+    })
+}
+
+fn build_unmount_body<T>(
+    request_builder: reqwest::RequestBuilder,
+    _builder: &FileSystemUnmountBuilder<T>,
+) -> reqwest::RequestBuilder
+where
+    T: TryFromResponse,
+{
+    request_builder.json(&UnmountJson { action: "unmount" })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{get_zosmf, GetJson};
+
+    use super::*;
+
+    #[test]
+    fn maximal_request() {
+        let zosmf = get_zosmf();
+
+        let json = r#"
+        {
+            "action": "mount",
+            "mount-point": "/u/ibmuser/mnt",
+            "fs-type": "zFS",
+            "mode": "rdonly",
+            "setuid": "nosetuid"
+        }
+        "#;
+        let manual_request = zosmf
+            .core
+            .client
+            .put("https://test.com/zosmf/restfiles/mfs/IBMUSER.TEST.ZFS")
+            .json(&serde_json::from_str::<serde_json::Value>(json).unwrap())
+            .build()
+            .unwrap();
+
+        let request = zosmf
+            .files()
+            .mount("IBMUSER.TEST.ZFS", "/u/ibmuser/mnt")
+            .file_system_type("zFS")
+            .mode(FileSystemMountMode::Rdonly)
+            .setuid(false)
+            .get_request()
+            .unwrap();
+
+        assert_eq!(format!("{:?}", manual_request), format!("{:?}", request));
+        assert_eq!(manual_request.json(), request.json());
+    }
+
+    #[test]
+    fn minimal_request() {
+        let zosmf = get_zosmf();
+
+        let json = r#"
+        {
+            "action": "mount",
+            "mount-point": "/u/ibmuser/mnt"
+        }
+        "#;
+        let manual_request = zosmf
+            .core
+            .client
+            .put("https://test.com/zosmf/restfiles/mfs/IBMUSER.TEST.ZFS")
+            .json(&serde_json::from_str::<serde_json::Value>(json).unwrap())
+            .build()
+            .unwrap();
+
+        let request = zosmf
+            .files()
+            .mount("IBMUSER.TEST.ZFS", "/u/ibmuser/mnt")
+            .get_request()
+            .unwrap();
+
+        assert_eq!(format!("{:?}", manual_request), format!("{:?}", request));
+        assert_eq!(manual_request.json(), request.json());
+    }
+
+    #[test]
+    fn unmount() {
+        let zosmf = get_zosmf();
+
+        let json = r#"
+        {
+            "action": "unmount"
+        }
+        "#;
+        let manual_request = zosmf
+            .core
+            .client
+            .put("https://test.com/zosmf/restfiles/mfs/IBMUSER.TEST.ZFS")
+            .json(&serde_json::from_str::<serde_json::Value>(json).unwrap())
+            .build()
+            .unwrap();
+
+        let request = zosmf
+            .files()
+            .unmount("IBMUSER.TEST.ZFS")
+            .get_request()
+            .unwrap();
+
+        assert_eq!(format!("{:?}", manual_request), format!("{:?}", request));
+        assert_eq!(manual_request.json(), request.json());
+    }
+}
@@ -8,7 +8,7 @@ use z_osmf_macros::{Endpoint, Getters};
 
 use crate::convert::TryFromResponse;
 use crate::restfiles::{get_etag, get_transaction_id};
-use crate::{ClientCore, Result};
+use crate::{ClientCore, ReadPayload, Result};
 
 use super::FileDataType;
 
@@ -20,12 +20,39 @@ pub struct FileRead<T> {
     transaction_id: Arc<str>,
 }
 
+impl<T> ReadPayload for FileRead<T>
+where
+    T: ReadPayload,
+{
+    fn as_text(&self) -> Option<&str> {
+        self.data.as_text()
+    }
+
+    fn as_bytes(&self) -> &[u8] {
+        self.data.as_bytes()
+    }
+}
+
 impl FileRead<Arc<str>> {
     pub fn data(&self) -> &str {
         &self.data
     }
 }
 
+impl std::ops::Deref for FileRead<Arc<str>> {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        &self.data
+    }
+}
+
+impl AsRef<str> for FileRead<Arc<str>> {
+    fn as_ref(&self) -> &str {
+        &self.data
+    }
+}
+
 impl TryFromResponse for FileRead<Arc<str>> {
     async fn try_from_response(value: reqwest::Response) -> Result<Self> {
         let (etag, transaction_id) = get_headers(&value)?;
@@ -46,6 +73,20 @@ impl FileRead<Bytes> {
     }
 }
 
+impl std::ops::Deref for FileRead<Bytes> {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        &self.data
+    }
+}
+
+impl AsRef<[u8]> for FileRead<Bytes> {
+    fn as_ref(&self) -> &[u8] {
+        &self.data
+    }
+}
+
 impl TryFromResponse for FileRead<Bytes> {
     async fn try_from_response(value: reqwest::Response) -> Result<Self> {
         let (etag, transaction_id) = get_headers(&value)?;
@@ -108,6 +149,18 @@ impl TryFromResponse for FileRead<Option<Bytes>> {
     }
 }
 
+/// A match found by [`FileReadBuilder::find_all`].
+#[derive(Clone, Debug, Deserialize, Eq, Getters, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub struct Match {
+    /// The line of the match, counted from one.
+    #[getter(copy)]
+    line: usize,
+    /// The byte offset of the match from the start of the file.
+    #[getter(copy)]
+    offset: usize,
+    text: Arc<str>,
+}
+
 #[derive(Clone, Debug, Endpoint)]
 #[endpoint(method = get, path = "/zosmf/restfiles/fs{path}")]
 pub struct FileReadBuilder<T>
@@ -122,7 +175,7 @@ where
     search: Option<Arc<str>>,
     #[endpoint(query = "research")]
     regex_search: Option<Arc<str>>,
-    #[endpoint(builder_fn = build_search_case_sensitive)]
+    #[endpoint(skip_setter, builder_fn = build_search_case_sensitive)]
     search_case_sensitive: Option<bool>,
     #[endpoint(query = "maxreturnsize")]
     search_max_return: Option<i32>,
@@ -136,6 +189,28 @@ where
     target_type: PhantomData<T>,
 }
 
+impl<T> FileReadBuilder<T>
+where
+    T: TryFromResponse,
+{
+    /// Set whether `search` and `regex_search` ignore case, as z/OSMF does by default.
+    ///
+    /// This maps directly to the `insensitive` query parameter.
+    pub fn case_insensitive(mut self, value: bool) -> Self {
+        self.search_case_sensitive = Some(!value);
+
+        self
+    }
+
+    /// Set whether `search` and `regex_search` match case.
+    ///
+    /// `search_case_sensitive(true)` is the same as `case_insensitive(false)`,
+    /// and sends `insensitive=false`.
+    pub fn search_case_sensitive(self, value: bool) -> Self {
+        self.case_insensitive(!value)
+    }
+}
+
 impl<U> FileReadBuilder<FileRead<U>>
 where
     FileRead<U>: TryFromResponse,
@@ -156,6 +231,21 @@ where
         }
     }
 
+    pub fn record(self) -> FileReadBuilder<FileRead<Bytes>> {
+        FileReadBuilder {
+            core: self.core,
+            path: self.path,
+            search: self.search,
+            regex_search: self.regex_search,
+            search_case_sensitive: self.search_case_sensitive,
+            search_max_return: self.search_max_return,
+            data_type: Some(FileDataType::Record),
+            encoding: self.encoding,
+            etag: self.etag,
+            target_type: PhantomData,
+        }
+    }
+
     pub fn text(self) -> FileReadBuilder<FileRead<Arc<str>>> {
         FileReadBuilder {
             core: self.core,
@@ -209,6 +299,21 @@ where
         }
     }
 
+    pub fn record(self) -> FileReadBuilder<FileRead<Option<Bytes>>> {
+        FileReadBuilder {
+            core: self.core,
+            path: self.path,
+            search: self.search,
+            regex_search: self.regex_search,
+            search_case_sensitive: self.search_case_sensitive,
+            search_max_return: self.search_max_return,
+            data_type: Some(FileDataType::Record),
+            encoding: self.encoding,
+            etag: self.etag,
+            target_type: PhantomData,
+        }
+    }
+
     pub fn text(self) -> FileReadBuilder<FileRead<Option<Arc<str>>>> {
         FileReadBuilder {
             core: self.core,
@@ -225,6 +330,86 @@ where
     }
 }
 
+#[cfg(feature = "regex")]
+impl FileReadBuilder<FileRead<Arc<str>>> {
+    /// Find every match of a regular expression in the file.
+    ///
+    /// The search runs on the client, so the full syntax of the
+    /// [`regex`](https://docs.rs/regex) crate is available. When the pattern is a plain string,
+    /// z/OSMF is first asked whether the file contains it at all, so files without a match
+    /// are not downloaded. Any search options already set on the builder are ignored.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let matches = zosmf
+    ///     .files()
+    ///     .read("/etc/inetd.conf")
+    ///     .find_all(r"^ftp\s+stream")
+    ///     .await?;
+    ///
+    /// for m in matches.iter() {
+    ///     println!("line {}: {}", m.line(), m.text());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn find_all<P>(self, pattern: P) -> Result<Vec<Match>>
+    where
+        P: std::fmt::Display,
+    {
+        let pattern = pattern.to_string();
+        let regex = regex::Regex::new(&pattern)
+            .map_err(|err| crate::Error::InvalidValue(err.to_string()))?;
+
+        let builder = FileReadBuilder {
+            search: None,
+            regex_search: None,
+            search_case_sensitive: None,
+            search_max_return: None,
+            ..self
+        };
+
+        if regex::escape(&pattern) == pattern {
+            let probe = builder
+                .clone()
+                .regex_search(&pattern)
+                .search_case_sensitive(true)
+                .search_max_return(1)
+                .build()
+                .await?;
+
+            if probe.data().is_empty() {
+                return Ok(Vec::new());
+            }
+        }
+
+        let file = builder.build().await?;
+
+        Ok(find_matches(&regex, file.data()))
+    }
+}
+
+#[cfg(feature = "regex")]
+fn find_matches(regex: &regex::Regex, text: &str) -> Vec<Match> {
+    let mut line = 1;
+    let mut position = 0;
+
+    regex
+        .find_iter(text)
+        .map(|found| {
+            line += text[position..found.start()].matches('\n').count();
+            position = found.start();
+
+            Match {
+                line,
+                offset: found.start(),
+                text: found.as_str().into(),
+            }
+        })
+        .collect()
+}
+
 fn build_data_type<T>(
     request_builder: reqwest::RequestBuilder,
     dataset_read_builder: &FileReadBuilder<T>,
@@ -273,6 +458,41 @@ fn get_headers(response: &reqwest::Response) -> Result<(Option<Arc<str>>, Arc<st
 mod tests {
     use crate::tests::*;
 
+    #[cfg(feature = "regex")]
+    #[test]
+    fn find_matches() {
+        let regex = regex::Regex::new(r"ftp\w*").unwrap();
+        let matches = super::find_matches(&regex, "ftp stream\ntelnet\n\nftpd tcp ftp\n");
+
+        assert_eq!(
+            matches
+                .iter()
+                .map(|m| (m.line(), m.offset(), m.text()))
+                .collect::<Vec<_>>(),
+            vec![(1, 0, "ftp"), (4, 19, "ftpd"), (4, 28, "ftp")]
+        );
+    }
+
+    #[test]
+    fn encoded_path() {
+        let zosmf = get_zosmf();
+
+        let manual_request = zosmf
+            .core
+            .client
+            .get("https://test.com/zosmf/restfiles/fs/u/jiahj/test%20file%23%3F.txt")
+            .build()
+            .unwrap();
+
+        let request = zosmf
+            .files()
+            .read("/u/jiahj/test file#?.txt")
+            .get_request()
+            .unwrap();
+
+        assert_eq!(format!("{:?}", manual_request), format!("{:?}", request));
+    }
+
     #[test]
     fn data_type() {
         let zosmf = get_zosmf();
@@ -295,6 +515,30 @@ mod tests {
         assert_eq!(format!("{:?}", manual_request), format!("{:?}", request));
     }
 
+    #[test]
+    fn data_type_record() {
+        let zosmf = get_zosmf();
+
+        let manual_request = zosmf
+            .core
+            .client
+            .get("https://test.com/zosmf/restfiles/fs/u/jiahj/testFile.txt")
+            .header("X-IBM-Data-Type", "record")
+            .header("If-None-Match", "abcd1234")
+            .build()
+            .unwrap();
+
+        let request = zosmf
+            .files()
+            .read("/u/jiahj/testFile.txt")
+            .if_none_match("abcd1234")
+            .record()
+            .get_request()
+            .unwrap();
+
+        assert_eq!(format!("{:?}", manual_request), format!("{:?}", request));
+    }
+
     #[test]
     fn etag() {
         let zosmf = get_zosmf();
@@ -410,4 +654,43 @@ mod tests {
 
         assert_eq!(format!("{:?}", manual_request), format!("{:?}", read_file))
     }
+
+    #[test]
+    fn case_insensitive() {
+        let zosmf = get_zosmf();
+
+        let case_sensitive = zosmf
+            .files()
+            .read("/etc/inetd.conf")
+            .search("something")
+            .search_case_sensitive(true)
+            .get_request()
+            .unwrap();
+
+        let not_case_insensitive = zosmf
+            .files()
+            .read("/etc/inetd.conf")
+            .search("something")
+            .case_insensitive(false)
+            .get_request()
+            .unwrap();
+
+        assert_eq!(
+            format!("{:?}", case_sensitive),
+            format!("{:?}", not_case_insensitive)
+        );
+
+        let read_file = zosmf
+            .files()
+            .read("/etc/inetd.conf")
+            .search("something")
+            .case_insensitive(true)
+            .get_request()
+            .unwrap();
+
+        assert_eq!(
+            read_file.url().as_str(),
+            "https://test.com/zosmf/restfiles/fs/etc/inetd.conf?search=something"
+        );
+    }
 }
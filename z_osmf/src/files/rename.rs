@@ -5,7 +5,9 @@ use serde::Serialize;
 use z_osmf_macros::Endpoint;
 
 use crate::convert::TryFromResponse;
-use crate::ClientCore;
+use crate::{ClientCore, Error, Result};
+
+use super::FilesClient;
 
 #[derive(Clone, Debug, Endpoint)]
 #[endpoint(method = put, path = "/zosmf/restfiles/fs{to_path}")]
@@ -25,6 +27,60 @@ where
     target_type: PhantomData<T>,
 }
 
+impl FileRenameBuilder<String> {
+    /// Once the path is moved, check that the new path exists
+    /// and the old one no longer does, failing with [`Error::Unexpected`] otherwise.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let rename_file = zosmf
+    ///     .files()
+    ///     .rename("/u/jiahj/sourceFile.txt", "/u/jiahj/testFile.txt")
+    ///     .and_verify()
+    ///     .build()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn and_verify(self) -> FileRenameVerifyBuilder {
+        FileRenameVerifyBuilder { rename: self }
+    }
+}
+
+/// Move a file or directory, then check that only the new path exists.
+#[derive(Clone, Debug)]
+pub struct FileRenameVerifyBuilder {
+    rename: FileRenameBuilder<String>,
+}
+
+impl FileRenameVerifyBuilder {
+    pub async fn build(self) -> Result<String> {
+        let FileRenameVerifyBuilder { rename } = self;
+        let files = FilesClient::new(ClientCore::clone(&rename.core));
+        let from_path = rename.from_path.clone();
+        let to_path = rename.to_path.clone();
+
+        let transaction_id = rename.build().await?;
+
+        if !files.exists(&to_path).await? {
+            return Err(Error::Unexpected(format!(
+                "{} does not exist after the rename",
+                to_path
+            )));
+        }
+
+        if files.exists(&from_path).await? {
+            return Err(Error::Unexpected(format!(
+                "{} still exists after the rename",
+                from_path
+            )));
+        }
+
+        Ok(transaction_id)
+    }
+}
+
 #[derive(Serialize)]
 struct RequestJson<'a> {
     request: &'static str,
@@ -0,0 +1,138 @@
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use chrono::NaiveDateTime;
+use serde::Serialize;
+use z_osmf_macros::Endpoint;
+
+use crate::convert::TryFromResponse;
+use crate::ClientCore;
+
+#[derive(Clone, Debug, Endpoint)]
+#[endpoint(method = put, path = "/zosmf/restfiles/fs{path}")]
+pub struct FileChangeTimeBuilder<T>
+where
+    T: TryFromResponse,
+{
+    core: Arc<ClientCore>,
+
+    #[endpoint(path)]
+    path: Arc<str>,
+    #[endpoint(builder_fn = build_body)]
+    atime: Option<NaiveDateTime>,
+    #[endpoint(skip_builder)]
+    mtime: Option<NaiveDateTime>,
+    #[endpoint(skip_builder)]
+    recursive: Option<bool>,
+
+    target_type: PhantomData<T>,
+}
+
+#[derive(Serialize)]
+struct RequestJson {
+    request: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    atime: Option<NaiveDateTime>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mtime: Option<NaiveDateTime>,
+    recursive: bool,
+}
+
+fn build_body<T>(
+    request_builder: reqwest::RequestBuilder,
+    builder: &FileChangeTimeBuilder<T>,
+) -> reqwest::RequestBuilder
+where
+    T: TryFromResponse,
+{
+    request_builder.json(&RequestJson {
+        request: "utime",
+        atime: builder.atime,
+        mtime: builder.mtime,
+        recursive: builder.recursive == Some(true),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use crate::tests::{get_zosmf, GetJson};
+
+    #[test]
+    fn maximal_request() {
+        let zosmf = get_zosmf();
+
+        let json: serde_json::Value = serde_json::from_str(
+            r#"
+        {
+            "request": "utime",
+            "atime": "2024-03-01T08:30:00",
+            "mtime": "2024-02-29T17:45:10",
+            "recursive": true
+        }
+        "#,
+        )
+        .unwrap();
+        let manual_request = zosmf
+            .core
+            .client
+            .put("https://test.com/zosmf/restfiles/fs/u/jiahj/testDir")
+            .json(&json)
+            .build()
+            .unwrap();
+
+        let request = zosmf
+            .files()
+            .change_time("/u/jiahj/testDir")
+            .atime(
+                NaiveDate::from_ymd_opt(2024, 3, 1)
+                    .unwrap()
+                    .and_hms_opt(8, 30, 0)
+                    .unwrap(),
+            )
+            .mtime(
+                NaiveDate::from_ymd_opt(2024, 2, 29)
+                    .unwrap()
+                    .and_hms_opt(17, 45, 10)
+                    .unwrap(),
+            )
+            .recursive(true)
+            .get_request()
+            .unwrap();
+
+        assert_eq!(format!("{:?}", manual_request), format!("{:?}", request));
+        assert_eq!(manual_request.json(), request.json());
+    }
+
+    #[test]
+    fn minimal_request() {
+        let zosmf = get_zosmf();
+
+        let json: serde_json::Value = serde_json::from_str(
+            r#"
+        {
+            "request": "utime",
+            "recursive": false
+        }
+        "#,
+        )
+        .unwrap();
+        let manual_request = zosmf
+            .core
+            .client
+            .put("https://test.com/zosmf/restfiles/fs/u/jiahj/test.txt")
+            .json(&json)
+            .build()
+            .unwrap();
+
+        let request = zosmf
+            .files()
+            .change_time("/u/jiahj/test.txt")
+            .get_request()
+            .unwrap();
+
+        assert_eq!(format!("{:?}", manual_request), format!("{:?}", request));
+        assert_eq!(manual_request.json(), request.json());
+    }
+}
@@ -2,10 +2,12 @@ use std::marker::PhantomData;
 use std::sync::Arc;
 
 use bytes::Bytes;
+use futures_core::Stream;
 use z_osmf_macros::Endpoint;
 
-use crate::convert::TryFromResponse;
-use crate::ClientCore;
+use crate::convert::{TryFromResponse, TryIntoTarget};
+use crate::restfiles::{send_stream, with_stream_body};
+use crate::{ClientCore, Result, Transacted};
 
 #[derive(Clone, Debug, Endpoint)]
 #[endpoint(method = put, path = "/zosmf/restfiles/fs{path}")]
@@ -51,6 +53,111 @@ where
 
         self
     }
+
+    /// Upload binary data from a stream, without holding it all in memory.
+    ///
+    /// This replaces any data set before, and returns a [`FileWriteStreamBuilder`] that owns the
+    /// stream and sends it once, when it is built, so the request is never retried.
+    /// When combined with [`if_match`](Self::if_match), z/OSMF rejects a stale etag
+    /// with [`Error::PreconditionFailed`](crate::Error::PreconditionFailed) and the file is left unchanged;
+    /// a new stream must be supplied to try the write again.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// # let etag = "";
+    /// let chunks: Vec<Result<z_osmf::Bytes, std::io::Error>> = vec![
+    ///     Ok("first chunk".into()),
+    ///     Ok("second chunk".into()),
+    /// ];
+    ///
+    /// let write_file = zosmf
+    ///     .files()
+    ///     .write("/u/jiahj/large.bin")
+    ///     .if_match(etag)
+    ///     .body_stream(futures_util::stream::iter(chunks))
+    ///     .build()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn body_stream<S, E>(mut self, stream: S) -> FileWriteStreamBuilder<T>
+    where
+        S: Stream<Item = std::result::Result<Bytes, E>> + Send + 'static,
+        E: Into<Box<dyn std::error::Error + Send + Sync>>,
+    {
+        self.data = None;
+
+        FileWriteStreamBuilder {
+            builder: self,
+            body: reqwest::Body::wrap_stream(stream),
+        }
+    }
+}
+
+/// # FileWriteStreamBuilder
+///
+/// A file write with a streamed body, created by [`FileWriteBuilder::body_stream`].
+/// Building it consumes the stream, so it can only be sent once.
+#[derive(Debug)]
+pub struct FileWriteStreamBuilder<T>
+where
+    T: TryFromResponse,
+{
+    builder: FileWriteBuilder<T>,
+    body: reqwest::Body,
+}
+
+impl<T> FileWriteStreamBuilder<T>
+where
+    T: TryFromResponse,
+{
+    /// Build the request, with the streamed body, without sending it.
+    pub fn into_request_parts(
+        self,
+    ) -> Result<(
+        reqwest::Method,
+        reqwest::Url,
+        reqwest::header::HeaderMap,
+        Option<reqwest::Body>,
+    )> {
+        let mut request = self.into_request()?;
+        let body = request.body_mut().take();
+
+        Ok((
+            request.method().clone(),
+            request.url().clone(),
+            std::mem::take(request.headers_mut()),
+            body,
+        ))
+    }
+
+    pub async fn build(self) -> Result<T> {
+        let core = self.builder.core.clone();
+
+        send_stream(&core, self.into_request()?)
+            .await?
+            .try_into_target()
+            .await
+    }
+
+    /// Send the request like `build`, keeping the z/OSMF transaction ID of the response.
+    pub async fn build_transacted(self) -> Result<Transacted<T>> {
+        let core = self.builder.core.clone();
+
+        send_stream(&core, self.into_request()?)
+            .await?
+            .try_into_target()
+            .await
+    }
+
+    fn into_request(self) -> Result<reqwest::Request> {
+        Ok(with_stream_body(
+            self.builder.get_request()?,
+            "binary",
+            self.body,
+        ))
+    }
 }
 
 fn build_data<T>(
@@ -142,7 +249,20 @@ mod tests {
             .get_request()
             .unwrap();
 
-        assert_eq!(format!("{:?}", manual_request), format!("{:?}", request))
+        assert_eq!(format!("{:?}", manual_request), format!("{:?}", request));
+
+        let request = zosmf
+            .files()
+            .write("/u/jiahj/testFile.txt")
+            .encoding("IBM-1047")
+            .text("some data")
+            .get_request()
+            .unwrap();
+
+        assert_eq!(
+            request.headers()["X-IBM-Data-Type"],
+            "text;fileEncoding=IBM-1047"
+        );
     }
 
     #[test]
@@ -168,4 +288,34 @@ mod tests {
 
         assert_eq!(format!("{:?}", manual_request), format!("{:?}", write_file))
     }
+
+    #[test]
+    fn body_stream() {
+        let zosmf = get_zosmf();
+
+        let manual_request = zosmf
+            .core
+            .client
+            .put("https://test.com/zosmf/restfiles/fs/u/jiahj/large.bin")
+            .header("If-Match", "ABCD1234")
+            .header("X-IBM-Data-Type", "binary")
+            .build()
+            .unwrap();
+
+        let chunks: Vec<Result<Bytes, std::io::Error>> =
+            vec![Ok("some ".into()), Ok("data".into())];
+        let (method, url, headers, body) = zosmf
+            .files()
+            .write("/u/jiahj/large.bin")
+            .if_match("ABCD1234")
+            .text("replaced by the stream")
+            .body_stream(futures_util::stream::iter(chunks))
+            .into_request_parts()
+            .unwrap();
+
+        assert_eq!(&method, manual_request.method());
+        assert_eq!(&url, manual_request.url());
+        assert_eq!(&headers, manual_request.headers());
+        assert!(body.is_some_and(|body| body.as_bytes().is_none()));
+    }
 }
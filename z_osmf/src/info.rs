@@ -5,19 +5,73 @@ use serde::{Deserialize, Serialize};
 use z_osmf_macros::{Endpoint, Getters};
 
 use crate::convert::TryFromResponse;
-use crate::{ClientCore, Result};
+use crate::{ClientCore, Error, Result};
 
 #[derive(Clone, Debug, Deserialize, Eq, Getters, Hash, Ord, PartialEq, PartialOrd, Serialize)]
 pub struct Info {
-    zosmf_saf_realm: Arc<str>,
-    zosmf_port: Arc<str>,
+    #[serde(default)]
+    zosmf_saf_realm: Option<Arc<str>>,
+    #[serde(default)]
+    zosmf_port: Option<Arc<str>>,
     plugins: Arc<[Plugin]>,
-    api_version: Arc<str>,
-    zos_version: Arc<str>,
+    #[serde(default)]
+    api_version: Option<Arc<str>>,
+    #[serde(default)]
+    zos_version: Option<Arc<str>>,
     zosmf_version: Arc<str>,
+    #[serde(default)]
+    zosmf_full_version: Option<Arc<str>>,
     zosmf_hostname: Arc<str>,
 }
 
+impl Info {
+    /// Check whether a plugin, like `"Workflow"`, is installed, ignoring case.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let info = zosmf.info().await?;
+    /// if info.has_plugin("Workflow") && info.zosmf_semver()? >= z_osmf::semver::Version::new(27, 0, 0) {
+    ///     let workflows = zosmf.workflows().list().build().await?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn has_plugin(&self, name: &str) -> bool {
+        self.plugins
+            .iter()
+            .any(|plugin| plugin.default_name.eq_ignore_ascii_case(name))
+    }
+
+    /// The z/OSMF version as a semantic version, read from `zosmf_full_version`, like `27.0`,
+    /// or from `zosmf_version`, like `27` for z/OS V2R4, when z/OSMF does not send it.
+    ///
+    /// Missing minor and patch components are read as zero.
+    pub fn zosmf_semver(&self) -> Result<semver::Version> {
+        parse_version(
+            self.zosmf_full_version
+                .as_deref()
+                .unwrap_or(&self.zosmf_version),
+        )
+    }
+}
+
+fn parse_version(version: &str) -> Result<semver::Version> {
+    let invalid = || Error::InvalidValue(format!("invalid z/OSMF version: {:?}", version));
+
+    let parts = version
+        .split('.')
+        .map(|part| part.trim().parse().map_err(|_| invalid()))
+        .collect::<Result<Vec<u64>>>()?;
+
+    match parts[..] {
+        [major] => Ok(semver::Version::new(major, 0, 0)),
+        [major, minor] => Ok(semver::Version::new(major, minor, 0)),
+        [major, minor, patch] => Ok(semver::Version::new(major, minor, patch)),
+        _ => Err(invalid()),
+    }
+}
+
 impl TryFromResponse for Info {
     async fn try_from_response(value: reqwest::Response) -> Result<Self> {
         Ok(value.json().await?)
@@ -40,7 +94,106 @@ pub(crate) struct InfoBuilder<T>
 where
     T: TryFromResponse,
 {
-    core: ClientCore,
+    core: Arc<ClientCore>,
 
     target_type: PhantomData<T>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_info(zosmf_version: &str) -> Info {
+        serde_json::from_str(&format!(
+            r#"
+            {{
+                "zos_version": "04.27.00",
+                "zosmf_port": "443",
+                "zosmf_version": "{}",
+                "zosmf_hostname": "zosmf.test.com",
+                "plugins": [
+                    {{"pluginVersion": "HSMA250", "pluginDefaultName": "z/OS Operator Consoles", "pluginStatus": "ACTIVE"}},
+                    {{"pluginVersion": "HSMA250", "pluginDefaultName": "Workflow", "pluginStatus": "ACTIVE"}}
+                ],
+                "zosmf_saf_realm": "SAFRealm",
+                "zosmf_full_version": "27.0",
+                "api_version": "1"
+            }}
+            "#,
+            zosmf_version
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn deserialize_info() {
+        let info = get_info("27");
+
+        assert_eq!(info.zosmf_saf_realm(), Some("SAFRealm"));
+        assert_eq!(info.zosmf_port(), Some("443"));
+        assert_eq!(info.api_version(), Some("1"));
+        assert_eq!(info.zos_version(), Some("04.27.00"));
+        assert_eq!(info.zosmf_hostname(), "zosmf.test.com");
+
+        let info: Info = serde_json::from_str(
+            r#"
+            {
+                "zosmf_version": "25",
+                "zosmf_hostname": "zosmf.test.com",
+                "plugins": []
+            }
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(info.zosmf_saf_realm(), None);
+        assert_eq!(info.zosmf_port(), None);
+        assert_eq!(info.api_version(), None);
+        assert_eq!(info.zos_version(), None);
+    }
+
+    #[test]
+    fn has_plugin() {
+        let info = get_info("27");
+
+        assert!(info.has_plugin("Workflow"));
+        assert!(info.has_plugin("workflow"));
+        assert!(!info.has_plugin("Software Management"));
+    }
+
+    #[test]
+    fn zosmf_semver() {
+        let info = get_info("27");
+        assert_eq!(info.zosmf_version(), "27");
+        assert_eq!(info.zosmf_full_version(), Some("27.0"));
+        assert_eq!(info.zosmf_semver().unwrap(), semver::Version::new(27, 0, 0));
+
+        let info: Info = serde_json::from_str(
+            r#"{"zosmf_version": "28", "zosmf_hostname": "zosmf.test.com", "plugins": []}"#,
+        )
+        .unwrap();
+        assert_eq!(info.zosmf_full_version(), None);
+        assert_eq!(info.zosmf_semver().unwrap(), semver::Version::new(28, 0, 0));
+    }
+
+    #[test]
+    fn test_parse_version() {
+        assert_eq!(parse_version("27").unwrap(), semver::Version::new(27, 0, 0));
+        assert_eq!(
+            parse_version("28.1").unwrap(),
+            semver::Version::new(28, 1, 0)
+        );
+        assert_eq!(
+            parse_version("28.1.2").unwrap(),
+            semver::Version::new(28, 1, 2)
+        );
+        assert!(parse_version("26").unwrap() < parse_version("27.0").unwrap());
+
+        for version in ["", "V2R4", "27.x", "27..0", "1.2.3.4"] {
+            assert!(matches!(
+                parse_version(version),
+                Err(Error::InvalidValue(_))
+            ));
+        }
+    }
+}
@@ -5,6 +5,7 @@ pub mod list;
 pub mod purge;
 pub mod status;
 pub mod submit;
+pub mod wait;
 
 use std::sync::Arc;
 
@@ -22,6 +23,7 @@ use self::list::{JobList, JobListBuilder};
 use self::purge::JobPurgeBuilder;
 use self::status::JobStatusBuilder;
 use self::submit::{JobSource, JobSubmitBuilder};
+use self::wait::JobWaitBuilder;
 
 #[derive(Clone, Debug)]
 pub struct JobsClient {
@@ -40,7 +42,7 @@ impl JobsClient {
     /// ```
     /// # use z_osmf::jobs::JobIdentifier;
     /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
-    /// let identifier = JobIdentifier::NameId("TESTJOB2".to_string(), "JOB00084".to_string());
+    /// let identifier = JobIdentifier::name_id("TESTJOB2", "JOB00084");
     ///
     /// let job_feedback = zosmf
     ///     .jobs()
@@ -50,6 +52,22 @@ impl JobsClient {
     /// # Ok(())
     /// # }
     /// ```
+    ///
+    /// Request cancellation of job TESTJOB2 with ID JOB0084 without waiting for it to complete:
+    /// ```
+    /// # use z_osmf::jobs::JobIdentifier;
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let identifier = JobIdentifier::name_id("TESTJOB2", "JOB00084");
+    ///
+    /// let job_feedback = zosmf
+    ///     .jobs()
+    ///     .cancel(identifier)
+    ///     .asynchronous()
+    ///     .build()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
     pub fn cancel<I>(&self, identifier: I) -> JobFeedbackBuilder<JobFeedback>
     where
         I: Into<JobIdentifier>,
@@ -63,7 +81,7 @@ impl JobsClient {
     /// ```
     /// # use z_osmf::jobs::JobIdentifier;
     /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
-    /// let identifier = JobIdentifier::NameId("TESTJOBW".to_string(), "JOB00085".to_string());
+    /// let identifier = JobIdentifier::name_id("TESTJOBW", "JOB00085");
     ///
     /// let job_feedback = zosmf
     ///     .jobs()
@@ -86,7 +104,7 @@ impl JobsClient {
     /// ```
     /// # use z_osmf::jobs::JobIdentifier;
     /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
-    /// let identifier = JobIdentifier::NameId("TESTJOBW".to_string(), "JOB00023".to_string());
+    /// let identifier = JobIdentifier::name_id("TESTJOBW", "JOB00023");
     ///
     /// let job_feedback = zosmf
     ///     .jobs()
@@ -110,7 +128,7 @@ impl JobsClient {
     /// ```
     /// # use z_osmf::jobs::JobIdentifier;
     /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
-    /// let identifier = JobIdentifier::NameId("TESTJOBW".to_string(), "JOB00023".to_string());
+    /// let identifier = JobIdentifier::name_id("TESTJOBW", "JOB00023");
     ///
     /// let job_feedback = zosmf
     ///     .jobs()
@@ -153,7 +171,7 @@ impl JobsClient {
     /// ```
     /// # use z_osmf::jobs::JobIdentifier;
     /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
-    /// let identifier = JobIdentifier::NameId("TESTJOB1".to_string(), "JOB00023".to_string());
+    /// let identifier = JobIdentifier::name_id("TESTJOB1", "JOB00023");
     ///
     /// let job_files = zosmf
     ///     .jobs()
@@ -163,6 +181,27 @@ impl JobsClient {
     /// # Ok(())
     /// # }
     /// ```
+    ///
+    /// Check the size of each spool file before reading it:
+    /// ```
+    /// # use z_osmf::jobs::JobIdentifier;
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let identifier = JobIdentifier::name_id("TESTJOB1", "JOB00023");
+    ///
+    /// let job_files = zosmf.jobs().list_files(identifier).build().await?;
+    /// for job_file in job_files.items().iter() {
+    ///     println!(
+    ///         "{} {} {}: {} records, {} bytes",
+    ///         job_file.step_name().unwrap_or_default(),
+    ///         job_file.proc_step().unwrap_or_default(),
+    ///         job_file.dd_name(),
+    ///         job_file.record_count(),
+    ///         job_file.byte_count()
+    ///     );
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
     pub fn list_files<I>(&self, identifier: I) -> JobFileListBuilder<JobFileList>
     where
         I: Into<JobIdentifier>,
@@ -177,7 +216,7 @@ impl JobsClient {
     /// # use z_osmf::jobs::files::read::JobFileId;
     /// # use z_osmf::jobs::JobIdentifier;
     /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
-    /// let identifier = JobIdentifier::NameId("TESTJOBJ".to_string(), "JOB00023".to_string());
+    /// let identifier = JobIdentifier::name_id("TESTJOBJ", "JOB00023");
     ///
     /// let job_file = zosmf
     ///     .jobs()
@@ -190,16 +229,15 @@ impl JobsClient {
     ///
     /// Read a range of records (the first 250) of file 8 for job TESTJOBJ with ID JOB00023:
     /// ```
-    /// # use std::str::FromStr;
     /// # use z_osmf::jobs::files::read::{JobFileId, RecordRange};
     /// # use z_osmf::jobs::JobIdentifier;
     /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
-    /// let identifier = JobIdentifier::NameId("TESTJOBJ".to_string(), "JOB00023".to_string());
+    /// let identifier = JobIdentifier::name_id("TESTJOBJ", "JOB00023");
     ///
     /// let job_file = zosmf
     ///     .jobs()
     ///     .read_file(identifier, JobFileId::Id(8))
-    ///     .record_range(RecordRange::from_str("0-249")?)
+    ///     .record_range(RecordRange::new(0, 249)?)
     ///     .build()
     ///     .await?;
     /// # Ok(())
@@ -211,7 +249,7 @@ impl JobsClient {
     /// # use z_osmf::jobs::files::read::JobFileId;
     /// # use z_osmf::jobs::JobIdentifier;
     /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
-    /// let identifier = JobIdentifier::NameId("TESTJOBJ".to_string(), "JOB00060".to_string());
+    /// let identifier = JobIdentifier::name_id("TESTJOBJ", "JOB00060");
     ///
     /// let job_file = zosmf
     ///     .jobs()
@@ -233,13 +271,91 @@ impl JobsClient {
         JobFileReadBuilder::new(self.core.clone(), identifier, file_id)
     }
 
+    /// Read the first spool file allocated to a DD name, like `SYSPRINT` or `JESMSGLG`.
+    ///
+    /// Fails with [`Error::InvalidValue`] when the job has no spool file for the DD name.
+    ///
+    /// # Example
+    /// ```
+    /// # use z_osmf::jobs::JobIdentifier;
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let identifier = JobIdentifier::name_id("TESTJOBJ", "JOB00023");
+    ///
+    /// let job_file = zosmf
+    ///     .jobs()
+    ///     .read_file_by_ddname(identifier, "SYSPRINT")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn read_file_by_ddname<I, D>(
+        &self,
+        identifier: I,
+        dd_name: D,
+    ) -> Result<JobFileRead<Arc<str>>>
+    where
+        I: Into<JobIdentifier>,
+        D: std::fmt::Display,
+    {
+        let identifier = identifier.into();
+        let dd_name = dd_name.to_string();
+
+        let job_files = self.list_files(identifier.clone()).build().await?;
+        let job_file = job_files.find_dd_name(&dd_name).ok_or_else(|| {
+            Error::InvalidValue(format!(
+                "job {} has no spool file for DD name {}",
+                identifier, dd_name
+            ))
+        })?;
+
+        self.read_file(identifier, job_file.id()).build().await
+    }
+
+    /// Purge the output of a job that has completed.
+    ///
+    /// z/OSMF purges output with the same `DELETE` request as [`cancel_and_purge`](Self::cancel_and_purge),
+    /// which cancels a job that is still running. So the job status is checked first,
+    /// and a job that does not have a status of [`JobStatus::Output`] is refused with
+    /// [`Error::InvalidValue`] instead.
+    ///
+    /// # Example
+    /// ```
+    /// # use z_osmf::jobs::JobIdentifier;
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let identifier = JobIdentifier::correlator("J0000085SY1.....CC20F380.......:");
+    ///
+    /// let job_feedback = zosmf.jobs().purge(identifier).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn purge<I>(&self, identifier: I) -> Result<JobFeedback>
+    where
+        I: Into<JobIdentifier>,
+    {
+        let identifier = identifier.into();
+
+        let job = self.status(identifier.clone()).build().await?;
+        match job.status() {
+            Some(JobStatus::Output) => {}
+            status => {
+                return Err(Error::InvalidValue(format!(
+                    "job {} has not completed, with status {}",
+                    identifier,
+                    status.map_or_else(|| "unknown".to_string(), |status| status.to_string())
+                )))
+            }
+        }
+
+        self.cancel_and_purge(identifier).build().await
+    }
+
     /// # Examples
     ///
     /// Release job TESTJOBW with ID JOB0023:
     /// ```
     /// # use z_osmf::jobs::JobIdentifier;
     /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
-    /// let identifier = JobIdentifier::NameId("TESTJOBW".to_string(), "JOB00023".to_string());
+    /// let identifier = JobIdentifier::name_id("TESTJOBW", "JOB00023");
     ///
     /// let job_feedback = zosmf
     ///     .jobs()
@@ -262,7 +378,7 @@ impl JobsClient {
     /// ```
     /// # use z_osmf::jobs::JobIdentifier;
     /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
-    /// let identifier = JobIdentifier::NameId("BLSJPRMI".to_string(), "STC00052".to_string());
+    /// let identifier = JobIdentifier::name_id("BLSJPRMI", "STC00052");
     ///
     /// let job_status = zosmf
     ///     .jobs()
@@ -301,12 +417,73 @@ impl JobsClient {
     /// # Ok(())
     /// # }
     /// ```
+    ///
+    /// Submit a job from text with JCL symbols:
+    /// ```
+    /// # use z_osmf::jobs::submit::{JclData, JobSource};
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let jcl = r#"//TESTJOBX JOB (),MSGCLASS=H
+    /// //STEP1 EXEC PGM=IEBGENER
+    /// //SYSUT1 DD DSN=&HLQ..INPUT,DISP=SHR
+    /// "#;
+    ///
+    /// let job_data = zosmf
+    ///     .jobs()
+    ///     .submit(JobSource::Jcl(JclData::Text(jcl.into())))
+    ///     .symbol("HLQ", "IBMUSER")
+    ///     .build()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
     pub fn submit<S>(&self, source: S) -> JobSubmitBuilder<JobAttributes>
     where
         S: Into<JobSource>,
     {
         JobSubmitBuilder::new(self.core.clone(), source)
     }
+
+    /// # Examples
+    ///
+    /// Wait up to 10 minutes for the output of job TESTJOB1 with ID JOB00023:
+    /// ```
+    /// # use std::time::Duration;
+    /// # use z_osmf::jobs::JobIdentifier;
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let identifier = JobIdentifier::name_id("TESTJOB1", "JOB00023");
+    ///
+    /// let job_attributes = zosmf
+    ///     .jobs()
+    ///     .wait(identifier)
+    ///     .poll_interval(Duration::from_secs(2))
+    ///     .timeout(Duration::from_secs(600))
+    ///     .build()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Wait for a job to leave the input queue:
+    /// ```
+    /// # use z_osmf::jobs::{JobIdentifier, JobStatus};
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let identifier = JobIdentifier::name_id("TESTJOB1", "JOB00023");
+    ///
+    /// let job_attributes = zosmf
+    ///     .jobs()
+    ///     .wait(identifier)
+    ///     .until(|status| *status != JobStatus::Input)
+    ///     .build()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn wait<I>(&self, identifier: I) -> JobWaitBuilder
+    where
+        I: Into<JobIdentifier>,
+    {
+        JobWaitBuilder::new(self.status(identifier))
+    }
 }
 
 #[derive(Clone, Debug, Deserialize, Eq, Getters, Hash, Ord, PartialEq, PartialOrd, Serialize)]
@@ -318,7 +495,6 @@ pub struct JobAttributes {
     name: Arc<str>,
     subsystem: Option<Arc<str>>,
     owner: Arc<str>,
-    #[getter(copy)]
     status: Option<JobStatus>,
     #[getter(copy)]
     #[serde(rename = "type")]
@@ -339,6 +515,13 @@ impl JobAttributes {
     pub fn identifier(&self) -> JobIdentifier {
         self.into()
     }
+
+    /// The return code of the job, parsed into a [`ReturnCode`].
+    ///
+    /// Returns `None` while the job has not completed.
+    pub fn parsed_return_code(&self) -> Option<ReturnCode> {
+        self.return_code.as_deref().map(ReturnCode::from)
+    }
 }
 
 impl TryFromResponse for JobAttributes {
@@ -422,8 +605,27 @@ impl TryFromResponse for JobAttributesStep {
 
 #[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
 pub enum JobIdentifier {
-    Correlator(String),
-    NameId(String, String),
+    Correlator(Arc<str>),
+    NameId(Arc<str>, Arc<str>),
+}
+
+impl JobIdentifier {
+    /// Identify a job by its name and ID, like `TESTJOBJ` and `JOB00023`.
+    pub fn name_id<N, I>(name: N, id: I) -> Self
+    where
+        N: Into<Arc<str>>,
+        I: Into<Arc<str>>,
+    {
+        JobIdentifier::NameId(name.into(), id.into())
+    }
+
+    /// Identify a job by its correlator.
+    pub fn correlator<C>(correlator: C) -> Self
+    where
+        C: Into<Arc<str>>,
+    {
+        JobIdentifier::Correlator(correlator.into())
+    }
 }
 
 impl std::str::FromStr for JobIdentifier {
@@ -431,8 +633,8 @@ impl std::str::FromStr for JobIdentifier {
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
         match s.split('/').collect::<Vec<_>>()[..] {
-            [name, id] => Ok(JobIdentifier::NameId(name.to_string(), id.to_string())),
-            [correlator] => Ok(JobIdentifier::Correlator(correlator.to_string())),
+            [name, id] => Ok(JobIdentifier::NameId(name.into(), id.into())),
+            [correlator] => Ok(JobIdentifier::Correlator(correlator.into())),
             _ => Err(Error::InvalidValue(format!(
                 "invalid job identifier: {}",
                 s
@@ -441,6 +643,14 @@ impl std::str::FromStr for JobIdentifier {
     }
 }
 
+impl TryFrom<&str> for JobIdentifier {
+    type Error = Error;
+
+    fn try_from(value: &str) -> std::result::Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
 impl std::fmt::Display for JobIdentifier {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -450,18 +660,85 @@ impl std::fmt::Display for JobIdentifier {
     }
 }
 
+/// Identifiers share their names, so this only counts references, without copying strings.
+impl From<&JobIdentifier> for JobIdentifier {
+    fn from(value: &JobIdentifier) -> Self {
+        value.clone()
+    }
+}
+
 impl From<&JobAttributes> for JobIdentifier {
     fn from(value: &JobAttributes) -> Self {
-        JobIdentifier::NameId(value.name().to_string(), value.id().to_string())
+        JobIdentifier::NameId(value.name.clone(), value.id.clone())
     }
 }
 
-#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
-#[serde(rename_all = "UPPERCASE")]
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[non_exhaustive]
 pub enum JobStatus {
     Active,
     Input,
     Output,
+    /// Any other status reported by z/OSMF.
+    Unknown(Arc<str>),
+}
+
+impl From<String> for JobStatus {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "ACTIVE" => JobStatus::Active,
+            "INPUT" => JobStatus::Input,
+            "OUTPUT" => JobStatus::Output,
+            _ => JobStatus::Unknown(value.into()),
+        }
+    }
+}
+
+impl From<&str> for JobStatus {
+    fn from(value: &str) -> Self {
+        JobStatus::from(value.to_string())
+    }
+}
+
+impl std::str::FromStr for JobStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(s.into())
+    }
+}
+
+impl std::fmt::Display for JobStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            JobStatus::Active => "ACTIVE",
+            JobStatus::Input => "INPUT",
+            JobStatus::Output => "OUTPUT",
+            JobStatus::Unknown(status) => status,
+        };
+
+        write!(f, "{}", s)
+    }
+}
+
+impl<'de> Deserialize<'de> for JobStatus {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+
+        Ok(s.parse().unwrap())
+    }
+}
+
+impl Serialize for JobStatus {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
 }
 
 #[derive(Clone, Debug, Deserialize, Eq, Getters, Hash, Ord, PartialEq, PartialOrd, Serialize)]
@@ -495,10 +772,72 @@ pub struct JobStepData {
 
 #[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
 #[serde(rename_all = "UPPERCASE")]
+#[non_exhaustive]
 pub enum JobType {
     Job,
     Stc,
     Tsu,
+    /// Any other job type reported by z/OSMF.
+    #[serde(other)]
+    Unknown,
+}
+
+/// The completion of a job, as reported in its `retcode`.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[non_exhaustive]
+pub enum ReturnCode {
+    /// `CC 0000`
+    Success,
+    /// `CC nnnn`, with a condition code from 1 to 4.
+    Warning(i32),
+    /// `CC nnnn`, with a condition code above 4.
+    Error(i32),
+    /// `ABEND Sxxx` or `ABEND Unnnn`.
+    Abend {
+        system: Option<String>,
+        user: Option<String>,
+    },
+    /// `JCL ERROR`
+    JclError,
+    /// `CANCELED`
+    Canceled,
+    /// Any other `retcode`, like `SEC ERROR` or `CONV ABEND`.
+    Unknown(String),
+}
+
+impl From<&str> for ReturnCode {
+    fn from(value: &str) -> Self {
+        let value = value.trim();
+
+        match value.split_once(' ') {
+            Some(("CC", code)) => match code.trim().parse() {
+                Ok(0) => ReturnCode::Success,
+                Ok(code @ 1..=4) => ReturnCode::Warning(code),
+                Ok(code) => ReturnCode::Error(code),
+                Err(_) => ReturnCode::Unknown(value.to_string()),
+            },
+            Some(("ABEND", code)) => {
+                let code = code.trim();
+
+                match code.split_at_checked(1) {
+                    Some(("S", system)) => ReturnCode::Abend {
+                        system: Some(system.to_string()),
+                        user: None,
+                    },
+                    Some(("U", user)) => ReturnCode::Abend {
+                        system: None,
+                        user: Some(user.to_string()),
+                    },
+                    _ => ReturnCode::Unknown(value.to_string()),
+                }
+            }
+            _ => match value {
+                "JCL ERROR" => ReturnCode::JclError,
+                "CANCELED" => ReturnCode::Canceled,
+                _ => ReturnCode::Unknown(value.to_string()),
+            },
+        }
+    }
 }
 
 fn get_subsystem(value: &Option<Arc<str>>) -> String {
@@ -515,8 +854,89 @@ mod tests {
     #[test]
     fn display_job_identifier() {
         assert_eq!(
-            format!("{}", JobIdentifier::Correlator("ABCD1234".to_string())),
+            format!("{}", JobIdentifier::Correlator("ABCD1234".into())),
             "ABCD1234"
         );
     }
+
+    #[test]
+    fn job_identifier_constructors() {
+        assert_eq!(
+            JobIdentifier::name_id("TESTJOBJ", "JOB00023"),
+            JobIdentifier::NameId("TESTJOBJ".into(), "JOB00023".into())
+        );
+        assert_eq!(
+            JobIdentifier::correlator(String::from("ABCD1234")),
+            JobIdentifier::Correlator("ABCD1234".into())
+        );
+
+        assert_eq!(
+            JobIdentifier::try_from("TESTJOBJ/JOB00023").unwrap(),
+            JobIdentifier::name_id("TESTJOBJ", "JOB00023")
+        );
+        assert!(JobIdentifier::try_from("A/B/C").is_err());
+    }
+
+    #[test]
+    fn job_identifier_shares_names() {
+        let identifier = JobIdentifier::name_id("TESTJOBJ", "JOB00023");
+
+        match (&identifier, JobIdentifier::from(&identifier)) {
+            (JobIdentifier::NameId(name, id), JobIdentifier::NameId(name_2, id_2)) => {
+                assert!(Arc::ptr_eq(name, &name_2));
+                assert!(Arc::ptr_eq(id, &id_2));
+            }
+            other => panic!("unexpected identifiers: {:?}", other),
+        }
+        assert_eq!(identifier.to_string(), "TESTJOBJ/JOB00023");
+    }
+
+    #[test]
+    fn parse_return_code() {
+        assert_eq!(ReturnCode::from("CC 0000"), ReturnCode::Success);
+        assert_eq!(ReturnCode::from("CC 0004"), ReturnCode::Warning(4));
+        assert_eq!(ReturnCode::from("CC 0008"), ReturnCode::Error(8));
+        assert_eq!(ReturnCode::from("CC 4095"), ReturnCode::Error(4095));
+        assert_eq!(
+            ReturnCode::from("ABEND S806"),
+            ReturnCode::Abend {
+                system: Some("806".to_string()),
+                user: None
+            }
+        );
+        assert_eq!(
+            ReturnCode::from("ABEND U0100"),
+            ReturnCode::Abend {
+                system: None,
+                user: Some("0100".to_string())
+            }
+        );
+        assert_eq!(ReturnCode::from("JCL ERROR"), ReturnCode::JclError);
+        assert_eq!(ReturnCode::from("CANCELED"), ReturnCode::Canceled);
+        assert_eq!(
+            ReturnCode::from("SEC ERROR"),
+            ReturnCode::Unknown("SEC ERROR".to_string())
+        );
+        assert_eq!(
+            ReturnCode::from("CONV ABEND"),
+            ReturnCode::Unknown("CONV ABEND".to_string())
+        );
+        assert_eq!(
+            ReturnCode::from("CC ABCD"),
+            ReturnCode::Unknown("CC ABCD".to_string())
+        );
+    }
+
+    #[test]
+    fn deserialize_unknown_values() {
+        let status: JobStatus = serde_json::from_str(r#""OUTPUT""#).unwrap();
+        assert_eq!(status, JobStatus::Output);
+
+        let status: JobStatus = serde_json::from_str(r#""HELD""#).unwrap();
+        assert_eq!(status, JobStatus::Unknown("HELD".into()));
+        assert_eq!(serde_json::to_string(&status).unwrap(), r#""HELD""#);
+
+        let job_type: JobType = serde_json::from_str(r#""APPC""#).unwrap();
+        assert_eq!(job_type, JobType::Unknown);
+    }
 }
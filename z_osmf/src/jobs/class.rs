@@ -101,7 +101,7 @@ mod tests {
             .build()
             .unwrap();
 
-        let identifier = JobIdentifier::NameId("TESTJOBW".to_string(), "JOB00023".to_string());
+        let identifier = JobIdentifier::NameId("TESTJOBW".into(), "JOB00023".into());
         let job_feedback = zosmf
             .jobs()
             .change_class(identifier, 'A')
@@ -135,7 +135,7 @@ mod tests {
             .build()
             .unwrap();
 
-        let identifier = JobIdentifier::NameId("TESTJOBW".to_string(), "JOB00023".to_string());
+        let identifier = JobIdentifier::NameId("TESTJOBW".into(), "JOB00023".into());
         let job_feedback = zosmf
             .jobs()
             .change_class(identifier, 'A')
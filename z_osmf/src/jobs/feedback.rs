@@ -9,6 +9,10 @@ use crate::{ClientCore, Result};
 
 use super::{get_subsystem, JobIdentifier};
 
+/// The feedback z/OSMF returns once a request on a job has been processed.
+///
+/// z/OSMF responds with `200 OK` even when the request failed, so check
+/// [`succeeded`](Self::succeeded), or the status, internal code and message.
 #[derive(Clone, Debug, Deserialize, Eq, Getters, Hash, Ord, PartialEq, PartialOrd, Serialize)]
 #[serde(rename_all = "kebab-case")]
 pub struct JobFeedback {
@@ -24,16 +28,53 @@ pub struct JobFeedback {
     system_name: Arc<str>,
     job_correlator: Arc<str>,
     status: Arc<str>,
+    #[serde(default)]
     internal_code: Option<Arc<str>>,
+    #[serde(default)]
     message: Option<Arc<str>>,
 }
 
+impl JobFeedback {
+    /// Whether z/OSMF reported the request as completed, with a status of `0`
+    /// and no internal code.
+    pub fn succeeded(&self) -> bool {
+        self.status.trim() == "0" && self.internal_code.is_none()
+    }
+}
+
 impl TryFromResponse for JobFeedback {
     async fn try_from_response(value: reqwest::Response) -> Result<Self> {
         Ok(value.json().await?)
     }
 }
 
+/// The feedback z/OSMF returns when a request is accepted for asynchronous processing.
+///
+/// The request has not necessarily completed; check the job status to follow it.
+#[derive(Clone, Debug, Deserialize, Eq, Getters, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct JobFeedbackAsync {
+    #[serde(rename = "jobid")]
+    id: Arc<str>,
+    #[serde(rename = "jobname")]
+    name: Arc<str>,
+    #[serde(default, rename = "original-jobid")]
+    original_id: Option<Arc<str>>,
+    owner: Arc<str>,
+    member: Arc<str>,
+    #[serde(rename = "sysname")]
+    system_name: Arc<str>,
+    #[serde(default)]
+    job_correlator: Option<Arc<str>>,
+    status: Arc<str>,
+}
+
+impl TryFromResponse for JobFeedbackAsync {
+    async fn try_from_response(value: reqwest::Response) -> Result<Self> {
+        Ok(value.json().await?)
+    }
+}
+
 #[derive(Clone, Debug, Endpoint)]
 #[endpoint(method = put, path = "/zosmf/restjobs/jobs{subsystem}/{identifier}")]
 pub struct JobFeedbackBuilder<T>
@@ -58,7 +99,10 @@ impl<T> JobFeedbackBuilder<T>
 where
     T: TryFromResponse,
 {
-    pub fn asynchronous(self) -> JobFeedbackBuilder<()> {
+    /// Have z/OSMF return as soon as the request is accepted, rather than once it completes.
+    ///
+    /// This sends the request with version `1.0` of the protocol, instead of the synchronous `2.0`.
+    pub fn asynchronous(self) -> JobFeedbackBuilder<JobFeedbackAsync> {
         JobFeedbackBuilder {
             core: self.core,
             subsystem: self.subsystem,
@@ -126,7 +170,7 @@ mod tests {
             .build()
             .unwrap();
 
-        let identifier = JobIdentifier::NameId("TESTJOB2".to_string(), "JOB00084".to_string());
+        let identifier = JobIdentifier::NameId("TESTJOB2".into(), "JOB00084".into());
 
         let job_feedback = zosmf.jobs().cancel(identifier).get_request().unwrap();
 
@@ -138,6 +182,112 @@ mod tests {
         assert_eq!(manual_request.json(), job_feedback.json())
     }
 
+    #[test]
+    fn cancel_asynchronous() {
+        let zosmf = get_zosmf();
+
+        let raw_json = r#"
+        {
+            "request": "cancel",
+            "version": "1.0"
+        }
+        "#;
+        let json: serde_json::Value = serde_json::from_str(raw_json).unwrap();
+
+        let manual_request = zosmf
+            .core
+            .client
+            .put("https://test.com/zosmf/restjobs/jobs/TESTJOB2/JOB00084")
+            .json(&json)
+            .build()
+            .unwrap();
+
+        let identifier = JobIdentifier::NameId("TESTJOB2".into(), "JOB00084".into());
+
+        let job_feedback = zosmf
+            .jobs()
+            .cancel(identifier)
+            .asynchronous()
+            .get_request()
+            .unwrap();
+
+        assert_eq!(
+            format!("{:?}", manual_request),
+            format!("{:?}", job_feedback)
+        );
+
+        assert_eq!(manual_request.json(), job_feedback.json())
+    }
+
+    #[test]
+    fn deserialize_feedback() {
+        let feedback: JobFeedback = serde_json::from_str(
+            r#"
+            {
+                "jobid": "JOB00084",
+                "jobname": "TESTJOB2",
+                "original-jobid": "JOB00084",
+                "owner": "IBMUSER",
+                "member": "JES2",
+                "sysname": "SY1",
+                "job-correlator": "J0000084SY1.....CC20F378.......:",
+                "status": "0"
+            }
+            "#,
+        )
+        .unwrap();
+
+        assert!(feedback.succeeded());
+        assert_eq!(feedback.message(), None);
+
+        let feedback: JobFeedback = serde_json::from_str(
+            r#"
+            {
+                "jobid": "JOB00084",
+                "jobname": "TESTJOB2",
+                "owner": "IBMUSER",
+                "member": "JES2",
+                "sysname": "SY1",
+                "job-correlator": "J0000084SY1.....CC20F378.......:",
+                "status": "156",
+                "internal-code": "4",
+                "message": "Job is not in the expected state for the request"
+            }
+            "#,
+        )
+        .unwrap();
+
+        assert!(!feedback.succeeded());
+        assert_eq!(feedback.status(), "156");
+        assert_eq!(feedback.internal_code(), Some("4"));
+        assert_eq!(
+            feedback.message(),
+            Some("Job is not in the expected state for the request")
+        );
+    }
+
+    #[test]
+    fn deserialize_feedback_async() {
+        let feedback: JobFeedbackAsync = serde_json::from_str(
+            r#"
+            {
+                "jobid": "JOB00084",
+                "jobname": "TESTJOB2",
+                "original-jobid": "JOB00084",
+                "owner": "IBMUSER",
+                "member": "JES2",
+                "sysname": "SY1",
+                "job-correlator": "J0000084SY1.....CC20F378.......:",
+                "status": "0"
+            }
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(feedback.id(), "JOB00084");
+        assert_eq!(feedback.status(), "0");
+    }
+
     #[test]
     fn hold_example_1() {
         let zosmf = get_zosmf();
@@ -157,7 +307,7 @@ mod tests {
             .build()
             .unwrap();
 
-        let identifier = JobIdentifier::NameId("TESTJOBW".to_string(), "JOB00023".to_string());
+        let identifier = JobIdentifier::NameId("TESTJOBW".into(), "JOB00023".into());
         let job_feedback = zosmf.jobs().hold(identifier).get_request().unwrap();
 
         assert_eq!(
@@ -187,7 +337,7 @@ mod tests {
             .build()
             .unwrap();
 
-        let identifier = JobIdentifier::NameId("TESTJOBW".to_string(), "JOB00023".to_string());
+        let identifier = JobIdentifier::NameId("TESTJOBW".into(), "JOB00023".into());
         let job_feedback = zosmf.jobs().release(identifier).get_request().unwrap();
 
         assert_eq!(
@@ -45,6 +45,15 @@ pub struct JobFileList {
     items: Arc<[JobFile]>,
 }
 
+impl JobFileList {
+    /// Find the first spool file allocated to the given DD name, ignoring case.
+    pub fn find_dd_name(&self, dd_name: &str) -> Option<&JobFile> {
+        self.items
+            .iter()
+            .find(|file| file.dd_name.eq_ignore_ascii_case(dd_name))
+    }
+}
+
 impl TryFromResponse for JobFileList {
     async fn try_from_response(value: reqwest::Response) -> Result<Self> {
         Ok(JobFileList {
@@ -93,9 +102,72 @@ mod tests {
             .build()
             .unwrap();
 
-        let identifier = JobIdentifier::NameId("TESTJOB1".to_string(), "JOB00023".to_string());
+        let identifier = JobIdentifier::NameId("TESTJOB1".into(), "JOB00023".into());
         let job_files = zosmf.jobs().list_files(identifier).get_request().unwrap();
 
         assert_eq!(format!("{:?}", manual_request), format!("{:?}", job_files))
     }
+
+    #[test]
+    fn find_dd_name() {
+        let list = JobFileList {
+            items: serde_json::from_str(
+                r#"
+                [
+                    {
+                        "recfm": "UA", "records-url": "https://test.com/zosmf/restjobs/jobs/TESTJOB1/JOB00023/files/2/records",
+                        "stepname": "JES2", "subsystem": "JES2", "job-correlator": null, "byte-count": 1200,
+                        "lrecl": 133, "jobid": "JOB00023", "ddname": "JESMSGLG", "id": 2, "record-count": 18,
+                        "class": "H", "jobname": "TESTJOB1", "procstep": null
+                    },
+                    {
+                        "recfm": "FBA", "records-url": "https://test.com/zosmf/restjobs/jobs/TESTJOB1/JOB00023/files/102/records",
+                        "stepname": "STEP1", "subsystem": "JES2", "job-correlator": null, "byte-count": 500,
+                        "lrecl": 121, "jobid": "JOB00023", "ddname": "SYSPRINT", "id": 102, "record-count": 8,
+                        "class": "H", "jobname": "TESTJOB1", "procstep": null
+                    }
+                ]
+                "#,
+            )
+            .unwrap(),
+        };
+
+        assert_eq!(
+            list.find_dd_name("sysprint").map(|file| file.id()),
+            Some(102)
+        );
+        assert_eq!(list.find_dd_name("JESMSGLG").map(|file| file.id()), Some(2));
+        assert!(list.find_dd_name("SYSOUT").is_none());
+    }
+
+    #[test]
+    fn deserialize_job_file() {
+        let job_file: JobFile = serde_json::from_str(
+            r#"
+            {
+                "recfm": "FBA",
+                "records-url": "https://test.com/zosmf/restjobs/jobs/TESTJOB1/JOB00023/files/102/records",
+                "stepname": "STEP1",
+                "subsystem": "JES2",
+                "job-correlator": null,
+                "byte-count": 500,
+                "lrecl": 121,
+                "jobid": "JOB00023",
+                "ddname": "SYSPRINT",
+                "id": 102,
+                "record-count": 8,
+                "class": "H",
+                "jobname": "TESTJOB1",
+                "procstep": "COMPILE"
+            }
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(job_file.dd_name(), "SYSPRINT");
+        assert_eq!(job_file.step_name(), Some("STEP1"));
+        assert_eq!(job_file.proc_step(), Some("COMPILE"));
+        assert_eq!(job_file.record_count(), 8);
+        assert_eq!(job_file.byte_count(), 500);
+    }
 }
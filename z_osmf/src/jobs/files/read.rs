@@ -9,7 +9,7 @@ use z_osmf_macros::Endpoint;
 
 use crate::convert::TryFromResponse;
 use crate::jobs::{get_subsystem, JobIdentifier};
-use crate::{ClientCore, Result};
+use crate::{ClientCore, ReadPayload, Result};
 
 #[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
 pub enum JobFileId {
@@ -37,12 +37,39 @@ pub struct JobFileRead<T> {
     data: T,
 }
 
+impl<T> ReadPayload for JobFileRead<T>
+where
+    T: ReadPayload,
+{
+    fn as_text(&self) -> Option<&str> {
+        self.data.as_text()
+    }
+
+    fn as_bytes(&self) -> &[u8] {
+        self.data.as_bytes()
+    }
+}
+
 impl JobFileRead<Arc<str>> {
     pub fn data(&self) -> &str {
         &self.data
     }
 }
 
+impl std::ops::Deref for JobFileRead<Arc<str>> {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        &self.data
+    }
+}
+
+impl AsRef<str> for JobFileRead<Arc<str>> {
+    fn as_ref(&self) -> &str {
+        &self.data
+    }
+}
+
 impl TryFromResponse for JobFileRead<Arc<str>> {
     async fn try_from_response(value: reqwest::Response) -> Result<Self> {
         Ok(JobFileRead {
@@ -57,6 +84,20 @@ impl JobFileRead<Bytes> {
     }
 }
 
+impl std::ops::Deref for JobFileRead<Bytes> {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        &self.data
+    }
+}
+
+impl AsRef<[u8]> for JobFileRead<Bytes> {
+    fn as_ref(&self) -> &[u8] {
+        &self.data
+    }
+}
+
 impl TryFromResponse for JobFileRead<Bytes> {
     async fn try_from_response(value: reqwest::Response) -> Result<Self> {
         Ok(JobFileRead {
@@ -189,6 +230,23 @@ mod tests {
 
     use super::*;
 
+    #[tokio::test]
+    async fn deref_data() {
+        let response = || reqwest::Response::from(http::Response::new("//TESTJOB JOB"));
+
+        let text = JobFileRead::<Arc<str>>::try_from_response(response())
+            .await
+            .unwrap();
+        assert!(text.starts_with("//TESTJOB"));
+        assert_eq!(AsRef::<str>::as_ref(&text), text.data());
+
+        let binary = JobFileRead::<Bytes>::try_from_response(response())
+            .await
+            .unwrap();
+        assert_eq!(binary.len(), 13);
+        assert_eq!(AsRef::<[u8]>::as_ref(&binary), &binary.data()[..]);
+    }
+
     #[test]
     fn read_1() {
         let zosmf = get_zosmf();
@@ -200,7 +258,7 @@ mod tests {
             .build()
             .unwrap();
 
-        let identifier = JobIdentifier::NameId("TESTJOBJ".to_string(), "JOB00023".to_string());
+        let identifier = JobIdentifier::NameId("TESTJOBJ".into(), "JOB00023".into());
         let file_id = JobFileId::Id(1);
         let job_file = zosmf
             .jobs()
@@ -223,7 +281,7 @@ mod tests {
             .build()
             .unwrap();
 
-        let identifier = JobIdentifier::NameId("TESTJOBJ".to_string(), "JOB00023".to_string());
+        let identifier = JobIdentifier::NameId("TESTJOBJ".into(), "JOB00023".into());
         let file_id = JobFileId::Id(8);
         let job_file = zosmf
             .jobs()
@@ -246,7 +304,7 @@ mod tests {
             .build()
             .unwrap();
 
-        let identifier = JobIdentifier::NameId("TESTJOBJ".to_string(), "JOB00060".to_string());
+        let identifier = JobIdentifier::NameId("TESTJOBJ".into(), "JOB00060".into());
         let file_id = JobFileId::Jcl;
 
         let job_file = zosmf
@@ -108,8 +108,11 @@ where
 
 #[cfg(test)]
 mod tests {
+    use crate::jobs::{JobAttributes, JobStatus};
     use crate::tests::*;
 
+    use super::*;
+
     #[test]
     fn example_1() {
         let zosmf = get_zosmf();
@@ -158,4 +161,79 @@ mod tests {
 
         assert_eq!(format!("{:?}", manual_request), format!("{:?}", job_list))
     }
+
+    #[test]
+    fn filters() {
+        let zosmf = get_zosmf();
+
+        let manual_request = zosmf
+            .core
+            .client
+            .get("https://test.com/zosmf/restjobs/jobs")
+            .query(&[
+                ("owner", "*"),
+                ("jobid", "JOB00023"),
+                ("max-jobs", "10"),
+                ("user-correlator", "NIGHTLY"),
+                ("status", "active"),
+            ])
+            .build()
+            .unwrap();
+
+        let job_list = zosmf
+            .jobs()
+            .list()
+            .owner("*")
+            .job_id("JOB00023")
+            .max_jobs(10)
+            .user_correlator("NIGHTLY")
+            .active_only(true)
+            .get_request()
+            .unwrap();
+
+        assert_eq!(format!("{:?}", manual_request), format!("{:?}", job_list))
+    }
+
+    #[test]
+    fn unknown_status() {
+        let job = r#"
+        {
+            "jobid": "JOB00023",
+            "jobname": "TESTJOB1",
+            "subsystem": "JES2",
+            "owner": "IBMUSER",
+            "status": "STATUS",
+            "type": "JOB",
+            "class": "A",
+            "retcode": null,
+            "url": "https://test.com/zosmf/restjobs/jobs/TESTJOB1/JOB00023",
+            "files-url": "https://test.com/zosmf/restjobs/jobs/TESTJOB1/JOB00023/files",
+            "job-correlator": null,
+            "phase": 20,
+            "phase-name": "Job is on the hard copy queue",
+            "exec-system": "SYSA"
+        }
+        "#;
+        let items = format!(
+            "[{}, {}, {}]",
+            job.replace("STATUS", "OUTPUT"),
+            job.replace("STATUS", "HELD"),
+            job.replace("STATUS", "ACTIVE")
+        );
+
+        let jobs: Arc<[JobAttributes]> = serde_json::from_str(&items).unwrap();
+        let statuses: Vec<_> = jobs.iter().map(|job| job.status()).collect();
+        assert_eq!(
+            statuses,
+            [
+                Some(&JobStatus::Output),
+                Some(&JobStatus::Unknown("HELD".into())),
+                Some(&JobStatus::Active)
+            ]
+        );
+
+        let jobs: Arc<[JobAttributesExec]> = serde_json::from_str(&items).unwrap();
+        assert_eq!(jobs[1].status(), Some(&JobStatus::Unknown("HELD".into())));
+        assert_eq!(jobs[1].exec_system(), Some("SYSA"));
+    }
 }
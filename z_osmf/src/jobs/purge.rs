@@ -83,7 +83,7 @@ mod tests {
             .build()
             .unwrap();
 
-        let identifier = JobIdentifier::NameId("TESTJOBW".to_string(), "JOB00085".to_string());
+        let identifier = JobIdentifier::NameId("TESTJOBW".into(), "JOB00085".into());
         let job_feedback = zosmf
             .jobs()
             .cancel_and_purge(identifier)
@@ -95,4 +95,55 @@ mod tests {
             format!("{:?}", job_feedback)
         )
     }
+
+    #[test]
+    fn purge_by_correlator() {
+        let zosmf = get_zosmf();
+
+        let manual_request = zosmf
+            .core
+            .client
+            .delete("https://test.com/zosmf/restjobs/jobs/J0000085SY1.....CC20F380.......:")
+            .header("X-IBM-Job-Modify-Version", "2.0")
+            .build()
+            .unwrap();
+
+        let identifier = JobIdentifier::Correlator("J0000085SY1.....CC20F380.......:".into());
+        let job_feedback = zosmf
+            .jobs()
+            .cancel_and_purge(identifier)
+            .get_request()
+            .unwrap();
+
+        assert_eq!(
+            format!("{:?}", manual_request),
+            format!("{:?}", job_feedback)
+        )
+    }
+
+    const ACTIVE_JOB: &str = r#"{"jobid":"JOB00085","jobname":"TESTJOBW","subsystem":"JES2",
+        "owner":"IBMUSER","status":"ACTIVE","type":"JOB","class":"A","retcode":null,
+        "url":"https://test.com/zosmf/restjobs/jobs/TESTJOBW/JOB00085",
+        "files-url":"https://test.com/zosmf/restjobs/jobs/TESTJOBW/JOB00085/files",
+        "job-correlator":"J0000085SY1.....CC20F380.......:","phase":14,
+        "phase-name":"Job is actively executing","reason-not-running":null}"#;
+
+    #[tokio::test]
+    async fn purge_refuses_running_job() {
+        let (url, mut events) = serve(&[(200, ACTIVE_JOB)], None);
+        let zosmf = crate::ZOsmf::new(reqwest::Client::new(), url);
+
+        let result = zosmf
+            .jobs()
+            .purge(JobIdentifier::name_id("TESTJOBW", "JOB00085"))
+            .await;
+
+        assert!(matches!(result, Err(crate::Error::InvalidValue(_))));
+        assert_eq!(
+            events.recv().await.unwrap(),
+            "GET /zosmf/restjobs/jobs/TESTJOBW/JOB00085 HTTP/1.1"
+        );
+        assert_eq!(events.recv().await.unwrap(), "body");
+        assert!(events.try_recv().is_err());
+    }
 }
@@ -138,7 +138,7 @@ mod tests {
             .build()
             .unwrap();
 
-        let identifier = JobIdentifier::NameId("BLSJPRMI".to_string(), "STC00052".to_string());
+        let identifier = JobIdentifier::NameId("BLSJPRMI".into(), "STC00052".into());
         let job_status = zosmf
             .jobs()
             .status(identifier)
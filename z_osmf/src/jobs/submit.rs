@@ -1,15 +1,20 @@
 use std::collections::HashMap;
 use std::marker::PhantomData;
 use std::sync::Arc;
+use std::time::Duration;
 
 use bytes::Bytes;
 use serde::{Deserialize, Serialize};
 use z_osmf_macros::Endpoint;
 
 use crate::convert::TryFromResponse;
-use crate::ClientCore;
+use crate::{ClientCore, Error, Result};
 
-use super::get_subsystem;
+use super::files::read::{JobFileRead, JobFileReadBuilder};
+use super::files::{JobFileList, JobFileListBuilder};
+use super::status::JobStatusBuilder;
+use super::wait::JobWaitBuilder;
+use super::{get_subsystem, JobAttributes, JobIdentifier};
 
 #[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
 pub enum JclData {
@@ -93,6 +98,203 @@ where
 
         self
     }
+
+    /// Set a JCL symbol, sent to the internal reader as an `X-IBM-JCL-Symbol-{name}` header.
+    ///
+    /// Symbols set this way are added to any already set with [`symbols`](Self::symbols).
+    pub fn symbol<N, V>(mut self, name: N, value: V) -> Self
+    where
+        N: std::fmt::Display,
+        V: std::fmt::Display,
+    {
+        self.symbols
+            .get_or_insert_with(HashMap::new)
+            .insert(name.to_string().into(), value.to_string().into());
+
+        self
+    }
+}
+
+impl JobSubmitBuilder<JobAttributes> {
+    /// Build a request that submits the job, and when the response has no job ID yet,
+    /// polls the job's status by its correlator until JES assigns one.
+    ///
+    /// On a busy system the internal reader can respond before the job ID is known.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// # use std::time::Duration;
+    /// # use z_osmf::jobs::submit::JobSource;
+    /// let attributes = zosmf
+    ///     .jobs()
+    ///     .submit(JobSource::Dataset("SYS1.PROCLIB(IEFBR14)".to_string()))
+    ///     .await_jobid()
+    ///     .poll_interval(Duration::from_millis(500))
+    ///     .timeout(Duration::from_secs(60))
+    ///     .build()
+    ///     .await?;
+    ///
+    /// println!("{}", attributes.id());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn await_jobid(self) -> JobSubmitAwaitIdBuilder {
+        JobSubmitAwaitIdBuilder {
+            submit: self,
+            poll_interval: Duration::from_secs(1),
+            timeout: Duration::from_secs(30),
+        }
+    }
+
+    /// Submit the job and return a handle for following it to completion.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// # use z_osmf::jobs::submit::JobSource;
+    /// let job = zosmf
+    ///     .jobs()
+    ///     .submit(JobSource::Dataset("SYS1.PROCLIB(IEFBR14)".to_string()))
+    ///     .submit_and_track()
+    ///     .await?;
+    ///
+    /// let attributes = job.wait().build().await?;
+    /// println!("{:?}", attributes.return_code());
+    /// println!("{}", job.spool_text().await?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn submit_and_track(self) -> Result<SubmittedJob> {
+        let core = self.core.clone();
+        let subsystem = self.subsystem.clone();
+        let attributes = self.build().await?;
+
+        Ok(SubmittedJob {
+            core,
+            subsystem,
+            attributes,
+        })
+    }
+}
+
+/// Submit a job and wait for JES to assign it a job ID,
+/// as built by [`JobSubmitBuilder::await_jobid`].
+///
+/// By default, the status is polled every second,
+/// giving up with [`Error::Timeout`] after 30 seconds.
+#[derive(Clone, Debug)]
+pub struct JobSubmitAwaitIdBuilder {
+    submit: JobSubmitBuilder<JobAttributes>,
+    poll_interval: Duration,
+    timeout: Duration,
+}
+
+impl JobSubmitAwaitIdBuilder {
+    /// Set the time between status requests.
+    pub fn poll_interval(mut self, value: Duration) -> Self {
+        self.poll_interval = value;
+
+        self
+    }
+
+    /// Give up with [`Error::Timeout`] if the job has no job ID in time.
+    pub fn timeout(mut self, value: Duration) -> Self {
+        self.timeout = value;
+
+        self
+    }
+
+    pub async fn build(self) -> Result<JobAttributes> {
+        let core = self.submit.core.clone();
+        let subsystem = self.submit.subsystem.clone();
+        let attributes = self.submit.build().await?;
+
+        if has_job_id(&attributes) {
+            return Ok(attributes);
+        }
+
+        let correlator = attributes.job_correlator().ok_or_else(|| {
+            Error::InvalidValue("submitted job has neither a job ID nor a correlator".into())
+        })?;
+        let mut status =
+            JobStatusBuilder::<JobAttributes>::new(core, JobIdentifier::correlator(correlator));
+        if let Some(subsystem) = &subsystem {
+            status = status.subsystem(subsystem);
+        }
+
+        let deadline = tokio::time::Instant::now() + self.timeout;
+        loop {
+            let remaining = deadline.saturating_duration_since(tokio::time::Instant::now());
+            if remaining.is_zero() {
+                return Err(Error::Timeout(self.timeout));
+            }
+            tokio::time::sleep(remaining.min(self.poll_interval)).await;
+
+            let attributes = status.clone().build().await?;
+            if has_job_id(&attributes) {
+                return Ok(attributes);
+            }
+        }
+    }
+}
+
+/// A submitted job, as returned by [`JobSubmitBuilder::submit_and_track`].
+#[derive(Clone, Debug)]
+pub struct SubmittedJob {
+    core: Arc<ClientCore>,
+    subsystem: Option<Arc<str>>,
+    attributes: JobAttributes,
+}
+
+impl SubmittedJob {
+    /// The attributes of the job at the time it was submitted.
+    pub fn attributes(&self) -> &JobAttributes {
+        &self.attributes
+    }
+
+    /// Concatenate the text of every spool file of the job, in spool file order.
+    pub async fn spool_text(&self) -> Result<String> {
+        let mut list =
+            JobFileListBuilder::<JobFileList>::new(self.core.clone(), self.attributes.identifier());
+        if let Some(subsystem) = &self.subsystem {
+            list = list.subsystem(subsystem);
+        }
+
+        let mut files = list.build().await?.items().to_vec();
+        files.sort_by_key(|file| file.id());
+
+        let mut text = String::new();
+        for file in files.iter() {
+            let mut read = JobFileReadBuilder::<JobFileRead<Arc<str>>>::new(
+                self.core.clone(),
+                self.attributes.identifier(),
+                file.id(),
+            );
+            if let Some(subsystem) = &self.subsystem {
+                read = read.subsystem(subsystem);
+            }
+
+            push_spool_file(&mut text, read.build().await?.data());
+        }
+
+        Ok(text)
+    }
+
+    /// Build a request for the current status of the job.
+    pub fn status(&self) -> JobStatusBuilder<JobAttributes> {
+        let status = JobStatusBuilder::new(self.core.clone(), self.attributes.identifier());
+
+        match &self.subsystem {
+            Some(subsystem) => status.subsystem(subsystem),
+            None => status,
+        }
+    }
+
+    /// Build a poller that waits for the job to complete.
+    pub fn wait(&self) -> JobWaitBuilder {
+        JobWaitBuilder::new(self.status())
+    }
 }
 
 #[derive(Serialize)]
@@ -100,6 +302,10 @@ struct Source<'a> {
     file: &'a str,
 }
 
+fn has_job_id(attributes: &JobAttributes) -> bool {
+    !attributes.id().trim().is_empty()
+}
+
 fn build_jcl_source<T>(
     request_builder: reqwest::RequestBuilder,
     builder: &JobSubmitBuilder<T>,
@@ -166,6 +372,15 @@ where
     request_builder
 }
 
+/// Append a spool file to the output, keeping each file on its own lines.
+fn push_spool_file(text: &mut String, data: &str) {
+    if !text.is_empty() && !text.ends_with('\n') {
+        text.push('\n');
+    }
+
+    text.push_str(data);
+}
+
 fn build_subsystem<T>(builder: &JobSubmitBuilder<T>) -> String
 where
     T: TryFromResponse,
@@ -181,7 +396,10 @@ where
     T: TryFromResponse,
 {
     if let Some(symbols) = &builder.symbols {
-        for (name, value) in symbols.iter() {
+        let mut symbols: Vec<_> = symbols.iter().collect();
+        symbols.sort_unstable();
+
+        for (name, value) in symbols {
             request_builder =
                 request_builder.header(format!("X-IBM-JCL-Symbol-{}", name), value.as_ref());
         }
@@ -196,6 +414,91 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn has_job_id() {
+        let job = r#"
+        {
+            "jobid": "JOBID",
+            "jobname": "TESTJOB1",
+            "subsystem": "JES2",
+            "owner": "IBMUSER",
+            "status": "INPUT",
+            "type": "JOB",
+            "class": "A",
+            "retcode": null,
+            "url": "https://test.com/zosmf/restjobs/jobs/TESTJOB1/JOBID",
+            "files-url": "https://test.com/zosmf/restjobs/jobs/TESTJOB1/JOBID/files",
+            "job-correlator": "J0000023SY1.....CC20F378.......:",
+            "phase": 14,
+            "phase-name": "Job is actively converting"
+        }
+        "#;
+
+        let attributes: JobAttributes =
+            serde_json::from_str(&job.replace("JOBID", "JOB00023")).unwrap();
+        assert!(super::has_job_id(&attributes));
+
+        let attributes: JobAttributes = serde_json::from_str(&job.replace("JOBID", "")).unwrap();
+        assert!(!super::has_job_id(&attributes));
+    }
+
+    const SUBMITTED: &str = r#"{"jobid":"","jobname":"TESTJOB1","subsystem":"JES2",
+        "owner":"IBMUSER","status":"INPUT","type":"JOB","class":"A","retcode":null,
+        "url":"https://test.com/zosmf/restjobs/jobs/TESTJOB1","files-url":"https://test.com/files",
+        "job-correlator":"J0000023SY1.....CC20F378.......:","phase":14,
+        "phase-name":"Job is actively converting"}"#;
+    const ASSIGNED: &str = r#"{"jobid":"JOB00023","jobname":"TESTJOB1","subsystem":"JES2",
+        "owner":"IBMUSER","status":"INPUT","type":"JOB","class":"A","retcode":null,
+        "url":"https://test.com/zosmf/restjobs/jobs/TESTJOB1","files-url":"https://test.com/files",
+        "job-correlator":"J0000023SY1.....CC20F378.......:","phase":14,
+        "phase-name":"Job is actively converting"}"#;
+
+    #[tokio::test]
+    async fn await_jobid() {
+        let (url, _) = serve(&[(201, SUBMITTED), (200, SUBMITTED), (200, ASSIGNED)], None);
+        let zosmf = crate::ZOsmf::new(reqwest::Client::new(), url);
+
+        let attributes = zosmf
+            .jobs()
+            .submit(JobSource::Dataset("SYS1.PROCLIB(IEFBR14)".to_string()))
+            .await_jobid()
+            .poll_interval(Duration::from_millis(10))
+            .build()
+            .await
+            .unwrap();
+
+        assert_eq!(attributes.id(), "JOB00023");
+    }
+
+    #[tokio::test]
+    async fn await_jobid_timeout() {
+        let (url, _) = serve(&[(201, SUBMITTED)], None);
+        let zosmf = crate::ZOsmf::new(reqwest::Client::new(), url);
+
+        let result = zosmf
+            .jobs()
+            .submit(JobSource::Dataset("SYS1.PROCLIB(IEFBR14)".to_string()))
+            .await_jobid()
+            .poll_interval(Duration::from_millis(10))
+            .timeout(Duration::from_millis(50))
+            .build()
+            .await;
+
+        assert!(
+            matches!(result, Err(Error::Timeout(timeout)) if timeout == Duration::from_millis(50))
+        );
+    }
+
+    #[test]
+    fn test_push_spool_file() {
+        let mut text = String::new();
+        push_spool_file(&mut text, "JES2 JOB LOG");
+        push_spool_file(&mut text, "//TESTJOBX JOB\n");
+        push_spool_file(&mut text, "IEF142I");
+
+        assert_eq!(text, "JES2 JOB LOG\n//TESTJOBX JOB\nIEF142I");
+    }
+
     #[test]
     fn example_1() {
         let zosmf = get_zosmf();
@@ -234,6 +537,39 @@ mod tests {
         )
     }
 
+    #[test]
+    fn jcl_with_symbols() {
+        let zosmf = get_zosmf();
+
+        let jcl = r#"//TESTJOBX JOB (),MSGCLASS=H
+        //STEP1 EXEC PGM=IEBGENER
+        //SYSUT1 DD DSN=&HLQ..INPUT,DISP=SHR
+        //SYSUT2 DD DSN=&HLQ..&OUT,DISP=SHR
+        "#;
+
+        let manual_request = zosmf
+            .core
+            .client
+            .put("https://test.com/zosmf/restjobs/jobs")
+            .header("X-IBM-JCL-Symbol-HLQ", "IBMUSER")
+            .header("X-IBM-JCL-Symbol-OUT", "OUTPUT")
+            .header("Content-Type", "text/plain")
+            .header("X-IBM-Intrdr-Mode", "TEXT")
+            .body(jcl.to_string())
+            .build()
+            .unwrap();
+
+        let job_data = zosmf
+            .jobs()
+            .submit(JobSource::Jcl(JclData::Text(jcl.into())))
+            .symbol("OUT", "OUTPUT")
+            .symbol("HLQ", "IBMUSER")
+            .get_request()
+            .unwrap();
+
+        assert_eq!(format!("{:?}", manual_request), format!("{:?}", job_data));
+    }
+
     #[test]
     fn notification_events() {
         let zosmf = get_zosmf();
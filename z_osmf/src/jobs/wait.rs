@@ -0,0 +1,121 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::{Error, Result};
+
+use super::status::JobStatusBuilder;
+use super::{JobAttributes, JobStatus};
+
+/// Poll the status of a job until it reaches a wanted status.
+///
+/// By default, the job is polled every 5 seconds with no timeout
+/// until its output is available.
+#[derive(Clone)]
+pub struct JobWaitBuilder {
+    status: JobStatusBuilder<JobAttributes>,
+    poll_interval: Duration,
+    timeout: Option<Duration>,
+    until: Arc<dyn Fn(&JobStatus) -> bool + Send + Sync>,
+}
+
+impl std::fmt::Debug for JobWaitBuilder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("JobWaitBuilder")
+            .field("status", &self.status)
+            .field("poll_interval", &self.poll_interval)
+            .field("timeout", &self.timeout)
+            .finish_non_exhaustive()
+    }
+}
+
+impl JobWaitBuilder {
+    pub(super) fn new(status: JobStatusBuilder<JobAttributes>) -> Self {
+        JobWaitBuilder {
+            status,
+            poll_interval: Duration::from_secs(5),
+            timeout: None,
+            until: Arc::new(|status| *status == JobStatus::Output),
+        }
+    }
+
+    /// Set the time between status requests.
+    pub fn poll_interval(mut self, value: Duration) -> Self {
+        self.poll_interval = value;
+
+        self
+    }
+
+    /// Give up with [`Error::Timeout`] if the job has not reached the wanted status in time.
+    pub fn timeout(mut self, value: Duration) -> Self {
+        self.timeout = Some(value);
+
+        self
+    }
+
+    /// Stop waiting when the predicate returns true, instead of when the job reaches `OUTPUT`.
+    pub fn until<F>(mut self, predicate: F) -> Self
+    where
+        F: Fn(&JobStatus) -> bool + Send + Sync + 'static,
+    {
+        self.until = Arc::new(predicate);
+
+        self
+    }
+
+    pub async fn build(self) -> Result<JobAttributes> {
+        let deadline = self
+            .timeout
+            .map(|timeout| (tokio::time::Instant::now() + timeout, timeout));
+
+        loop {
+            let attributes = self.status.clone().build().await?;
+
+            if attributes
+                .status()
+                .is_some_and(|status| (self.until)(status))
+            {
+                return Ok(attributes);
+            }
+
+            let delay = match deadline {
+                Some((deadline, timeout)) => {
+                    let remaining = deadline.saturating_duration_since(tokio::time::Instant::now());
+                    if remaining.is_zero() {
+                        return Err(Error::Timeout(timeout));
+                    }
+
+                    remaining.min(self.poll_interval)
+                }
+                None => self.poll_interval,
+            };
+
+            tokio::time::sleep(delay).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::jobs::JobIdentifier;
+    use crate::tests::*;
+
+    use super::*;
+
+    #[test]
+    fn defaults() {
+        let zosmf = get_zosmf();
+
+        let wait = zosmf
+            .jobs()
+            .wait(JobIdentifier::NameId("TESTJOB1".into(), "JOB00023".into()));
+
+        assert_eq!(wait.poll_interval, Duration::from_secs(5));
+        assert_eq!(wait.timeout, None);
+        assert!((wait.until)(&JobStatus::Output));
+        assert!(!(wait.until)(&JobStatus::Active));
+
+        let wait = wait.until(|status| *status != JobStatus::Input);
+        assert!((wait.until)(&JobStatus::Active));
+        assert!(!(wait.until)(&JobStatus::Input));
+    }
+}
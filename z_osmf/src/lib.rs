@@ -61,16 +61,37 @@
 //! # Ok(())
 //! # }
 //! ```
+//!
+//! Time a request and inspect the raw response:
+//! ```
+//! # async fn example(zosmf: z_osmf::ZOsmf) -> z_osmf::Result<()> {
+//! let builder = zosmf.datasets().list("USERNAME");
+//!
+//! let request = builder.get_request()?;
+//! println!("{} {}", request.method(), request.url());
+//!
+//! let start = std::time::Instant::now();
+//! let response = builder.get_response().await?;
+//! println!("{} in {:?}", response.status(), start.elapsed());
+//! # Ok(())
+//! # }
+//! ```
 
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
 #![forbid(unsafe_code)]
 
 pub use bytes::Bytes;
+pub use semver;
 
+pub use self::builder::ZOsmfBuilder;
 pub use self::error::{Error, Result};
+#[cfg(any(feature = "datasets", feature = "files", feature = "jobs"))]
+pub use self::payload::ReadPayload;
+pub use self::retry::RetryPolicy;
+pub use self::transacted::Transacted;
 
-pub mod info;
 pub mod error;
+pub mod info;
 
 #[cfg(feature = "datasets")]
 pub mod datasets;
@@ -78,22 +99,49 @@ pub mod datasets;
 pub mod files;
 #[cfg(feature = "jobs")]
 pub mod jobs;
+#[cfg(feature = "mvs-console")]
+pub mod mvs_console;
+#[cfg(feature = "notifications")]
+pub mod notifications;
+#[cfg(feature = "persistence")]
+pub mod persistence;
+#[cfg(feature = "provisioning")]
+pub mod provisioning;
 #[cfg(any(feature = "datasets", feature = "files"))]
 pub mod restfiles;
+#[cfg(feature = "software")]
+pub mod software;
 #[cfg(feature = "system-variables")]
 pub mod system_variables;
+#[cfg(feature = "topology")]
+pub mod topology;
+#[cfg(feature = "tso")]
+pub mod tso;
 #[cfg(feature = "workflows")]
 pub mod workflows;
 
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::{Arc, RwLock};
 
+use base64::Engine;
+use chrono::{DateTime, Utc};
 use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
 use serde::{Deserialize, Serialize};
+use z_osmf_macros::Getters;
 
 use self::error::CheckStatus;
 
+mod builder;
 mod convert;
+#[cfg(any(feature = "datasets", feature = "files", feature = "jobs"))]
+mod payload;
+mod retry;
+#[cfg(feature = "tracing")]
+mod trace;
+mod transacted;
 mod utils;
+#[cfg(feature = "zowe")]
+mod zowe;
 
 /// # ZOsmf
 ///
@@ -139,14 +187,301 @@ impl ZOsmf {
     where
         U: std::fmt::Display,
     {
-        let token = Arc::new(RwLock::new(None));
+        let tokens = Arc::new(RwLock::new(Vec::new()));
         let url = url.to_string().into();
 
-        let core = ClientCore { client, token, url };
+        let core = ClientCore {
+            client,
+            #[cfg(feature = "tracing")]
+            debug_curl: false,
+            headers: Vec::new(),
+            in_flight: Arc::new(AtomicUsize::new(0)),
+            max_concurrency: None,
+            max_response_size: None,
+            queries: Vec::new(),
+            retry: None,
+            tokens,
+            url,
+        };
 
         ZOsmf { core }
     }
 
+    /// Create a new z/OSMF client that authenticates with an existing token, without logging in.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn example() {
+    /// # use z_osmf::{AuthToken, ZOsmf};
+    /// let client = reqwest::Client::new();
+    /// let url = "https://zosmf.mainframe.my-company.com";
+    /// let token = AuthToken::jwt(std::env::var("ZOSMF_JWT").unwrap());
+    ///
+    /// let zosmf = ZOsmf::with_token(client, url, token);
+    /// # }
+    /// ```
+    pub fn with_token<U>(client: reqwest::Client, url: U, token: AuthToken) -> Self
+    where
+        U: std::fmt::Display,
+    {
+        let ZOsmf { core } = ZOsmf::new(client, url);
+
+        ZOsmf {
+            core: ClientCore {
+                tokens: Arc::new(RwLock::new(vec![token])),
+                ..core
+            },
+        }
+    }
+
+    /// Configure the HTTP client of a new z/OSMF client, instead of providing one.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn example() -> anyhow::Result<()> {
+    /// # use std::time::Duration;
+    /// # use z_osmf::ZOsmf;
+    /// let zosmf = ZOsmf::builder("https://zosmf.mainframe.my-company.com")
+    ///     .timeout(Duration::from_secs(30))
+    ///     .danger_accept_invalid_certs(true)
+    ///     .build()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn builder<U>(url: U) -> ZOsmfBuilder
+    where
+        U: std::fmt::Display,
+    {
+        ZOsmfBuilder::new(url)
+    }
+
+    /// Create a z/OSMF client from environment variables and authenticate with it.
+    ///
+    /// | Variable                     | Required | Description                                        |
+    /// |------------------------------|----------|----------------------------------------------------|
+    /// | `ZOSMF_BASE_URL`             | yes      | Everything before the `/zosmf` in the API URLs.    |
+    /// | `ZOSMF_USERNAME`             | yes      | The user to log in as.                             |
+    /// | `ZOSMF_PASSWORD`             | yes      | The password of the user.                          |
+    /// | `ZOSMF_CERT_PATH`            | no       | A PEM root certificate to trust, for private CAs.  |
+    /// | `ZOSMF_ACCEPT_INVALID_CERTS` | no       | Set to `true` to skip certificate validation.      |
+    ///
+    /// A missing required variable fails with [`Error::Env`]. The certificate variables
+    /// need the `rustls-tls` feature, and fail with [`Error::InvalidValue`] without it.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn example() -> anyhow::Result<()> {
+    /// # use z_osmf::ZOsmf;
+    /// let zosmf = ZOsmf::from_env().await?;
+    ///
+    /// let my_datasets = zosmf.datasets().list("USERNAME").build().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "env")]
+    pub async fn from_env() -> Result<Self> {
+        ZOsmf::from_vars(|name| std::env::var(name)).await
+    }
+
+    /// Create a z/OSMF client like [`from_env`](Self::from_env), looking up variables with `lookup`.
+    #[cfg(feature = "env")]
+    async fn from_vars<L>(lookup: L) -> Result<Self>
+    where
+        L: Fn(&str) -> std::result::Result<String, std::env::VarError>,
+    {
+        let var = |name: &'static str| lookup(name).map_err(|source| Error::Env { name, source });
+
+        let base_url = var("ZOSMF_BASE_URL")?;
+        let username = var("ZOSMF_USERNAME")?;
+        let password = var("ZOSMF_PASSWORD")?;
+
+        let accept_invalid_certs = lookup("ZOSMF_ACCEPT_INVALID_CERTS")
+            .is_ok_and(|value| value.eq_ignore_ascii_case("true") || value == "1");
+        let cert_path = lookup("ZOSMF_CERT_PATH").ok();
+
+        let builder = ZOsmf::builder(base_url).connect_timeout(std::time::Duration::from_secs(10));
+
+        #[cfg(feature = "rustls-tls")]
+        let builder = {
+            let mut builder = builder.danger_accept_invalid_certs(accept_invalid_certs);
+            if let Some(cert_path) = cert_path {
+                builder = builder.add_root_certificate(std::fs::read(cert_path)?);
+            }
+
+            builder
+        };
+
+        #[cfg(not(feature = "rustls-tls"))]
+        if accept_invalid_certs || cert_path.is_some() {
+            return Err(Error::InvalidValue(
+                "ZOSMF_ACCEPT_INVALID_CERTS and ZOSMF_CERT_PATH need the rustls-tls feature"
+                    .to_string(),
+            ));
+        }
+
+        let zosmf = builder.build()?;
+
+        zosmf.login(username, password).await?;
+
+        Ok(zosmf)
+    }
+
+    /// Create a z/OSMF client from a profile of a Zowe team configuration file, like
+    /// `~/.zowe/zowe.config.json`, and authenticate with it.
+    ///
+    /// The profile is named by its dotted path, like `lpar1.zosmf`, or defaults to the
+    /// configuration's default `zosmf` profile. Its properties are merged over those of its
+    /// parent profiles and of the default `base` profile.
+    ///
+    /// | Property                  | Required | Description                                    |
+    /// |---------------------------|----------|------------------------------------------------|
+    /// | `host`                    | yes      | The host name of z/OSMF.                       |
+    /// | `port`                    | no       | The port of z/OSMF.                            |
+    /// | `protocol`                | no       | Defaults to `https`.                           |
+    /// | `user`, `password`        | no       | The credentials to log in with.                |
+    /// | `tokenType`, `tokenValue` | no       | A `jwtToken` or `LtpaToken2` to use instead.   |
+    /// | `rejectUnauthorized`      | no       | Set to `false` to skip certificate validation. |
+    ///
+    /// Skipping certificate validation needs the `rustls-tls` feature, and fails with
+    /// [`Error::InvalidValue`] without it.
+    ///
+    /// Credentials kept in the Zowe secure credential store are not read; a profile without
+    /// plain text credentials fails with [`Error::InvalidValue`].
+    ///
+    /// # Example
+    /// ```
+    /// # async fn example() -> anyhow::Result<()> {
+    /// # use z_osmf::ZOsmf;
+    /// let zosmf = ZOsmf::from_zowe_config("zowe.config.json", Some("lpar1.zosmf")).await?;
+    ///
+    /// let my_datasets = zosmf.datasets().list("USERNAME").build().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "zowe")]
+    pub async fn from_zowe_config<P>(path: P, profile_name: Option<&str>) -> Result<Self>
+    where
+        P: AsRef<std::path::Path>,
+    {
+        let config = std::fs::read_to_string(path)?;
+        let profile = zowe::resolve_profile(&config, profile_name)?;
+
+        let builder =
+            ZOsmf::builder(profile.url).connect_timeout(std::time::Duration::from_secs(10));
+
+        #[cfg(feature = "rustls-tls")]
+        let builder = builder.danger_accept_invalid_certs(!profile.reject_unauthorized);
+
+        #[cfg(not(feature = "rustls-tls"))]
+        if !profile.reject_unauthorized {
+            return Err(Error::InvalidValue(
+                "rejectUnauthorized set to false needs the rustls-tls feature".to_string(),
+            ));
+        }
+
+        let zosmf = builder.build()?;
+
+        match profile.credentials {
+            zowe::ZoweCredentials::Password { user, password } => {
+                zosmf.login(user, password).await?;
+            }
+            zowe::ZoweCredentials::Token(token) => zosmf.set_tokens(vec![token])?,
+        }
+
+        Ok(zosmf)
+    }
+
+    /// Retry failed requests according to the given policy.
+    ///
+    /// Only `GET` requests are retried unless the policy opts in to
+    /// [`RetryPolicy::retry_non_idempotent`]. When every attempt fails with a retryable error,
+    /// the last error is returned as [`Error::Retry`] along with the transaction IDs of the
+    /// failed attempts. A retry that fails with an error that is not retried, like a `404`,
+    /// returns that error as it is.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn example() {
+    /// # use z_osmf::{RetryPolicy, ZOsmf};
+    /// let client = reqwest::Client::new();
+    /// let url = "https://zosmf.mainframe.my-company.com";
+    ///
+    /// let zosmf = ZOsmf::new(client, url).with_retry(RetryPolicy::default());
+    /// # }
+    /// ```
+    pub fn with_retry(mut self, policy: RetryPolicy) -> Self {
+        self.core.retry = Some(policy);
+
+        self
+    }
+
+    /// Send at most `n` requests at a time, to avoid overwhelming or being throttled by z/OSMF.
+    ///
+    /// The limit is shared by every clone of this client and its sub-clients;
+    /// further requests wait for one in flight to complete. A request is in flight until its
+    /// response body has been read or dropped, so a streamed read holds its slot while the
+    /// stream is alive. A limit of `0` is read as `1`.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn example() {
+    /// # use z_osmf::ZOsmf;
+    /// let client = reqwest::Client::new();
+    /// let url = "https://zosmf.mainframe.my-company.com";
+    ///
+    /// let zosmf = ZOsmf::new(client, url).with_max_concurrency(8);
+    /// # }
+    /// ```
+    pub fn with_max_concurrency(mut self, n: usize) -> Self {
+        self.core.max_concurrency = Some(Arc::new(tokio::sync::Semaphore::new(n.max(1))));
+
+        self
+    }
+
+    /// Reject responses larger than `bytes`, like reads of unexpectedly large datasets.
+    ///
+    /// A response whose `Content-Length` is too large fails with [`Error::ResponseTooLarge`]
+    /// before its body is downloaded. A response without one, like a chunked read, fails with
+    /// [`Error::ResponseTooLarge`] as soon as more than `bytes` of its body have been read.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn example() {
+    /// # use z_osmf::ZOsmf;
+    /// let client = reqwest::Client::new();
+    /// let url = "https://zosmf.mainframe.my-company.com";
+    ///
+    /// let zosmf = ZOsmf::new(client, url).with_max_response_size(64 * 1024 * 1024);
+    /// # }
+    /// ```
+    pub fn with_max_response_size(mut self, bytes: u64) -> Self {
+        self.core.max_response_size = Some(bytes);
+
+        self
+    }
+
+    /// Log an equivalent `curl` command for each request sent, to reproduce it for support.
+    ///
+    /// The command is logged at the `DEBUG` level within the request's span, with the
+    /// authentication token masked and the body replaced by its length.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn example() {
+    /// # use z_osmf::ZOsmf;
+    /// let client = reqwest::Client::new();
+    /// let url = "https://zosmf.mainframe.my-company.com";
+    ///
+    /// let zosmf = ZOsmf::new(client, url).debug_curl(true);
+    /// # }
+    /// ```
+    #[cfg(feature = "tracing")]
+    pub fn debug_curl(mut self, enabled: bool) -> Self {
+        self.core.debug_curl = enabled;
+
+        self
+    }
+
     /// Retrieve information about z/OSMF.
     ///
     /// # Example
@@ -160,6 +495,28 @@ impl ZOsmf {
         info::InfoBuilder::new(self.core.clone()).build().await
     }
 
+    /// Check whether the client holds an authentication token that has not expired.
+    ///
+    /// LTPA2 tokens carry no readable expiry, so they are considered valid while stored.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// if !zosmf.is_authenticated() {
+    ///     zosmf.login("USERNAME", "PASSWORD").await?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn is_authenticated(&self) -> bool {
+        match self.core.token() {
+            Ok(Some(token)) => token
+                .expires_at()
+                .is_none_or(|expires_at| expires_at > Utc::now()),
+            _ => false,
+        }
+    }
+
     /// Authenticate with z/OSMF.
     ///
     /// # Example
@@ -184,6 +541,64 @@ impl ZOsmf {
             .check_status()
             .await?;
 
+        self.store_tokens(&response)
+    }
+
+    /// Authenticate with z/OSMF on a system that requires multi-factor authentication.
+    ///
+    /// The MFA token, like a one-time code, is sent appended to the password
+    /// as `password:token`, the compound in-band form accepted by IBM Z MFA.
+    /// When z/OSMF rejects the login with a message naming MFA, like an invalid or
+    /// expired MFA token, this fails with [`Error::MfaRejected`]; other failures,
+    /// like a wrong password, are returned as they are.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let auth_tokens = zosmf.login_mfa("USERNAME", "PASSWORD", "123456").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn login_mfa<U, P, T>(
+        &self,
+        username: U,
+        password: P,
+        mfa_token: T,
+    ) -> Result<Vec<AuthToken>>
+    where
+        U: std::fmt::Display,
+        P: std::fmt::Display,
+        T: std::fmt::Display,
+    {
+        self.login(username, format!("{}:{}", password, mfa_token))
+            .await
+            .map_err(mfa_error)
+    }
+
+    /// Authenticate with z/OSMF using the client certificate configured with
+    /// [`ZOsmfBuilder::identity`], without sending a password.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let auth_tokens = zosmf.login_with_certificate().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn login_with_certificate(&self) -> Result<Vec<AuthToken>> {
+        let response = self
+            .core
+            .client
+            .post(format!("{}/zosmf/services/authenticate", self.core.url))
+            .send()
+            .await?
+            .check_status()
+            .await?;
+
+        self.store_tokens(&response)
+    }
+
+    fn store_tokens(&self, response: &reqwest::Response) -> Result<Vec<AuthToken>> {
         let mut tokens: Vec<AuthToken> = response
             .headers()
             .get_all(reqwest::header::SET_COOKIE)
@@ -192,7 +607,7 @@ impl ZOsmf {
             .collect();
         tokens.sort_unstable();
 
-        self.set_token(tokens.first().cloned())?;
+        self.set_tokens(tokens.clone())?;
 
         Ok(tokens)
     }
@@ -202,6 +617,7 @@ impl ZOsmf {
     /// <p style="background:rgba(255,181,77,0.16);padding:0.75em;">
     /// <strong>Warning:</strong> Logging out before an action has completed,
     /// like immediately after submitting a job, can cause the action to fail.
+    /// Use [`logout_graceful`](Self::logout_graceful) to wait for requests in flight first.
     /// </p>
     ///
     /// # Example
@@ -220,7 +636,122 @@ impl ZOsmf {
             .check_status()
             .await?;
 
-        self.set_token(None)?;
+        self.set_tokens(Vec::new())?;
+
+        Ok(())
+    }
+
+    /// Logout of z/OSMF once the requests already in flight on this client have completed.
+    ///
+    /// Requests sent through any clone of this client, or its sub-clients, are waited for
+    /// until their response body has been read or dropped, so a streamed read is waited for
+    /// while its stream is alive. Gives up with [`Error::Timeout`], without logging out,
+    /// if they have not completed in time.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// # use std::time::Duration;
+    /// zosmf.logout_graceful(Duration::from_secs(30)).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn logout_graceful(&self, timeout: std::time::Duration) -> Result<()> {
+        const POLL_INTERVAL: std::time::Duration = std::time::Duration::from_millis(50);
+
+        let deadline = tokio::time::Instant::now() + timeout;
+
+        while self.core.in_flight.load(Ordering::SeqCst) > 0 {
+            let remaining = deadline.saturating_duration_since(tokio::time::Instant::now());
+            if remaining.is_zero() {
+                return Err(Error::Timeout(timeout));
+            }
+
+            tokio::time::sleep(remaining.min(POLL_INTERVAL)).await;
+        }
+
+        self.logout().await
+    }
+
+    /// Export the current session so that it can be persisted and restored later.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let session = zosmf.export_session()?;
+    /// std::fs::write("session.json", serde_json::to_string(&session)?)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn export_session(&self) -> Result<SessionBlob> {
+        let tokens = self.core.tokens.read()?.clone();
+
+        Ok(SessionBlob {
+            url: self.core.url.clone(),
+            tokens,
+        })
+    }
+
+    /// Restore a session previously created with [`ZOsmf::export_session`].
+    ///
+    /// Fails with [`Error::InvalidValue`] if the session was exported for a different base URL.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn example(mut zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let session = serde_json::from_str(&std::fs::read_to_string("session.json")?)?;
+    /// zosmf.import_session(session)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn import_session(&mut self, session: SessionBlob) -> Result<()> {
+        let SessionBlob { url, tokens } = session;
+
+        if url.trim_end_matches('/') != self.core.url.trim_end_matches('/') {
+            return Err(Error::InvalidValue(format!(
+                "session base URL {} does not match client base URL {}",
+                url, self.core.url
+            )));
+        }
+
+        self.set_tokens(tokens)
+    }
+
+    /// Retrieve the authentication tokens currently held by the client.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let auth_tokens = zosmf.tokens();
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn tokens(&self) -> Vec<AuthToken> {
+        match self.core.tokens.read() {
+            Ok(read) => read.clone(),
+            Err(err) => err.into_inner().clone(),
+        }
+    }
+
+    /// Replace the authentication tokens held by the client.
+    ///
+    /// When both are present, requests are authenticated with the JWT
+    /// in preference to the LTPA2 token.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// # use z_osmf::AuthToken;
+    /// zosmf.set_tokens(vec![AuthToken::Jwt("eyJhbGciOiJSUzI1NiJ9...".to_string())])?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_tokens(&self, mut tokens: Vec<AuthToken>) -> Result<()> {
+        tokens.sort_unstable();
+        tokens.dedup();
+
+        let mut write = self.core.tokens.write()?;
+        *write = tokens;
 
         Ok(())
     }
@@ -253,6 +784,20 @@ impl ZOsmf {
         files::FilesClient::new(self.core.clone())
     }
 
+    /// Create a sub-client for issuing MVS console commands.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let console = zosmf.console();
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "mvs-console")]
+    pub fn console(&self) -> mvs_console::ConsoleClient {
+        mvs_console::ConsoleClient::new(self.core.clone())
+    }
+
     /// Create a sub-client for interacting with jobs.
     ///
     /// # Example
@@ -267,6 +812,62 @@ impl ZOsmf {
         jobs::JobsClient::new(self.core.clone())
     }
 
+    /// Create a sub-client for sending and reading user notifications.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let notifications = zosmf.notifications();
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "notifications")]
+    pub fn notifications(&self) -> notifications::NotificationsClient {
+        notifications::NotificationsClient::new(self.core.clone())
+    }
+
+    /// Create a sub-client for storing data on the host.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let persistence = zosmf.persistence();
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "persistence")]
+    pub fn persistence(&self) -> persistence::PersistenceClient {
+        persistence::PersistenceClient::new(self.core.clone())
+    }
+
+    /// Create a sub-client for interacting with cloud provisioning templates and instances.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let provisioning = zosmf.provisioning();
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "provisioning")]
+    pub fn provisioning(&self) -> provisioning::ProvisioningClient {
+        provisioning::ProvisioningClient::new(self.core.clone())
+    }
+
+    /// Create a sub-client for interacting with software instances.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let software = zosmf.software();
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "software")]
+    pub fn software(&self) -> software::SoftwareClient {
+        software::SoftwareClient::new(self.core.clone())
+    }
+
     /// Create a sub-client for interacting with system symbols and variables.
     ///
     /// # Example
@@ -281,6 +882,34 @@ impl ZOsmf {
         system_variables::SystemVariablesClient::new(self.core.clone())
     }
 
+    /// Create a sub-client for interacting with the systems, sysplexes and groups defined to z/OSMF.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let topology = zosmf.topology();
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "topology")]
+    pub fn topology(&self) -> topology::TopologyClient {
+        topology::TopologyClient::new(self.core.clone())
+    }
+
+    /// Create a sub-client for interacting with TSO/E address spaces.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let tso = zosmf.tso();
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "tso")]
+    pub fn tso(&self) -> tso::TsoClient {
+        tso::TsoClient::new(self.core.clone())
+    }
+
     /// Create a sub-client for interacting with workflows.
     ///
     /// # Example
@@ -294,25 +923,63 @@ impl ZOsmf {
     pub fn workflows(&self) -> workflows::WorkflowsClient {
         workflows::WorkflowsClient::new(self.core.clone())
     }
-
-    fn set_token(&self, token: Option<AuthToken>) -> Result<()> {
-        let mut write = self
-            .core
-            .token
-            .write()
-            .map_err(|err| Error::RwLockPoisonError(err.to_string()))?;
-        *write = token;
-
-        Ok(())
-    }
 }
 
-#[derive(Clone, Debug, Deserialize, Eq, Ord, PartialEq, PartialOrd, Serialize)]
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
 pub enum AuthToken {
     Jwt(String),
     Ltpa2(String),
 }
 
+impl AuthToken {
+    /// Create a JWT authentication token from its raw value.
+    ///
+    /// # Example
+    /// ```
+    /// # use z_osmf::AuthToken;
+    /// let token = AuthToken::jwt("eyJhbGciOiJSUzI1NiJ9...");
+    /// ```
+    pub fn jwt<T>(token: T) -> Self
+    where
+        T: std::fmt::Display,
+    {
+        AuthToken::Jwt(token.to_string())
+    }
+
+    /// Create an LTPA2 authentication token from its raw value.
+    ///
+    /// # Example
+    /// ```
+    /// # use z_osmf::AuthToken;
+    /// let token = AuthToken::ltpa2("abcd1234...");
+    /// ```
+    pub fn ltpa2<T>(token: T) -> Self
+    where
+        T: std::fmt::Display,
+    {
+        AuthToken::Ltpa2(token.to_string())
+    }
+
+    /// The expiry of a JWT, read from its `exp` claim.
+    ///
+    /// The signature is not verified. Returns `None` for LTPA2 tokens
+    /// and for JWTs without a readable `exp` claim.
+    pub fn expires_at(&self) -> Option<DateTime<Utc>> {
+        match self {
+            AuthToken::Jwt(token) => {
+                let payload = token.split('.').nth(1)?;
+                let payload = base64::engine::general_purpose::URL_SAFE_NO_PAD
+                    .decode(payload.trim_end_matches('='))
+                    .ok()?;
+                let JwtClaims { exp } = serde_json::from_slice(&payload).ok()?;
+
+                DateTime::from_timestamp(exp?, 0)
+            }
+            AuthToken::Ltpa2(_) => None,
+        }
+    }
+}
+
 impl std::str::FromStr for AuthToken {
     type Err = Error;
 
@@ -382,13 +1049,96 @@ impl From<&AuthToken> for HeaderMap {
     }
 }
 
+#[derive(Clone, Debug, Deserialize, Eq, Getters, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub struct SessionBlob {
+    url: Arc<str>,
+    tokens: Vec<AuthToken>,
+}
+
+#[derive(Deserialize)]
+struct JwtClaims {
+    #[serde(default)]
+    exp: Option<i64>,
+}
+
 #[derive(Clone, Debug)]
 struct ClientCore {
     client: reqwest::Client,
-    token: Arc<RwLock<Option<AuthToken>>>,
+    #[cfg(feature = "tracing")]
+    debug_curl: bool,
+    headers: Vec<(Arc<str>, Arc<str>)>,
+    in_flight: Arc<AtomicUsize>,
+    max_concurrency: Option<Arc<tokio::sync::Semaphore>>,
+    max_response_size: Option<u64>,
+    queries: Vec<(Arc<str>, Arc<str>)>,
+    retry: Option<RetryPolicy>,
+    tokens: Arc<RwLock<Vec<AuthToken>>>,
     url: Arc<str>,
 }
 
+impl ClientCore {
+    /// The token used to authenticate requests: the JWT when present, otherwise the LTPA2 token.
+    fn token(&self) -> Result<Option<AuthToken>> {
+        let read = self.tokens.read()?;
+
+        let jwt = read.iter().find(|token| matches!(token, AuthToken::Jwt(_)));
+        let ltpa2 = read
+            .iter()
+            .find(|token| matches!(token, AuthToken::Ltpa2(_)));
+
+        Ok(jwt.or(ltpa2).cloned())
+    }
+
+    /// Wait for a slot to send a request when the client's concurrency is capped.
+    async fn acquire_permit(&self) -> Option<tokio::sync::OwnedSemaphorePermit> {
+        match &self.max_concurrency {
+            Some(semaphore) => semaphore.clone().acquire_owned().await.ok(),
+            None => None,
+        }
+    }
+
+    /// Count a request as in flight until the returned guard is dropped.
+    fn track_request(&self) -> InFlightGuard {
+        self.in_flight.fetch_add(1, Ordering::SeqCst);
+
+        InFlightGuard(self.in_flight.clone())
+    }
+}
+
+struct InFlightGuard(Arc<AtomicUsize>);
+
+impl Drop for InFlightGuard {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+/// Convert an authentication failure of [`ZOsmf::login_mfa`] that blames MFA into [`Error::MfaRejected`].
+///
+/// Only a `401 Unauthorized` that names MFA is converted, since a rejected
+/// password or an expired LTPA or JWT token also mentions a token.
+fn mfa_error(err: Error) -> Error {
+    let message = match &err {
+        Error::ZOsmf(zosmf_err) if zosmf_err.status() == reqwest::StatusCode::UNAUTHORIZED => {
+            zosmf_err.message().into()
+        }
+        Error::ZOsmfText { status, body, .. } if *status == reqwest::StatusCode::UNAUTHORIZED => {
+            body.clone()
+        }
+        _ => return err,
+    };
+
+    let lowercase = message.to_lowercase();
+    if ["mfa", "multi-factor", "multifactor"]
+        .iter()
+        .any(|keyword| lowercase.contains(keyword))
+    {
+        Error::MfaRejected(message)
+    } else {
+        err
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -397,6 +1147,390 @@ mod tests {
         ZOsmf::new(reqwest::Client::new(), "https://test.com")
     }
 
+    #[tokio::test]
+    async fn max_concurrency() {
+        let zosmf = get_zosmf();
+        assert!(zosmf.core.acquire_permit().await.is_none());
+
+        let zosmf = zosmf.with_max_concurrency(1);
+        let clone = zosmf.clone();
+
+        let permit = zosmf.core.acquire_permit().await;
+        assert!(permit.is_some());
+        assert!(tokio::time::timeout(
+            std::time::Duration::from_millis(50),
+            clone.core.acquire_permit()
+        )
+        .await
+        .is_err());
+
+        drop(permit);
+        assert!(clone.core.acquire_permit().await.is_some());
+    }
+
+    /// Serve each connection with the next of `responses`, as a status and JSON body,
+    /// repeating the last, and report the request line of each request and `body` when
+    /// a body is sent. With `release`, the body of the first response is only sent once
+    /// `release` receives.
+    pub(crate) fn serve(
+        responses: &'static [(u16, &'static str)],
+        release: Option<std::sync::mpsc::Receiver<()>>,
+    ) -> (String, tokio::sync::mpsc::UnboundedReceiver<String>) {
+        use std::io::{Read, Write};
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let (events, received) = tokio::sync::mpsc::unbounded_channel();
+
+        std::thread::spawn(move || {
+            let mut release = release;
+
+            for (index, mut stream) in listener.incoming().flatten().enumerate() {
+                let (status, body) = responses[index.min(responses.len() - 1)];
+                let release = release.take();
+                let events = events.clone();
+
+                std::thread::spawn(move || {
+                    let mut request = [0; 4096];
+                    let read = stream.read(&mut request).unwrap_or(0);
+                    let request = String::from_utf8_lossy(&request[..read]);
+                    let _ = events.send(request.lines().next().unwrap_or_default().to_string());
+
+                    let _ = write!(
+                        stream,
+                        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\n\
+                         Content-Length: {}\r\nConnection: close\r\n\r\n",
+                        status,
+                        reqwest::StatusCode::from_u16(status)
+                            .ok()
+                            .and_then(|status| status.canonical_reason())
+                            .unwrap_or_default(),
+                        body.len()
+                    );
+                    let _ = stream.flush();
+
+                    if let Some(release) = release {
+                        let _ = release.recv();
+                    }
+                    let _ = events.send("body".to_string());
+                    let _ = stream.write_all(body.as_bytes());
+                });
+            }
+        });
+
+        (url, received)
+    }
+
+    #[tokio::test]
+    async fn logout_graceful_waits_for_response_bodies() {
+        let (release, gate) = std::sync::mpsc::channel();
+        let (url, mut events) = serve(
+            &[(
+                200,
+                r#"{"zosmf_version":"27","zosmf_hostname":"zosmf.test.com","plugins":[]}"#,
+            )],
+            Some(gate),
+        );
+        let zosmf = ZOsmf::new(reqwest::Client::new(), url);
+
+        let info = tokio::spawn({
+            let zosmf = zosmf.clone();
+
+            async move { zosmf.info().await }
+        });
+        assert_eq!(events.recv().await.unwrap(), "GET /zosmf/info HTTP/1.1");
+        assert_eq!(zosmf.core.in_flight.load(Ordering::SeqCst), 1);
+
+        let logout = tokio::spawn({
+            let zosmf = zosmf.clone();
+
+            async move {
+                zosmf
+                    .logout_graceful(std::time::Duration::from_secs(5))
+                    .await
+            }
+        });
+        release.send(()).unwrap();
+
+        assert_eq!(events.recv().await.unwrap(), "body");
+        assert_eq!(
+            events.recv().await.unwrap(),
+            "DELETE /zosmf/services/authenticate HTTP/1.1"
+        );
+        logout.await.unwrap().unwrap();
+        assert_eq!(
+            info.await.unwrap().unwrap().zosmf_hostname(),
+            "zosmf.test.com"
+        );
+        assert_eq!(zosmf.core.in_flight.load(Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test]
+    async fn logout_graceful_waits_for_requests() {
+        let zosmf = get_zosmf();
+
+        let in_flight = zosmf.clone().core.track_request();
+        assert_eq!(zosmf.core.in_flight.load(Ordering::SeqCst), 1);
+
+        assert!(matches!(
+            zosmf
+                .logout_graceful(std::time::Duration::from_millis(100))
+                .await,
+            Err(Error::Timeout(_))
+        ));
+
+        drop(in_flight);
+        assert_eq!(zosmf.core.in_flight.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn getter_iter() {
+        #[derive(Getters)]
+        struct Lists {
+            #[getter(iter)]
+            shared: Arc<[Arc<str>]>,
+            #[getter(iter)]
+            owned: Vec<i32>,
+            #[getter(iter)]
+            optional: Option<Arc<[i32]>>,
+            #[getter(iter)]
+            missing: Option<Vec<i32>>,
+        }
+
+        let lists = Lists {
+            shared: ["A".into(), "B".into()].into(),
+            owned: vec![1, 2, 3],
+            optional: Some([4, 5].into()),
+            missing: None,
+        };
+
+        assert_eq!(
+            lists.shared().map(|s| s.as_ref()).collect::<Vec<_>>(),
+            ["A", "B"]
+        );
+        assert_eq!(lists.owned().sum::<i32>(), 6);
+        assert_eq!(lists.optional().copied().collect::<Vec<_>>(), [4, 5]);
+        assert_eq!(lists.missing().count(), 0);
+    }
+
+    #[test]
+    fn token_expires_at() {
+        let token = AuthToken::Jwt(
+            "eyJhbGciOiJSUzI1NiJ9.eyJzdWIiOiJJQk1VU0VSIiwiZXhwIjoxNzAwMDAwMDAwfQ.c2ln".to_string(),
+        );
+        assert_eq!(token.expires_at(), DateTime::from_timestamp(1700000000, 0));
+
+        let token = AuthToken::Jwt("eyJhbGciOiJSUzI1NiJ9.eyJzdWIiOiJJQk1VU0VSIn0.c2ln".to_string());
+        assert_eq!(token.expires_at(), None);
+
+        let token = AuthToken::Jwt("NONSENSE".to_string());
+        assert_eq!(token.expires_at(), None);
+
+        let token = AuthToken::Ltpa2("abcd1234".to_string());
+        assert_eq!(token.expires_at(), None);
+    }
+
+    #[cfg(feature = "env")]
+    #[tokio::test]
+    async fn from_env_missing_variable() {
+        let lookup = |vars: &'static [(&'static str, &'static str)]| {
+            move |name: &str| {
+                vars.iter()
+                    .find(|(key, _)| *key == name)
+                    .map(|(_, value)| value.to_string())
+                    .ok_or(std::env::VarError::NotPresent)
+            }
+        };
+
+        match ZOsmf::from_vars(lookup(&[])).await {
+            Err(Error::Env { name, .. }) => assert_eq!(name, "ZOSMF_BASE_URL"),
+            other => panic!("unexpected result: {:?}", other.map(|_| ())),
+        }
+
+        match ZOsmf::from_vars(lookup(&[
+            ("ZOSMF_BASE_URL", "https://test.com"),
+            ("ZOSMF_USERNAME", "IBMUSER"),
+        ]))
+        .await
+        {
+            Err(Error::Env { name, .. }) => assert_eq!(name, "ZOSMF_PASSWORD"),
+            other => panic!("unexpected result: {:?}", other.map(|_| ())),
+        }
+    }
+
+    #[test]
+    fn store_tokens() {
+        let zosmf = get_zosmf();
+        let response = reqwest::Response::from(
+            http::Response::builder()
+                .header(
+                    "Set-Cookie",
+                    "LtpaToken2=abcd1234; Path=/; Secure; HttpOnly",
+                )
+                .header(
+                    "Set-Cookie",
+                    "jwtToken=eyJhbGciOiJSUzI1NiJ9; Path=/; Secure",
+                )
+                .body("")
+                .unwrap(),
+        );
+
+        let tokens = zosmf.store_tokens(&response).unwrap();
+
+        assert_eq!(
+            tokens,
+            vec![
+                AuthToken::Jwt("eyJhbGciOiJSUzI1NiJ9".to_string()),
+                AuthToken::Ltpa2("abcd1234".to_string())
+            ]
+        );
+        assert_eq!(zosmf.tokens(), tokens);
+    }
+
+    #[tokio::test]
+    async fn mfa_error() {
+        let response = |body: &str| {
+            reqwest::Response::from(
+                http::Response::builder()
+                    .status(401)
+                    .body(body.to_string())
+                    .unwrap(),
+            )
+        };
+
+        let err =
+            response(r#"{"category":1,"rc":8,"reason":4,"message":"The MFA token has expired"}"#)
+                .check_status()
+                .await
+                .unwrap_err();
+        assert!(matches!(
+            super::mfa_error(err),
+            Error::MfaRejected(message) if message.as_ref() == "The MFA token has expired"
+        ));
+
+        let err = response("Invalid multi-factor token code")
+            .check_status()
+            .await
+            .unwrap_err();
+        assert!(matches!(super::mfa_error(err), Error::MfaRejected(_)));
+
+        let err =
+            response(r#"{"category":1,"rc":8,"reason":4,"message":"The LTPA token has expired"}"#)
+                .check_status()
+                .await
+                .unwrap_err();
+        assert!(matches!(super::mfa_error(err), Error::ZOsmf(_)));
+
+        let err = response("Invalid password or token")
+            .check_status()
+            .await
+            .unwrap_err();
+        assert!(matches!(super::mfa_error(err), Error::ZOsmfText { .. }));
+
+        let err = response("Not authorized").check_status().await.unwrap_err();
+        assert!(matches!(super::mfa_error(err), Error::ZOsmfText { .. }));
+    }
+
+    #[test]
+    fn with_token() {
+        let zosmf = ZOsmf::with_token(
+            reqwest::Client::new(),
+            "https://test.com",
+            AuthToken::jwt("eyJhbGciOiJSUzI1NiJ9"),
+        );
+
+        assert_eq!(
+            zosmf.tokens(),
+            vec![AuthToken::Jwt("eyJhbGciOiJSUzI1NiJ9".to_string())]
+        );
+        assert!(zosmf.is_authenticated());
+
+        let request = zosmf.datasets().list("SYS1").get_request().unwrap();
+        assert_eq!(
+            request.headers()[reqwest::header::AUTHORIZATION],
+            "Bearer eyJhbGciOiJSUzI1NiJ9"
+        );
+    }
+
+    #[test]
+    fn is_authenticated() {
+        let zosmf = get_zosmf();
+        assert!(!zosmf.is_authenticated());
+
+        zosmf
+            .set_tokens(vec![AuthToken::Jwt(
+                "eyJhbGciOiJSUzI1NiJ9.eyJzdWIiOiJJQk1VU0VSIiwiZXhwIjoxNzAwMDAwMDAwfQ.c2ln"
+                    .to_string(),
+            )])
+            .unwrap();
+        assert!(!zosmf.is_authenticated());
+
+        zosmf
+            .set_tokens(vec![AuthToken::Jwt(
+                "eyJhbGciOiJSUzI1NiJ9.eyJzdWIiOiJJQk1VU0VSIiwiZXhwIjozMjUwMzY4MDAwMH0.c2ln"
+                    .to_string(),
+            )])
+            .unwrap();
+        assert!(zosmf.is_authenticated());
+
+        zosmf
+            .set_tokens(vec![AuthToken::Ltpa2("abcd1234".to_string())])
+            .unwrap();
+        assert!(zosmf.is_authenticated());
+    }
+
+    #[test]
+    fn session_round_trip() {
+        let zosmf = get_zosmf();
+        let tokens = vec![
+            AuthToken::Jwt("eyJhbGciOiJSUzI1NiJ9.e30.c2ln".to_string()),
+            AuthToken::Ltpa2("abcd1234".to_string()),
+        ];
+        zosmf.set_tokens(tokens.clone()).unwrap();
+
+        let session = zosmf.export_session().unwrap();
+        assert_eq!(session.url(), "https://test.com");
+        assert_eq!(session.tokens(), &tokens[..]);
+
+        let json = serde_json::to_string(&session).unwrap();
+        let session: SessionBlob = serde_json::from_str(&json).unwrap();
+
+        let mut restored = ZOsmf::new(reqwest::Client::new(), "https://test.com/");
+        restored.import_session(session.clone()).unwrap();
+        assert_eq!(restored.tokens(), tokens);
+
+        let mut other = ZOsmf::new(reqwest::Client::new(), "https://other.com");
+        assert!(matches!(
+            other.import_session(session),
+            Err(Error::InvalidValue(_))
+        ));
+        assert!(other.tokens().is_empty());
+    }
+
+    #[test]
+    fn preferred_token() {
+        let zosmf = get_zosmf();
+        assert_eq!(zosmf.core.token().unwrap(), None);
+
+        let jwt = AuthToken::Jwt("eyJhbGciOiJSUzI1NiJ9.e30.c2ln".to_string());
+        let ltpa2 = AuthToken::Ltpa2("abcd1234".to_string());
+
+        zosmf.set_tokens(vec![ltpa2.clone()]).unwrap();
+        assert_eq!(zosmf.core.token().unwrap(), Some(ltpa2.clone()));
+
+        zosmf.set_tokens(vec![ltpa2.clone(), jwt.clone()]).unwrap();
+        assert_eq!(zosmf.core.token().unwrap(), Some(jwt.clone()));
+        assert_eq!(zosmf.tokens(), vec![jwt.clone(), ltpa2.clone()]);
+
+        let headers: HeaderMap = (&zosmf.core.token().unwrap().unwrap()).into();
+        assert_eq!(
+            headers.get(reqwest::header::AUTHORIZATION),
+            Some(&HeaderValue::from_static(
+                "Bearer eyJhbGciOiJSUzI1NiJ9.e30.c2ln"
+            ))
+        );
+    }
+
     pub(crate) trait GetJson {
         fn json(&self) -> Option<serde_json::Value>;
     }
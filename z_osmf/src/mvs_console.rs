@@ -0,0 +1,215 @@
+pub mod issue;
+
+mod solicited;
+
+use std::sync::Arc;
+
+use futures_core::Stream;
+use futures_util::StreamExt;
+use serde::{Deserialize, Serialize};
+use z_osmf_macros::Getters;
+
+use crate::convert::TryFromResponse;
+use crate::{ClientCore, Result};
+
+use self::issue::ConsoleIssueBuilder;
+use self::solicited::ConsoleSolicitedBuilder;
+
+#[derive(Clone, Debug)]
+pub struct ConsoleClient {
+    core: ClientCore,
+}
+
+/// # MVS Consoles
+impl ConsoleClient {
+    pub(crate) fn new(core: ClientCore) -> Self {
+        ConsoleClient { core }
+    }
+
+    /// # Examples
+    ///
+    /// Retrieve further messages solicited by a command:
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let console_response = zosmf
+    ///     .console()
+    ///     .get_solicited("defcn", "C1977656")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_solicited<C, K>(&self, console_name: C, key: K) -> Result<ConsoleResponse>
+    where
+        C: std::fmt::Display,
+        K: std::fmt::Display,
+    {
+        ConsoleSolicitedBuilder::new(self.core.clone(), console_name, key)
+            .build()
+            .await
+    }
+
+    /// # Examples
+    ///
+    /// Issue a command on the default console:
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let console_response = zosmf
+    ///     .console()
+    ///     .issue("defcn", "D A,L")
+    ///     .build()
+    ///     .await?;
+    ///
+    /// if let Some(response) = console_response.response() {
+    ///     println!("{}", response);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Issue a command on a named system and wait for a keyword in the response:
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let console_response = zosmf
+    ///     .console()
+    ///     .issue("ibmusecn", "D IPLINFO")
+    ///     .solicited_key("IEE254I")
+    ///     .system("SY1")
+    ///     .build()
+    ///     .await?;
+    ///
+    /// if console_response.solicited_key_detected() == Some(false) {
+    ///     if let Some(key) = console_response.response_key() {
+    ///         let further = zosmf.console().get_solicited("ibmusecn", key).await?;
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn issue<C, M>(&self, console_name: C, command: M) -> ConsoleIssueBuilder<ConsoleResponse>
+    where
+        C: std::fmt::Display,
+        M: std::fmt::Display,
+    {
+        ConsoleIssueBuilder::new(self.core.clone(), console_name, command)
+    }
+
+    /// Issue the same command on many systems, with at most `concurrency` commands in flight.
+    ///
+    /// Each system is yielded with its own result as soon as its command completes,
+    /// so a system that is down or unreachable does not stop the others.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// use futures_util::StreamExt;
+    ///
+    /// let systems = ["SY1", "SY2", "SY3"];
+    ///
+    /// let mut responses = std::pin::pin!(zosmf.console().issue_to_many("defcn", systems, "D IPLINFO", 2));
+    ///
+    /// while let Some((system, response)) = responses.next().await {
+    ///     match response {
+    ///         Ok(response) => println!("{}: {:?}", system, response.response()),
+    ///         Err(err) => eprintln!("{}: {}", system, err),
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn issue_to_many<C, I, M>(
+        &self,
+        console_name: C,
+        systems: I,
+        command: M,
+        concurrency: usize,
+    ) -> impl Stream<Item = (Arc<str>, Result<ConsoleResponse>)>
+    where
+        C: std::fmt::Display,
+        I: IntoIterator,
+        I::Item: std::fmt::Display,
+        M: std::fmt::Display,
+    {
+        let core = self.core.clone();
+        let console_name = console_name.to_string();
+        let command = command.to_string();
+
+        futures_util::stream::iter(systems)
+            .map(move |system| {
+                let system: Arc<str> = system.to_string().into();
+                let issue = ConsoleIssueBuilder::new(core.clone(), &console_name, &command)
+                    .system(system.clone());
+
+                async move { (system, issue.build().await) }
+            })
+            .buffer_unordered(concurrency.max(1))
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, Getters, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub struct ConsoleResponse {
+    #[serde(default, rename = "cmd-response")]
+    response: Option<Arc<str>>,
+    #[serde(default, rename = "cmd-response-key")]
+    response_key: Option<Arc<str>>,
+    #[serde(default, rename = "cmd-response-url")]
+    response_url: Option<Arc<str>>,
+    #[getter(copy)]
+    #[serde(default, rename = "sol-key-detected")]
+    solicited_key_detected: Option<bool>,
+}
+
+impl TryFromResponse for ConsoleResponse {
+    async fn try_from_response(value: reqwest::Response) -> Result<Self> {
+        Ok(value.json().await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn issue_to_many_continues_after_errors() {
+        let zosmf = crate::ZOsmf::new(reqwest::Client::new(), "http://127.0.0.1:1");
+
+        let mut systems: Vec<Arc<str>> = zosmf
+            .console()
+            .issue_to_many("defcn", ["SY1", "SY2", "SY3"], "D IPLINFO", 2)
+            .map(|(system, response)| {
+                assert!(response.is_err());
+
+                system
+            })
+            .collect()
+            .await;
+        systems.sort_unstable();
+
+        assert_eq!(
+            systems,
+            vec![Arc::from("SY1"), Arc::from("SY2"), Arc::from("SY3")]
+        );
+    }
+
+    #[test]
+    fn deserialize_response() {
+        let response: ConsoleResponse = serde_json::from_str(
+            r#"
+            {
+                "cmd-response-key": "C1977656",
+                "cmd-response-url": "https://test.com/zosmf/restconsoles/consoles/ibmusecn/solmsgs/C1977656",
+                "cmd-response-uri": "/zosmf/restconsoles/consoles/ibmusecn/solmsgs/C1977656",
+                "cmd-response": " IEE254I  11.26.23 IPLINFO DISPLAY 350",
+                "sol-key-detected": true
+            }
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            response.response(),
+            Some(" IEE254I  11.26.23 IPLINFO DISPLAY 350")
+        );
+        assert_eq!(response.response_key(), Some("C1977656"));
+        assert_eq!(response.solicited_key_detected(), Some(true));
+    }
+}
@@ -0,0 +1,91 @@
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use serde::Serialize;
+use z_osmf_macros::Endpoint;
+
+use crate::convert::TryFromResponse;
+use crate::ClientCore;
+
+#[derive(Clone, Debug, Endpoint)]
+#[endpoint(method = put, path = "/zosmf/restconsoles/consoles/{console_name}")]
+pub struct ConsoleIssueBuilder<T>
+where
+    T: TryFromResponse,
+{
+    core: Arc<ClientCore>,
+
+    #[endpoint(path)]
+    console_name: Arc<str>,
+    #[endpoint(builder_fn = build_body)]
+    command: Arc<str>,
+    #[endpoint(skip_builder)]
+    solicited_key: Option<Arc<str>>,
+    #[endpoint(skip_builder)]
+    system: Option<Arc<str>>,
+
+    target_type: PhantomData<T>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "kebab-case")]
+struct RequestJson<'a> {
+    cmd: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sol_key: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    system: Option<&'a str>,
+}
+
+fn build_body<T>(
+    request_builder: reqwest::RequestBuilder,
+    builder: &ConsoleIssueBuilder<T>,
+) -> reqwest::RequestBuilder
+where
+    T: TryFromResponse,
+{
+    request_builder.json(&RequestJson {
+        cmd: &builder.command,
+        sol_key: builder.solicited_key.as_deref(),
+        system: builder.system.as_deref(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{get_zosmf, GetJson};
+
+    #[test]
+    fn maximal_request() {
+        let zosmf = get_zosmf();
+
+        let json: serde_json::Value = serde_json::from_str(
+            r#"
+        {
+            "cmd": "D IPLINFO",
+            "sol-key": "IEE254I",
+            "system": "SY1"
+        }
+        "#,
+        )
+        .unwrap();
+        let manual_request = zosmf
+            .core
+            .client
+            .put("https://test.com/zosmf/restconsoles/consoles/ibmusecn")
+            .json(&json)
+            .build()
+            .unwrap();
+
+        let request = zosmf
+            .console()
+            .issue("ibmusecn", "D IPLINFO")
+            .solicited_key("IEE254I")
+            .system("SY1")
+            .get_request()
+            .unwrap();
+
+        assert_eq!(format!("{:?}", manual_request), format!("{:?}", request));
+        assert_eq!(manual_request.json(), request.json());
+    }
+}
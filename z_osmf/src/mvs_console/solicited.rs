@@ -0,0 +1,23 @@
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use z_osmf_macros::Endpoint;
+
+use crate::convert::TryFromResponse;
+use crate::ClientCore;
+
+#[derive(Clone, Debug, Endpoint)]
+#[endpoint(method = get, path = "/zosmf/restconsoles/consoles/{console_name}/solmsgs/{key}")]
+pub(super) struct ConsoleSolicitedBuilder<T>
+where
+    T: TryFromResponse,
+{
+    core: Arc<ClientCore>,
+
+    #[endpoint(path)]
+    console_name: Arc<str>,
+    #[endpoint(path)]
+    key: Arc<str>,
+
+    target_type: PhantomData<T>,
+}
@@ -0,0 +1,89 @@
+pub mod list;
+
+mod read;
+mod send;
+
+use std::sync::Arc;
+
+use crate::{ClientCore, Result};
+
+use self::list::{NotificationList, NotificationListBuilder};
+use self::read::NotificationReadBuilder;
+use self::send::NotificationSendBuilder;
+
+#[derive(Clone, Debug)]
+pub struct NotificationsClient {
+    core: ClientCore,
+}
+
+/// # Notifications
+impl NotificationsClient {
+    pub(crate) fn new(core: ClientCore) -> Self {
+        NotificationsClient { core }
+    }
+
+    /// # Examples
+    ///
+    /// List the notifications in the user's inbox:
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let notifications = zosmf.notifications().list().await?;
+    ///
+    /// for notification in notifications.items().iter().filter(|n| !n.is_read()) {
+    ///     println!("{:?}: {:?}", notification.title(), notification.body());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list(&self) -> Result<NotificationList> {
+        NotificationListBuilder::new(self.core.clone())
+            .build()
+            .await
+    }
+
+    /// # Examples
+    ///
+    /// Mark a notification as read:
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// zosmf.notifications().mark_read("1526").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn mark_read<I>(&self, id: I) -> Result<()>
+    where
+        I: std::fmt::Display,
+    {
+        NotificationReadBuilder::new(self.core.clone(), id, "read")
+            .build()
+            .await
+    }
+
+    /// # Examples
+    ///
+    /// Send a notification to two users:
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// zosmf
+    ///     .notifications()
+    ///     .send(&["IBMUSER", "ZOSMFAD"], "IPL tonight", "SYSA will be IPLed at 22:00.")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn send<R, T, B>(&self, recipients: &[R], title: T, body: B) -> Result<()>
+    where
+        R: std::fmt::Display,
+        T: std::fmt::Display,
+        B: std::fmt::Display,
+    {
+        let recipients = recipients
+            .iter()
+            .map(|recipient| recipient.to_string().into())
+            .collect::<Arc<[Arc<str>]>>();
+
+        NotificationSendBuilder::new(self.core.clone(), recipients, title, body)
+            .build()
+            .await
+    }
+}
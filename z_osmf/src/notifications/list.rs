@@ -0,0 +1,127 @@
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use z_osmf_macros::{Endpoint, Getters};
+
+use crate::convert::TryFromResponse;
+use crate::{ClientCore, Result};
+
+#[derive(Clone, Debug, Deserialize, Eq, Getters, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub struct Notification {
+    id: Arc<str>,
+    #[serde(default, rename = "subject")]
+    title: Option<Arc<str>>,
+    #[serde(default, rename = "content")]
+    body: Option<Arc<str>>,
+    /// The time the notification was sent, in milliseconds since the Unix epoch.
+    #[getter(copy)]
+    #[serde(default)]
+    time: Option<i64>,
+    #[serde(default)]
+    status: Option<Arc<str>>,
+    #[serde(default, rename = "assigner")]
+    sender: Option<Arc<str>>,
+    #[serde(default)]
+    link: Option<Arc<str>>,
+}
+
+impl Notification {
+    pub fn is_read(&self) -> bool {
+        self.status
+            .as_deref()
+            .is_some_and(|status| status.eq_ignore_ascii_case("read"))
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, Getters, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub struct NotificationList {
+    items: Arc<[Notification]>,
+}
+
+impl TryFromResponse for NotificationList {
+    async fn try_from_response(value: reqwest::Response) -> Result<Self> {
+        let ResponseJson { items } = value.json().await?;
+
+        Ok(NotificationList { items })
+    }
+}
+
+#[derive(Clone, Debug, Endpoint)]
+#[endpoint(method = get, path = "/zosmf/notifications/inbox")]
+pub(super) struct NotificationListBuilder<T>
+where
+    T: TryFromResponse,
+{
+    core: Arc<ClientCore>,
+
+    target_type: PhantomData<T>,
+}
+
+#[derive(Deserialize)]
+struct ResponseJson {
+    #[serde(default)]
+    items: Arc<[Notification]>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::*;
+
+    use super::*;
+
+    #[test]
+    fn list() {
+        let zosmf = get_zosmf();
+
+        let manual_request = zosmf
+            .core
+            .client
+            .get("https://test.com/zosmf/notifications/inbox")
+            .build()
+            .unwrap();
+
+        let request = NotificationListBuilder::<NotificationList>::new(zosmf.core.clone())
+            .get_request()
+            .unwrap();
+
+        assert_eq!(format!("{:?}", manual_request), format!("{:?}", request));
+    }
+
+    #[test]
+    fn deserialize() {
+        let ResponseJson { items } = serde_json::from_str(
+            r#"
+            {
+                "items": [
+                    {
+                        "id": "1526",
+                        "subject": "IPL tonight",
+                        "content": "SYSA will be IPLed at 22:00.",
+                        "time": 1717171717000,
+                        "status": "Unread",
+                        "assigner": "ZOSMFAD",
+                        "link": "https://test.com/zosmf/"
+                    },
+                    {
+                        "id": "1527",
+                        "subject": "Workflow step assigned",
+                        "status": "Read"
+                    }
+                ],
+                "num": 2
+            }
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].title(), Some("IPL tonight"));
+        assert_eq!(items[0].body(), Some("SYSA will be IPLed at 22:00."));
+        assert_eq!(items[0].time(), Some(1717171717000));
+        assert_eq!(items[0].sender(), Some("ZOSMFAD"));
+        assert!(!items[0].is_read());
+        assert!(items[1].is_read());
+        assert_eq!(items[1].body(), None);
+    }
+}
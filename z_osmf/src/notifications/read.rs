@@ -0,0 +1,68 @@
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use serde::Serialize;
+use z_osmf_macros::Endpoint;
+
+use crate::convert::TryFromResponse;
+use crate::ClientCore;
+
+#[derive(Endpoint)]
+#[endpoint(method = put, path = "/zosmf/notifications/inbox/notification/{id}")]
+pub(super) struct NotificationReadBuilder<T>
+where
+    T: TryFromResponse,
+{
+    core: Arc<ClientCore>,
+
+    #[endpoint(path)]
+    id: Arc<str>,
+    #[endpoint(builder_fn = build_body)]
+    status: &'static str,
+
+    target_type: PhantomData<T>,
+}
+
+#[derive(Serialize)]
+struct RequestJson {
+    status: &'static str,
+}
+
+fn build_body<T>(
+    request_builder: reqwest::RequestBuilder,
+    builder: &NotificationReadBuilder<T>,
+) -> reqwest::RequestBuilder
+where
+    T: TryFromResponse,
+{
+    request_builder.json(&RequestJson {
+        status: builder.status,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{get_zosmf, GetJson};
+
+    use super::*;
+
+    #[test]
+    fn mark_read() {
+        let zosmf = get_zosmf();
+
+        let manual_request = zosmf
+            .core
+            .client
+            .put("https://test.com/zosmf/notifications/inbox/notification/1526")
+            .json(&serde_json::json!({ "status": "read" }))
+            .build()
+            .unwrap();
+
+        let request = NotificationReadBuilder::<()>::new(zosmf.core.clone(), "1526", "read")
+            .get_request()
+            .unwrap();
+
+        assert_eq!(format!("{:?}", manual_request), format!("{:?}", request));
+        assert_eq!(manual_request.json(), request.json());
+    }
+}
@@ -0,0 +1,83 @@
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use serde::Serialize;
+use z_osmf_macros::Endpoint;
+
+use crate::convert::TryFromResponse;
+use crate::ClientCore;
+
+#[derive(Endpoint)]
+#[endpoint(method = post, path = "/zosmf/notifications/new")]
+pub(super) struct NotificationSendBuilder<T>
+where
+    T: TryFromResponse,
+{
+    core: Arc<ClientCore>,
+
+    #[endpoint(builder_fn = build_body)]
+    recipients: Arc<[Arc<str>]>,
+    #[endpoint(skip_builder)]
+    title: Arc<str>,
+    #[endpoint(skip_builder)]
+    body: Arc<str>,
+
+    target_type: PhantomData<T>,
+}
+
+#[derive(Serialize)]
+struct RequestJson<'a> {
+    subject: &'a str,
+    content: &'a str,
+    assignees: String,
+}
+
+fn build_body<T>(
+    request_builder: reqwest::RequestBuilder,
+    builder: &NotificationSendBuilder<T>,
+) -> reqwest::RequestBuilder
+where
+    T: TryFromResponse,
+{
+    request_builder.json(&RequestJson {
+        subject: &builder.title,
+        content: &builder.body,
+        assignees: builder.recipients.join(","),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{get_zosmf, GetJson};
+
+    use super::*;
+
+    #[test]
+    fn send() {
+        let zosmf = get_zosmf();
+
+        let manual_request = zosmf
+            .core
+            .client
+            .post("https://test.com/zosmf/notifications/new")
+            .json(&serde_json::json!({
+                "subject": "IPL tonight",
+                "content": "SYSA will be IPLed at 22:00.",
+                "assignees": "IBMUSER,ZOSMFAD"
+            }))
+            .build()
+            .unwrap();
+
+        let request = NotificationSendBuilder::<()>::new(
+            zosmf.core.clone(),
+            ["IBMUSER".into(), "ZOSMFAD".into()],
+            "IPL tonight",
+            "SYSA will be IPLed at 22:00.",
+        )
+        .get_request()
+        .unwrap();
+
+        assert_eq!(format!("{:?}", manual_request), format!("{:?}", request));
+        assert_eq!(manual_request.json(), request.json());
+    }
+}
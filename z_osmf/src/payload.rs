@@ -0,0 +1,87 @@
+use std::sync::Arc;
+
+use bytes::Bytes;
+
+/// A consistent view of the data returned by a read, whether it was read as text or binary.
+///
+/// Implemented for [`DatasetRead`](crate::datasets::read::DatasetRead),
+/// [`FileRead`](crate::files::read::FileRead) and
+/// [`JobFileRead`](crate::jobs::files::read::JobFileRead), and for their data types.
+///
+/// # Example
+/// ```
+/// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+/// use z_osmf::ReadPayload;
+///
+/// let read = zosmf.files().read("/etc/inetd.conf").binary().build().await?;
+///
+/// println!("{} bytes", read.as_bytes().len());
+/// if let Some(text) = read.as_text() {
+///     println!("{}", text);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub trait ReadPayload {
+    /// The data as text, or `None` when it is not valid UTF-8 or was not modified.
+    fn as_text(&self) -> Option<&str>;
+
+    /// The data as bytes, empty when it was not modified.
+    fn as_bytes(&self) -> &[u8];
+}
+
+impl ReadPayload for Arc<str> {
+    fn as_text(&self) -> Option<&str> {
+        Some(self)
+    }
+
+    fn as_bytes(&self) -> &[u8] {
+        str::as_bytes(self)
+    }
+}
+
+impl ReadPayload for Bytes {
+    fn as_text(&self) -> Option<&str> {
+        std::str::from_utf8(self).ok()
+    }
+
+    fn as_bytes(&self) -> &[u8] {
+        self
+    }
+}
+
+impl<T> ReadPayload for Option<T>
+where
+    T: ReadPayload,
+{
+    fn as_text(&self) -> Option<&str> {
+        self.as_ref().and_then(|data| data.as_text())
+    }
+
+    fn as_bytes(&self) -> &[u8] {
+        self.as_ref().map_or(&[], |data| data.as_bytes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn read_payload() {
+        let text: Arc<str> = "//TESTJOB JOB".into();
+        assert_eq!(text.as_text(), Some("//TESTJOB JOB"));
+        assert_eq!(ReadPayload::as_bytes(&text), b"//TESTJOB JOB");
+
+        let binary = Bytes::from_static(&[0xC1, 0xC2]);
+        assert_eq!(binary.as_text(), None);
+        assert_eq!(ReadPayload::as_bytes(&binary), &[0xC1, 0xC2]);
+
+        let not_modified: Option<Bytes> = None;
+        assert_eq!(not_modified.as_text(), None);
+        assert!(not_modified.as_bytes().is_empty());
+
+        let modified = Some(Bytes::from_static(b"data"));
+        assert_eq!(modified.as_text(), Some("data"));
+    }
+}
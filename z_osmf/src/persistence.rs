@@ -0,0 +1,139 @@
+mod delete;
+mod read;
+mod write;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::convert::TryFromResponse;
+use crate::{ClientCore, Result};
+
+use self::delete::PersistenceDeleteBuilder;
+use self::read::PersistenceReadBuilder;
+use self::write::PersistenceWriteBuilder;
+
+#[derive(Clone, Debug)]
+pub struct PersistenceClient {
+    core: ClientCore,
+}
+
+/// # Data Persistence
+///
+/// Store JSON data on the host, scoped to the current user, a plugin ID and a resource path.
+impl PersistenceClient {
+    pub(crate) fn new(core: ClientCore) -> Self {
+        PersistenceClient { core }
+    }
+
+    /// # Examples
+    ///
+    /// Delete stored data:
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// zosmf
+    ///     .persistence()
+    ///     .delete("my-tool", "settings")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn delete<P, R>(&self, plugin_id: P, resource: R) -> Result<()>
+    where
+        P: std::fmt::Display,
+        R: std::fmt::Display,
+    {
+        PersistenceDeleteBuilder::new(self.core.clone(), plugin_id, resource)
+            .build()
+            .await
+    }
+
+    /// # Examples
+    ///
+    /// Read stored data into a typed value:
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// #[derive(serde::Deserialize)]
+    /// struct Settings {
+    ///     last_job: String,
+    /// }
+    ///
+    /// let settings: Settings = zosmf
+    ///     .persistence()
+    ///     .get("my-tool", "settings")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get<T, P, R>(&self, plugin_id: P, resource: R) -> Result<T>
+    where
+        T: DeserializeOwned,
+        P: std::fmt::Display,
+        R: std::fmt::Display,
+    {
+        let Json(value) = PersistenceReadBuilder::new(self.core.clone(), plugin_id, resource)
+            .build()
+            .await?;
+
+        Ok(value)
+    }
+
+    /// # Examples
+    ///
+    /// Read stored data as untyped JSON:
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let settings = zosmf
+    ///     .persistence()
+    ///     .get_value("my-tool", "settings")
+    ///     .await?;
+    ///
+    /// println!("{}", settings["last_job"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_value<P, R>(&self, plugin_id: P, resource: R) -> Result<serde_json::Value>
+    where
+        P: std::fmt::Display,
+        R: std::fmt::Display,
+    {
+        self.get(plugin_id, resource).await
+    }
+
+    /// # Examples
+    ///
+    /// Store a serializable value, replacing any data already stored:
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let settings = serde_json::json!({ "last_job": "JOB00023" });
+    ///
+    /// zosmf
+    ///     .persistence()
+    ///     .put("my-tool", "settings", &settings)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn put<P, R, T>(&self, plugin_id: P, resource: R, value: &T) -> Result<()>
+    where
+        P: std::fmt::Display,
+        R: std::fmt::Display,
+        T: Serialize + ?Sized,
+    {
+        let value = serde_json::to_value(value)?;
+
+        PersistenceWriteBuilder::new(self.core.clone(), plugin_id, resource, value)
+            .build()
+            .await
+    }
+}
+
+struct Json<T>(T);
+
+impl<T> TryFromResponse for Json<T>
+where
+    T: DeserializeOwned,
+{
+    async fn try_from_response(value: reqwest::Response) -> Result<Self> {
+        Ok(Json(value.json().await?))
+    }
+}
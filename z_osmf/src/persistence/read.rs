@@ -0,0 +1,48 @@
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use z_osmf_macros::Endpoint;
+
+use crate::convert::TryFromResponse;
+use crate::ClientCore;
+
+#[derive(Endpoint)]
+#[endpoint(method = get, path = "/zosmf/IzuUICommon/persistence/user/{plugin_id}/{resource}")]
+pub(super) struct PersistenceReadBuilder<T>
+where
+    T: TryFromResponse,
+{
+    core: Arc<ClientCore>,
+
+    #[endpoint(path)]
+    plugin_id: Arc<str>,
+    #[endpoint(path)]
+    resource: Arc<str>,
+
+    target_type: PhantomData<T>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::*;
+
+    use super::*;
+
+    #[test]
+    fn read() {
+        let zosmf = get_zosmf();
+
+        let manual_request = zosmf
+            .core
+            .client
+            .get("https://test.com/zosmf/IzuUICommon/persistence/user/my-tool/settings")
+            .build()
+            .unwrap();
+
+        let request = PersistenceReadBuilder::<()>::new(zosmf.core.clone(), "my-tool", "settings")
+            .get_request()
+            .unwrap();
+
+        assert_eq!(format!("{:?}", manual_request), format!("{:?}", request));
+    }
+}
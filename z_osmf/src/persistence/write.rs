@@ -0,0 +1,69 @@
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use z_osmf_macros::Endpoint;
+
+use crate::convert::TryFromResponse;
+use crate::ClientCore;
+
+#[derive(Endpoint)]
+#[endpoint(method = put, path = "/zosmf/IzuUICommon/persistence/user/{plugin_id}/{resource}")]
+pub(super) struct PersistenceWriteBuilder<T>
+where
+    T: TryFromResponse,
+{
+    core: Arc<ClientCore>,
+
+    #[endpoint(path)]
+    plugin_id: Arc<str>,
+    #[endpoint(path)]
+    resource: Arc<str>,
+    #[endpoint(builder_fn = build_body)]
+    value: serde_json::Value,
+
+    target_type: PhantomData<T>,
+}
+
+fn build_body<T>(
+    request_builder: reqwest::RequestBuilder,
+    builder: &PersistenceWriteBuilder<T>,
+) -> reqwest::RequestBuilder
+where
+    T: TryFromResponse,
+{
+    request_builder.json(&builder.value)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{get_zosmf, GetJson};
+
+    use super::*;
+
+    #[test]
+    fn write() {
+        let zosmf = get_zosmf();
+
+        let value = serde_json::json!({ "last_job": "JOB00023", "favorites": ["SYS1.PROCLIB"] });
+
+        let manual_request = zosmf
+            .core
+            .client
+            .put("https://test.com/zosmf/IzuUICommon/persistence/user/my-tool/settings")
+            .json(&value)
+            .build()
+            .unwrap();
+
+        let request = PersistenceWriteBuilder::<()>::new(
+            zosmf.core.clone(),
+            "my-tool",
+            "settings",
+            value.clone(),
+        )
+        .get_request()
+        .unwrap();
+
+        assert_eq!(format!("{:?}", manual_request), format!("{:?}", request));
+        assert_eq!(manual_request.json(), request.json());
+    }
+}
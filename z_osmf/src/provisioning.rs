@@ -0,0 +1,124 @@
+pub mod instances;
+pub mod templates;
+
+use std::sync::Arc;
+
+use crate::{ClientCore, Result};
+
+use self::instances::{
+    ProvisioningAction, ProvisioningActionBuilder, ProvisioningInstanceList,
+    ProvisioningInstanceListBuilder,
+};
+use self::templates::{
+    ProvisionedInstance, ProvisioningTemplateList, ProvisioningTemplateListBuilder,
+    ProvisioningTemplateRunBuilder,
+};
+
+#[derive(Clone, Debug)]
+pub struct ProvisioningClient {
+    core: ClientCore,
+}
+
+/// # Cloud Provisioning
+impl ProvisioningClient {
+    pub(crate) fn new(core: ClientCore) -> Self {
+        ProvisioningClient { core }
+    }
+
+    /// # Examples
+    ///
+    /// List the provisioned software service instances:
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let instances = zosmf.provisioning().list_instances().await?;
+    ///
+    /// for instance in instances.items().iter() {
+    ///     println!("{} {:?}", instance.object_name(), instance.state());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list_instances(&self) -> Result<ProvisioningInstanceList> {
+        ProvisioningInstanceListBuilder::new(self.core.clone())
+            .build()
+            .await
+    }
+
+    /// # Examples
+    ///
+    /// List the published software service templates:
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let templates = zosmf.provisioning().list_templates().await?;
+    ///
+    /// for template in templates.items().iter() {
+    ///     println!("{} {:?}", template.name(), template.description());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list_templates(&self) -> Result<ProvisioningTemplateList> {
+        ProvisioningTemplateListBuilder::new(self.core.clone())
+            .build()
+            .await
+    }
+
+    /// # Examples
+    ///
+    /// Deprovision a software service instance:
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let action = zosmf
+    ///     .provisioning()
+    ///     .perform_action("1d4d6b3f-ff4f-4b0d-b8e4-4e2d5c7f1f21", "deprovision")
+    ///     .await?;
+    ///
+    /// println!("{}", action.action_id());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn perform_action<I, A>(&self, instance: I, action: A) -> Result<ProvisioningAction>
+    where
+        I: std::fmt::Display,
+        A: std::fmt::Display,
+    {
+        ProvisioningActionBuilder::new(self.core.clone(), instance, action)
+            .build()
+            .await
+    }
+
+    /// # Examples
+    ///
+    /// Provision a CICS region from a template, setting one of its input variables:
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let instance = zosmf
+    ///     .provisioning()
+    ///     .provision("cics_template", [("CICS_APPLID", "CICSTST1")])
+    ///     .await?;
+    ///
+    /// println!("{}", instance.object_id());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn provision<T, I, K, V>(
+        &self,
+        template: T,
+        properties: I,
+    ) -> Result<ProvisionedInstance>
+    where
+        T: std::fmt::Display,
+        I: IntoIterator<Item = (K, V)>,
+        K: std::fmt::Display,
+        V: std::fmt::Display,
+    {
+        let properties = properties
+            .into_iter()
+            .map(|(name, value)| (name.to_string().into(), value.to_string().into()))
+            .collect::<Arc<[(Arc<str>, Arc<str>)]>>();
+
+        ProvisioningTemplateRunBuilder::new(self.core.clone(), template, properties)
+            .build()
+            .await
+    }
+}
@@ -0,0 +1,168 @@
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use z_osmf_macros::{Endpoint, Getters};
+
+use crate::convert::TryFromResponse;
+use crate::{ClientCore, Result};
+
+#[derive(Clone, Debug, Deserialize, Eq, Getters, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct ProvisioningInstance {
+    object_id: Arc<str>,
+    object_name: Arc<str>,
+    #[serde(default)]
+    external_name: Option<Arc<str>>,
+    #[serde(default, rename = "type")]
+    instance_type: Option<Arc<str>>,
+    #[serde(default)]
+    state: Option<Arc<str>>,
+    #[serde(default)]
+    owner: Option<Arc<str>>,
+    #[serde(default)]
+    provider: Option<Arc<str>>,
+    #[serde(default)]
+    system: Option<Arc<str>>,
+    #[serde(default)]
+    sysplex: Option<Arc<str>>,
+    #[serde(default)]
+    description: Option<Arc<str>>,
+    #[serde(default, rename = "catalog-object-name")]
+    template: Option<Arc<str>>,
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, Getters, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub struct ProvisioningInstanceList {
+    items: Arc<[ProvisioningInstance]>,
+}
+
+impl TryFromResponse for ProvisioningInstanceList {
+    async fn try_from_response(value: reqwest::Response) -> Result<Self> {
+        let ListJson { items } = value.json().await?;
+
+        Ok(ProvisioningInstanceList { items })
+    }
+}
+
+/// An action started on a software service instance, like `deprovision`.
+#[derive(Clone, Debug, Deserialize, Eq, Getters, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct ProvisioningAction {
+    action_id: Arc<str>,
+    #[serde(default)]
+    action_uri: Option<Arc<str>>,
+}
+
+impl TryFromResponse for ProvisioningAction {
+    async fn try_from_response(value: reqwest::Response) -> Result<Self> {
+        Ok(value.json().await?)
+    }
+}
+
+#[derive(Clone, Debug, Endpoint)]
+#[endpoint(method = get, path = "/zosmf/provisioning/rest/1.0/scr")]
+pub(super) struct ProvisioningInstanceListBuilder<T>
+where
+    T: TryFromResponse,
+{
+    core: Arc<ClientCore>,
+
+    target_type: PhantomData<T>,
+}
+
+#[derive(Clone, Debug, Endpoint)]
+#[endpoint(method = post, path = "/zosmf/provisioning/rest/1.0/scr/{instance}/actions/{action}")]
+pub(super) struct ProvisioningActionBuilder<T>
+where
+    T: TryFromResponse,
+{
+    core: Arc<ClientCore>,
+
+    #[endpoint(path)]
+    instance: Arc<str>,
+    #[endpoint(path)]
+    action: Arc<str>,
+
+    target_type: PhantomData<T>,
+}
+
+#[derive(Deserialize)]
+struct ListJson {
+    #[serde(rename = "scr-list")]
+    items: Arc<[ProvisioningInstance]>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::*;
+
+    use super::*;
+
+    #[test]
+    fn list_instances() {
+        let zosmf = get_zosmf();
+
+        let manual_request = zosmf
+            .core
+            .client
+            .get("https://test.com/zosmf/provisioning/rest/1.0/scr")
+            .build()
+            .unwrap();
+
+        let request = ProvisioningInstanceListBuilder::<()>::new(zosmf.core.clone())
+            .get_request()
+            .unwrap();
+
+        assert_eq!(format!("{:?}", manual_request), format!("{:?}", request));
+    }
+
+    #[test]
+    fn perform_action() {
+        let zosmf = get_zosmf();
+
+        let manual_request = zosmf
+            .core
+            .client
+            .post("https://test.com/zosmf/provisioning/rest/1.0/scr/1d4d6b3f/actions/deprovision")
+            .build()
+            .unwrap();
+
+        let request =
+            ProvisioningActionBuilder::<()>::new(zosmf.core.clone(), "1d4d6b3f", "deprovision")
+                .get_request()
+                .unwrap();
+
+        assert_eq!(format!("{:?}", manual_request), format!("{:?}", request));
+    }
+
+    #[test]
+    fn deserialize_list() {
+        let ListJson { items } = serde_json::from_str(
+            r#"
+            {
+                "scr-list": [
+                    {
+                        "object-id": "1d4d6b3f",
+                        "object-name": "CICS_CICSTST1",
+                        "external-name": "CICSTST1",
+                        "type": "CICS",
+                        "state": "provisioned",
+                        "owner": "IBMUSER",
+                        "system": "SY1",
+                        "catalog-object-name": "cics_template"
+                    },
+                    {"object-id": "8e0f2a11", "object-name": "DB2_DBT1"}
+                ]
+            }
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].instance_type(), Some("CICS"));
+        assert_eq!(items[0].template(), Some("cics_template"));
+        assert_eq!(items[1].object_name(), "DB2_DBT1");
+        assert_eq!(items[1].state(), None);
+    }
+}
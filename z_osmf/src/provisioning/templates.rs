@@ -0,0 +1,241 @@
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use z_osmf_macros::{Endpoint, Getters};
+
+use crate::convert::TryFromResponse;
+use crate::{ClientCore, Result};
+
+#[derive(Clone, Debug, Deserialize, Eq, Getters, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct ProvisioningTemplate {
+    name: Arc<str>,
+    #[serde(default)]
+    object_id: Option<Arc<str>>,
+    #[serde(default)]
+    version: Option<Arc<str>>,
+    #[serde(default)]
+    owner: Option<Arc<str>>,
+    #[serde(default)]
+    state: Option<Arc<str>>,
+    #[serde(default)]
+    description: Option<Arc<str>>,
+    #[serde(default)]
+    domain_name: Option<Arc<str>>,
+    #[serde(default)]
+    tenant_name: Option<Arc<str>>,
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, Getters, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub struct ProvisioningTemplateList {
+    items: Arc<[ProvisioningTemplate]>,
+}
+
+impl TryFromResponse for ProvisioningTemplateList {
+    async fn try_from_response(value: reqwest::Response) -> Result<Self> {
+        let ListJson { items } = value.json().await?;
+
+        Ok(ProvisioningTemplateList { items })
+    }
+}
+
+/// The software service instance created by running a template.
+#[derive(Clone, Debug, Deserialize, Eq, Getters, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub struct ProvisionedInstance {
+    object_id: Arc<str>,
+    object_name: Arc<str>,
+    object_uri: Option<Arc<str>>,
+    system_nickname: Option<Arc<str>>,
+    workflow_key: Option<Arc<str>>,
+}
+
+impl TryFromResponse for ProvisionedInstance {
+    async fn try_from_response(value: reqwest::Response) -> Result<Self> {
+        let RunJson {
+            registry_info,
+            workflow_info,
+            system_nickname,
+        } = value.json().await?;
+
+        Ok(ProvisionedInstance {
+            object_id: registry_info.object_id,
+            object_name: registry_info.object_name,
+            object_uri: registry_info.object_uri,
+            system_nickname,
+            workflow_key: workflow_info.and_then(|info| info.workflow_key),
+        })
+    }
+}
+
+#[derive(Clone, Debug, Endpoint)]
+#[endpoint(method = get, path = "/zosmf/provisioning/rest/1.0/psc")]
+pub(super) struct ProvisioningTemplateListBuilder<T>
+where
+    T: TryFromResponse,
+{
+    core: Arc<ClientCore>,
+
+    target_type: PhantomData<T>,
+}
+
+#[derive(Clone, Debug, Endpoint)]
+#[endpoint(method = post, path = "/zosmf/provisioning/rest/1.0/psc/{template}/actions/run")]
+pub(super) struct ProvisioningTemplateRunBuilder<T>
+where
+    T: TryFromResponse,
+{
+    core: Arc<ClientCore>,
+
+    #[endpoint(path)]
+    template: Arc<str>,
+    #[endpoint(builder_fn = build_body)]
+    properties: Arc<[(Arc<str>, Arc<str>)]>,
+
+    target_type: PhantomData<T>,
+}
+
+#[derive(Deserialize)]
+struct ListJson {
+    #[serde(rename = "psc-list")]
+    items: Arc<[ProvisioningTemplate]>,
+}
+
+#[derive(Serialize)]
+struct RequestJson<'a> {
+    #[serde(rename = "input-variables")]
+    input_variables: Vec<InputVariable<'a>>,
+}
+
+#[derive(Serialize)]
+struct InputVariable<'a> {
+    name: &'a str,
+    value: &'a str,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct RunJson {
+    registry_info: RegistryInfoJson,
+    #[serde(default)]
+    workflow_info: Option<WorkflowInfoJson>,
+    #[serde(default)]
+    system_nickname: Option<Arc<str>>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct RegistryInfoJson {
+    object_id: Arc<str>,
+    object_name: Arc<str>,
+    #[serde(default)]
+    object_uri: Option<Arc<str>>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct WorkflowInfoJson {
+    #[serde(default)]
+    workflow_key: Option<Arc<str>>,
+}
+
+fn build_body<T>(
+    request_builder: reqwest::RequestBuilder,
+    builder: &ProvisioningTemplateRunBuilder<T>,
+) -> reqwest::RequestBuilder
+where
+    T: TryFromResponse,
+{
+    request_builder.json(&RequestJson {
+        input_variables: builder
+            .properties
+            .iter()
+            .map(|(name, value)| InputVariable { name, value })
+            .collect(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::*;
+
+    use super::*;
+
+    #[test]
+    fn list_templates() {
+        let zosmf = get_zosmf();
+
+        let manual_request = zosmf
+            .core
+            .client
+            .get("https://test.com/zosmf/provisioning/rest/1.0/psc")
+            .build()
+            .unwrap();
+
+        let request = ProvisioningTemplateListBuilder::<()>::new(zosmf.core.clone())
+            .get_request()
+            .unwrap();
+
+        assert_eq!(format!("{:?}", manual_request), format!("{:?}", request));
+    }
+
+    #[test]
+    fn run() {
+        let zosmf = get_zosmf();
+
+        let manual_request = zosmf
+            .core
+            .client
+            .post("https://test.com/zosmf/provisioning/rest/1.0/psc/cics_template/actions/run")
+            .json(&serde_json::json!({
+                "input-variables": [
+                    {"name": "CICS_APPLID", "value": "CICSTST1"}
+                ]
+            }))
+            .build()
+            .unwrap();
+
+        let request = ProvisioningTemplateRunBuilder::<()>::new(
+            zosmf.core.clone(),
+            "cics_template",
+            [("CICS_APPLID".into(), "CICSTST1".into())],
+        )
+        .get_request()
+        .unwrap();
+
+        assert_eq!(format!("{:?}", manual_request), format!("{:?}", request));
+        assert_eq!(manual_request.json(), request.json());
+    }
+
+    #[test]
+    fn deserialize_run() {
+        let RunJson {
+            registry_info,
+            workflow_info,
+            system_nickname,
+        } = serde_json::from_str(
+            r#"
+            {
+                "registry-info": {
+                    "object-name": "CICS_CICSTST1",
+                    "object-id": "1d4d6b3f-ff4f-4b0d-b8e4-4e2d5c7f1f21",
+                    "object-uri": "/zosmf/provisioning/rest/1.0/scr/1d4d6b3f-ff4f-4b0d-b8e4-4e2d5c7f1f21"
+                },
+                "workflow-info": {
+                    "workflowKey": "0c6a7b2e-1f4b-4c4e-9a0e-3b1c9f0d8e55",
+                    "workflowDescription": "Provision a CICS region"
+                },
+                "system-nickname": "SY1"
+            }
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(registry_info.object_name.as_ref(), "CICS_CICSTST1");
+        assert_eq!(
+            workflow_info.and_then(|info| info.workflow_key).as_deref(),
+            Some("0c6a7b2e-1f4b-4c4e-9a0e-3b1c9f0d8e55")
+        );
+        assert_eq!(system_nickname.as_deref(), Some("SY1"));
+    }
+}
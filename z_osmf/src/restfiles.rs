@@ -1,12 +1,18 @@
 //! Functionality shared between the datasets and files modules
 
+#[cfg(feature = "encoding")]
+mod ebcdic;
+
 use std::sync::Arc;
 
 use serde::{Deserialize, Serialize};
 use z_osmf_macros::Getters;
 
 use crate::convert::TryFromResponse;
-use crate::{Error, Result};
+use crate::{ClientCore, Error, Result};
+
+#[cfg(feature = "encoding")]
+pub use self::ebcdic::{ebcdic_to_utf8, utf8_to_ebcdic, EbcdicCodePage};
 
 #[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
 #[serde(rename_all = "lowercase")]
@@ -16,12 +22,28 @@ pub enum CopyDataType {
     Text,
 }
 
+/// The etag of a dataset or file after a request changed it, along with the transaction ID.
+///
+/// The etag can be passed to `if_match` on a later write, to make sure nothing changed in between.
+/// z/OSMF documents the etag for dataset and file writes. Copies and creates, and the
+/// dataset catalog, compress, migrate and uncatalog requests, only populate
+/// [`etag`](Self::etag) when z/OSMF sends one, and otherwise leave it `None`.
 #[derive(Clone, Debug, Deserialize, Eq, Getters, Hash, Ord, PartialEq, PartialOrd, Serialize)]
 pub struct Etag {
     etag: Option<Arc<str>>,
     transaction_id: Arc<str>,
 }
 
+impl Etag {
+    #[cfg(feature = "datasets")]
+    pub(crate) fn new(etag: Option<Arc<str>>, transaction_id: Arc<str>) -> Self {
+        Etag {
+            etag,
+            transaction_id,
+        }
+    }
+}
+
 impl TryFromResponse for Etag {
     async fn try_from_response(value: reqwest::Response) -> Result<Self> {
         let etag = get_etag(&value)?;
@@ -52,6 +74,45 @@ pub(crate) fn get_transaction_id(response: &reqwest::Response) -> Result<Arc<str
         .into())
 }
 
+/// Attach a streamed body of the given `X-IBM-Data-Type` to a write request built without a body.
+pub(crate) fn with_stream_body(
+    mut request: reqwest::Request,
+    data_type: &'static str,
+    body: reqwest::Body,
+) -> reqwest::Request {
+    request.headers_mut().insert(
+        "X-IBM-Data-Type",
+        reqwest::header::HeaderValue::from_static(data_type),
+    );
+    *request.body_mut() = Some(body);
+
+    request
+}
+
+/// Send a write request with a streamed body, which is never retried.
+pub(crate) async fn send_stream(
+    core: &ClientCore,
+    request: reqwest::Request,
+) -> Result<reqwest::Response> {
+    #[cfg(feature = "tracing")]
+    let response = {
+        let span = tracing::info_span!(
+            "body_stream",
+            method = %request.method(),
+            path = request.url().path(),
+            status = tracing::field::Empty,
+            transaction_id = tracing::field::Empty,
+        );
+
+        core.execute_in_span(request, span).await
+    };
+
+    #[cfg(not(feature = "tracing"))]
+    let response = core.execute(request).await;
+
+    response
+}
+
 impl TryFromResponse for String {
     async fn try_from_response(value: reqwest::Response) -> Result<Self> {
         get_transaction_id(&value).map(|v| v.to_string())
@@ -76,6 +137,27 @@ mod tests {
         assert_eq!(get_etag(&response).unwrap(), None);
     }
 
+    #[tokio::test]
+    async fn etag_response() {
+        let response = |etag: Option<&'static str>| {
+            let mut builder = http::Response::builder().header("X-IBM-Txid", "TX0001");
+            if let Some(etag) = etag {
+                builder = builder.header("Etag", etag);
+            }
+
+            reqwest::Response::from(builder.body("").unwrap())
+        };
+
+        let etag = Etag::try_from_response(response(Some("B5C6454F783590AA8EC15BD88E29EA63")))
+            .await
+            .unwrap();
+        assert_eq!(etag.etag(), Some("B5C6454F783590AA8EC15BD88E29EA63"));
+        assert_eq!(etag.transaction_id(), "TX0001");
+
+        let etag = Etag::try_from_response(response(None)).await.unwrap();
+        assert_eq!(etag.etag(), None);
+    }
+
     #[test]
     fn test_get_transaction_id() {
         let response = reqwest::Response::from(
@@ -0,0 +1,523 @@
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use bytes::Bytes;
+use rand::Rng;
+use reqwest::{Method, ResponseBuilderExt, StatusCode};
+use tokio::sync::OwnedSemaphorePermit;
+
+use crate::error::CheckStatus;
+use crate::{ClientCore, Error, InFlightGuard, Result};
+
+/// # RetryPolicy
+///
+/// Controls how failed requests are retried with exponential backoff.
+///
+/// By default, only `GET` requests are retried, up to 3 attempts in total,
+/// on connection errors, timeouts and the `500`, `502`, `503` and `504` status codes.
+///
+/// ```
+/// # async fn example() {
+/// # use std::time::Duration;
+/// # use z_osmf::{RetryPolicy, ZOsmf};
+/// let policy = RetryPolicy::default()
+///     .max_attempts(5)
+///     .base_delay(Duration::from_secs(1));
+///
+/// let zosmf = ZOsmf::new(reqwest::Client::new(), "https://zosmf.mainframe.my-company.com")
+///     .with_retry(policy);
+/// # }
+/// ```
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    base_delay: Duration,
+    jitter: Duration,
+    status_codes: Arc<[StatusCode]>,
+    retry_non_idempotent: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(500),
+            jitter: Duration::from_millis(250),
+            status_codes: [
+                StatusCode::INTERNAL_SERVER_ERROR,
+                StatusCode::BAD_GATEWAY,
+                StatusCode::SERVICE_UNAVAILABLE,
+                StatusCode::GATEWAY_TIMEOUT,
+            ]
+            .into(),
+            retry_non_idempotent: false,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Set the total number of attempts made for a request, including the first.
+    pub fn max_attempts(mut self, value: u32) -> Self {
+        self.max_attempts = value.max(1);
+
+        self
+    }
+
+    /// Set the delay before the first retry. The delay doubles for each further retry.
+    pub fn base_delay(mut self, value: Duration) -> Self {
+        self.base_delay = value;
+
+        self
+    }
+
+    /// Set the upper bound of the random delay added to each backoff.
+    pub fn jitter(mut self, value: Duration) -> Self {
+        self.jitter = value;
+
+        self
+    }
+
+    /// Set the response status codes that trigger a retry.
+    pub fn status_codes<I>(mut self, value: I) -> Self
+    where
+        I: IntoIterator<Item = StatusCode>,
+    {
+        self.status_codes = value.into_iter().collect();
+
+        self
+    }
+
+    /// Allow requests other than `GET`, like job submission or dataset writes, to be retried.
+    ///
+    /// Retrying these requests can repeat an action that z/OSMF already completed.
+    pub fn retry_non_idempotent(mut self, value: bool) -> Self {
+        self.retry_non_idempotent = value;
+
+        self
+    }
+
+    fn allows(&self, method: &Method) -> bool {
+        self.max_attempts > 1 && (self.retry_non_idempotent || method == Method::GET)
+    }
+
+    fn delay(&self, attempt: u32) -> Duration {
+        let backoff = self
+            .base_delay
+            .saturating_mul(2u32.saturating_pow(attempt.saturating_sub(1)));
+
+        let jitter = match self.jitter.as_millis() as u64 {
+            0 => 0,
+            max => rand::thread_rng().gen_range(0..max),
+        };
+
+        backoff.saturating_add(Duration::from_millis(jitter))
+    }
+}
+
+impl ClientCore {
+    /// Send a request and check its status and size, retrying according to the client's retry policy.
+    ///
+    /// The request counts as in flight, and the slot of the client's concurrency limit taken by
+    /// the last attempt stays held, until the body of the returned response has been read or dropped.
+    pub(crate) async fn execute(&self, request: reqwest::Request) -> Result<reqwest::Response> {
+        let in_flight = self.track_request();
+
+        let (response, permit) = self.execute_with_retry(request).await?;
+
+        let response = self.check_size(response)?;
+
+        with_body(response, |inner| ResponseBody {
+            inner,
+            read: 0,
+            max: self.max_response_size,
+            _in_flight: in_flight,
+            _permit: permit,
+        })
+    }
+
+    /// Fail before the body is read when a response is larger than the client's maximum.
+    fn check_size(&self, response: reqwest::Response) -> Result<reqwest::Response> {
+        match (self.max_response_size, response.content_length()) {
+            (Some(max), Some(size)) if size > max => Err(Error::ResponseTooLarge { size, max }),
+            _ => Ok(response),
+        }
+    }
+
+    /// Send a single attempt of a request, taking a slot of the client's concurrency limit,
+    /// so that a request waiting to be retried does not take up a slot.
+    async fn send(
+        &self,
+        request: reqwest::Request,
+    ) -> reqwest::Result<(reqwest::Response, Option<OwnedSemaphorePermit>)> {
+        let permit = self.acquire_permit().await;
+
+        Ok((self.client.execute(request).await?, permit))
+    }
+
+    async fn execute_with_retry(
+        &self,
+        request: reqwest::Request,
+    ) -> Result<(reqwest::Response, Option<OwnedSemaphorePermit>)> {
+        let policy = match &self.retry {
+            Some(policy) if policy.allows(request.method()) => policy,
+            _ => {
+                let (response, permit) = self.send(request).await?;
+
+                return Ok((response.check_status().await?, permit));
+            }
+        };
+
+        let mut request = request;
+        let mut attempt = 1;
+        let mut transaction_ids: Vec<Arc<str>> = Vec::new();
+
+        let (result, retryable) = loop {
+            let retry_request = if attempt < policy.max_attempts {
+                request.try_clone()
+            } else {
+                None
+            };
+
+            let result = self.send(request).await;
+
+            let retryable = match &result {
+                Ok((response, _)) if policy.status_codes.contains(&response.status()) => {
+                    if let Some(transaction_id) = response
+                        .headers()
+                        .get("X-IBM-Txid")
+                        .and_then(|value| value.to_str().ok())
+                    {
+                        transaction_ids.push(transaction_id.into());
+                    }
+
+                    true
+                }
+                Ok(_) => false,
+                Err(err) => err.is_connect() || err.is_timeout(),
+            };
+
+            match retry_request {
+                Some(retry_request) if retryable => {
+                    drop(result);
+                    tokio::time::sleep(policy.delay(attempt)).await;
+
+                    request = retry_request;
+                    attempt += 1;
+                }
+                _ => break (result, retryable),
+            }
+        };
+
+        let result = match result {
+            Ok((response, permit)) => response
+                .check_status()
+                .await
+                .map(|response| (response, permit)),
+            Err(err) => Err(err.into()),
+        };
+
+        match result {
+            Err(source) if attempt > 1 && retryable => Err(Error::Retry {
+                attempts: attempt,
+                transaction_ids: transaction_ids.into(),
+                source: Box::new(source),
+            }),
+            result => result,
+        }
+    }
+}
+
+/// The body of a response, holding on to what its request took from the client until it has been read,
+/// and failing once more than the client's maximum response size has been read.
+struct ResponseBody {
+    inner: reqwest::Body,
+    read: u64,
+    max: Option<u64>,
+    _in_flight: InFlightGuard,
+    _permit: Option<OwnedSemaphorePermit>,
+}
+
+impl http_body::Body for ResponseBody {
+    type Data = Bytes;
+    type Error = Box<dyn std::error::Error + Send + Sync>;
+
+    fn poll_frame(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<std::result::Result<http_body::Frame<Bytes>, Self::Error>>> {
+        let body = self.get_mut();
+
+        let frame = match Pin::new(&mut body.inner).poll_frame(cx) {
+            Poll::Ready(Some(Ok(frame))) => frame,
+            Poll::Ready(Some(Err(err))) => return Poll::Ready(Some(Err(err.into()))),
+            Poll::Ready(None) => return Poll::Ready(None),
+            Poll::Pending => return Poll::Pending,
+        };
+
+        if let Some(data) = frame.data_ref() {
+            body.read = body.read.saturating_add(data.len() as u64);
+
+            if let Some(max) = body.max.filter(|max| body.read > *max) {
+                let size = body.read;
+
+                return Poll::Ready(Some(Err(Box::new(BodyTooLarge { size, max }))));
+            }
+        }
+
+        Poll::Ready(Some(Ok(frame)))
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.inner.is_end_stream()
+    }
+
+    fn size_hint(&self) -> http_body::SizeHint {
+        self.inner.size_hint()
+    }
+}
+
+/// The error of a response body that passed the client's maximum response size,
+/// converted to [`Error::ResponseTooLarge`] when it surfaces as a [`reqwest::Error`].
+#[derive(Debug, thiserror::Error)]
+#[error("response of at least {size} bytes exceeds the maximum of {max} bytes")]
+pub(crate) struct BodyTooLarge {
+    pub size: u64,
+    pub max: u64,
+}
+
+/// Replace the body of a response, keeping its status, headers and URL.
+fn with_body<F>(response: reqwest::Response, f: F) -> Result<reqwest::Response>
+where
+    F: FnOnce(reqwest::Body) -> ResponseBody,
+{
+    let url = response.url().clone();
+    let (parts, body) = http::Response::<reqwest::Body>::from(response).into_parts();
+
+    let mut builder = http::Response::builder()
+        .status(parts.status)
+        .version(parts.version);
+    if let Some(headers) = builder.headers_mut() {
+        *headers = parts.headers;
+    }
+    if let Some(extensions) = builder.extensions_mut() {
+        extensions.extend(parts.extensions);
+    }
+
+    builder
+        .url(url)
+        .body(reqwest::Body::wrap(f(body)))
+        .map(reqwest::Response::from)
+        .map_err(|err| Error::Unexpected(err.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn allows() {
+        let policy = RetryPolicy::default();
+        assert!(policy.allows(&Method::GET));
+        assert!(!policy.allows(&Method::PUT));
+        assert!(!policy.allows(&Method::POST));
+
+        let policy = policy.retry_non_idempotent(true);
+        assert!(policy.allows(&Method::PUT));
+
+        let policy = policy.max_attempts(1);
+        assert!(!policy.allows(&Method::GET));
+    }
+
+    #[test]
+    fn delay() {
+        let policy = RetryPolicy::default()
+            .base_delay(Duration::from_millis(100))
+            .jitter(Duration::ZERO);
+        assert_eq!(policy.delay(1), Duration::from_millis(100));
+        assert_eq!(policy.delay(2), Duration::from_millis(200));
+        assert_eq!(policy.delay(3), Duration::from_millis(400));
+
+        let policy = policy.jitter(Duration::from_millis(50));
+        for attempt in 1..4 {
+            let delay = policy.delay(attempt);
+            let backoff = Duration::from_millis(100 * 2u64.pow(attempt - 1));
+            assert!(delay >= backoff && delay < backoff + Duration::from_millis(50));
+        }
+    }
+
+    #[test]
+    fn check_size() {
+        let zosmf = crate::tests::get_zosmf();
+        let response = || reqwest::Response::from(http::Response::new("0123456789"));
+
+        assert!(zosmf.core.check_size(response()).is_ok());
+
+        let zosmf = zosmf.with_max_response_size(10);
+        assert!(zosmf.core.check_size(response()).is_ok());
+
+        let zosmf = zosmf.with_max_response_size(9);
+        assert!(matches!(
+            zosmf.core.check_size(response()),
+            Err(Error::ResponseTooLarge { size: 10, max: 9 })
+        ));
+    }
+
+    #[tokio::test]
+    async fn body_too_large() {
+        let zosmf = crate::tests::get_zosmf();
+        let response = |max| {
+            let chunks: Vec<std::result::Result<&str, std::io::Error>> =
+                vec![Ok("01234"), Ok("56789")];
+            let response = reqwest::Response::from(http::Response::new(
+                reqwest::Body::wrap_stream(futures_util::stream::iter(chunks)),
+            ));
+            assert_eq!(response.content_length(), None);
+
+            with_body(response, |inner| ResponseBody {
+                inner,
+                read: 0,
+                max,
+                _in_flight: zosmf.core.track_request(),
+                _permit: None,
+            })
+            .unwrap()
+        };
+
+        assert_eq!(response(Some(10)).text().await.unwrap(), "0123456789");
+        assert!(matches!(
+            response(Some(9)).bytes().await.map_err(Error::from),
+            Err(Error::ResponseTooLarge { size: 10, max: 9 })
+        ));
+        assert!(matches!(
+            response(Some(4)).text().await.map_err(Error::from),
+            Err(Error::ResponseTooLarge { size: 5, max: 4 })
+        ));
+    }
+
+    #[tokio::test]
+    async fn permit_released_between_attempts() {
+        let zosmf = crate::tests::get_zosmf()
+            .with_max_concurrency(1)
+            .with_retry(
+                RetryPolicy::default()
+                    .max_attempts(2)
+                    .base_delay(Duration::from_secs(1))
+                    .jitter(Duration::ZERO),
+            );
+        let request = zosmf
+            .core
+            .client
+            .get("http://127.0.0.1:1/zosmf/restfiles/ds/SYS1.PROCLIB")
+            .build()
+            .unwrap();
+
+        let core = zosmf.core.clone();
+        let retrying = tokio::spawn(async move { core.execute(request).await });
+        tokio::time::sleep(Duration::from_millis(200)).await;
+
+        assert!(
+            tokio::time::timeout(Duration::from_millis(200), zosmf.core.acquire_permit())
+                .await
+                .is_ok_and(|permit| permit.is_some())
+        );
+        assert!(matches!(
+            retrying.await.unwrap(),
+            Err(Error::Retry { attempts: 2, .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn permit_held_until_body_read() {
+        let (url, _) = crate::tests::serve(&[(200, "{}")], None);
+        let zosmf = crate::ZOsmf::new(reqwest::Client::new(), &url).with_max_concurrency(1);
+        let request = zosmf
+            .core
+            .client
+            .get(format!("{}/zosmf/info", url))
+            .build()
+            .unwrap();
+
+        let response = zosmf.core.execute(request).await.unwrap();
+        assert!(
+            tokio::time::timeout(Duration::from_millis(50), zosmf.core.acquire_permit())
+                .await
+                .is_err()
+        );
+
+        assert_eq!(response.text().await.unwrap(), "{}");
+        assert!(zosmf.core.acquire_permit().await.is_some());
+    }
+
+    #[tokio::test]
+    async fn no_retry_for_put() {
+        let zosmf = crate::tests::get_zosmf().with_retry(RetryPolicy::default());
+        let request = zosmf
+            .core
+            .client
+            .put("http://127.0.0.1:1/zosmf/restfiles/ds/SYS1.PROCLIB")
+            .build()
+            .unwrap();
+
+        assert!(matches!(
+            zosmf.core.execute(request).await,
+            Err(Error::Reqwest(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn no_retry_error_for_final_404() {
+        let (url, _) = crate::tests::serve(
+            &[
+                (503, "Service Unavailable"),
+                (
+                    404,
+                    r#"{"category":1,"rc":4,"reason":8,"message":"not found"}"#,
+                ),
+            ],
+            None,
+        );
+        let zosmf = crate::ZOsmf::new(reqwest::Client::new(), &url).with_retry(
+            RetryPolicy::default()
+                .base_delay(Duration::from_millis(1))
+                .jitter(Duration::ZERO),
+        );
+        let request = zosmf
+            .core
+            .client
+            .get(format!("{}/zosmf/restfiles/fs/u/jiahj/missing.txt", url))
+            .build()
+            .unwrap();
+
+        match zosmf.core.execute(request).await {
+            Err(Error::ZOsmf(err)) => assert_eq!(err.status(), StatusCode::NOT_FOUND),
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn retry_connect_error() {
+        let zosmf = crate::tests::get_zosmf().with_retry(
+            RetryPolicy::default()
+                .base_delay(Duration::from_millis(1))
+                .jitter(Duration::ZERO),
+        );
+        let request = zosmf
+            .core
+            .client
+            .get("http://127.0.0.1:1/zosmf/restfiles/ds?dslevel=SYS1")
+            .build()
+            .unwrap();
+
+        match zosmf.core.execute(request).await {
+            Err(Error::Retry {
+                attempts, source, ..
+            }) => {
+                assert_eq!(attempts, 3);
+                assert!(matches!(*source, Error::Reqwest(_)));
+            }
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+}
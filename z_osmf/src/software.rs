@@ -0,0 +1,92 @@
+pub mod instances;
+pub mod products;
+
+use crate::{ClientCore, Result};
+
+use self::instances::{
+    SoftwareInstance, SoftwareInstanceBuilder, SoftwareInstanceList, SoftwareInstanceListBuilder,
+};
+use self::products::{SoftwareProductList, SoftwareProductListBuilder};
+
+#[derive(Clone, Debug)]
+pub struct SoftwareClient {
+    core: ClientCore,
+}
+
+/// # Software Management
+impl SoftwareClient {
+    pub(crate) fn new(core: ClientCore) -> Self {
+        SoftwareClient { core }
+    }
+
+    /// # Examples
+    ///
+    /// Get the properties of a software instance:
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let instance = zosmf
+    ///     .software()
+    ///     .get_instance("ZOS25", "LOCAL")
+    ///     .await?;
+    ///
+    /// for zone in instance.target_zones().iter() {
+    ///     println!("{}", zone);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_instance<N, S>(&self, name: N, system: S) -> Result<SoftwareInstance>
+    where
+        N: std::fmt::Display,
+        S: std::fmt::Display,
+    {
+        SoftwareInstanceBuilder::new(self.core.clone(), name, system)
+            .build()
+            .await
+    }
+
+    /// # Examples
+    ///
+    /// List every software instance defined to z/OSMF:
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let instances = zosmf.software().list_instances().await?;
+    ///
+    /// for instance in instances.items().iter() {
+    ///     println!("{} on {}", instance.name(), instance.system());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list_instances(&self) -> Result<SoftwareInstanceList> {
+        SoftwareInstanceListBuilder::new(self.core.clone())
+            .build()
+            .await
+    }
+
+    /// # Examples
+    ///
+    /// List the products installed in a software instance:
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let products = zosmf
+    ///     .software()
+    ///     .list_products("ZOS25", "LOCAL")
+    ///     .await?;
+    ///
+    /// for product in products.items().iter() {
+    ///     println!("{:?} {:?}", product.name(), product.release());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list_products<N, S>(&self, name: N, system: S) -> Result<SoftwareProductList>
+    where
+        N: std::fmt::Display,
+        S: std::fmt::Display,
+    {
+        SoftwareProductListBuilder::new(self.core.clone(), name, system)
+            .build()
+            .await
+    }
+}
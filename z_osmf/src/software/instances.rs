@@ -0,0 +1,160 @@
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use z_osmf_macros::{Endpoint, Getters};
+
+use crate::convert::TryFromResponse;
+use crate::{ClientCore, Result};
+
+#[derive(Clone, Debug, Deserialize, Eq, Getters, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub struct SoftwareInstance {
+    name: Arc<str>,
+    system: Arc<str>,
+    #[serde(default)]
+    description: Option<Arc<str>>,
+    #[serde(default, rename = "globalzone")]
+    global_zone: Option<Arc<str>>,
+    #[serde(default, rename = "targetzones")]
+    target_zones: Arc<[Arc<str>]>,
+    #[serde(default)]
+    categories: Arc<[Arc<str>]>,
+    #[serde(default, rename = "swiurl")]
+    url: Option<Arc<str>>,
+    #[serde(default)]
+    created: Option<Arc<str>>,
+    #[serde(default, rename = "createdby")]
+    created_by: Option<Arc<str>>,
+    #[serde(default)]
+    modified: Option<Arc<str>>,
+    #[serde(default, rename = "modifiedby")]
+    modified_by: Option<Arc<str>>,
+}
+
+impl TryFromResponse for SoftwareInstance {
+    async fn try_from_response(value: reqwest::Response) -> Result<Self> {
+        Ok(value.json().await?)
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, Getters, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub struct SoftwareInstanceList {
+    items: Arc<[SoftwareInstance]>,
+}
+
+impl TryFromResponse for SoftwareInstanceList {
+    async fn try_from_response(value: reqwest::Response) -> Result<Self> {
+        let ResponseJson { items } = value.json().await?;
+
+        Ok(SoftwareInstanceList { items })
+    }
+}
+
+#[derive(Clone, Debug, Endpoint)]
+#[endpoint(method = get, path = "/zosmf/swmgmt/swi/{system}/{name}")]
+pub(super) struct SoftwareInstanceBuilder<T>
+where
+    T: TryFromResponse,
+{
+    core: Arc<ClientCore>,
+
+    #[endpoint(path)]
+    name: Arc<str>,
+    #[endpoint(path)]
+    system: Arc<str>,
+
+    target_type: PhantomData<T>,
+}
+
+#[derive(Clone, Debug, Endpoint)]
+#[endpoint(method = get, path = "/zosmf/swmgmt/swi")]
+pub(super) struct SoftwareInstanceListBuilder<T>
+where
+    T: TryFromResponse,
+{
+    core: Arc<ClientCore>,
+
+    target_type: PhantomData<T>,
+}
+
+#[derive(Deserialize)]
+struct ResponseJson {
+    #[serde(rename = "swilist")]
+    items: Arc<[SoftwareInstance]>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::*;
+
+    use super::*;
+
+    #[test]
+    fn get_instance() {
+        let zosmf = get_zosmf();
+
+        let manual_request = zosmf
+            .core
+            .client
+            .get("https://test.com/zosmf/swmgmt/swi/LOCAL/ZOS25")
+            .build()
+            .unwrap();
+
+        let request =
+            SoftwareInstanceBuilder::<SoftwareInstance>::new(zosmf.core.clone(), "ZOS25", "LOCAL")
+                .get_request()
+                .unwrap();
+
+        assert_eq!(format!("{:?}", manual_request), format!("{:?}", request));
+    }
+
+    #[test]
+    fn list_instances() {
+        let zosmf = get_zosmf();
+
+        let manual_request = zosmf
+            .core
+            .client
+            .get("https://test.com/zosmf/swmgmt/swi")
+            .build()
+            .unwrap();
+
+        let request = SoftwareInstanceListBuilder::<SoftwareInstanceList>::new(zosmf.core.clone())
+            .get_request()
+            .unwrap();
+
+        assert_eq!(format!("{:?}", manual_request), format!("{:?}", request));
+    }
+
+    #[test]
+    fn deserialize_list() {
+        let ResponseJson { items } = serde_json::from_str(
+            r#"
+            {
+                "swilist": [
+                    {
+                        "name": "ZOS25",
+                        "system": "LOCAL",
+                        "description": "z/OS V2R5 target",
+                        "globalzone": "ZOS25.GLOBAL.CSI",
+                        "targetzones": ["MVST100"],
+                        "categories": ["production"],
+                        "swiurl": "https://test.com/zosmf/swmgmt/swi/LOCAL/ZOS25",
+                        "created": "2024-02-01T10:00:00Z",
+                        "createdby": "IBMUSER"
+                    },
+                    {"name": "CICS61", "system": "SY2"}
+                ]
+            }
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].global_zone(), Some("ZOS25.GLOBAL.CSI"));
+        assert_eq!(items[0].target_zones(), &["MVST100".into()][..]);
+        assert_eq!(items[0].created_by(), Some("IBMUSER"));
+        assert_eq!(items[1].system(), "SY2");
+        assert!(items[1].categories().is_empty());
+    }
+}
@@ -0,0 +1,113 @@
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use z_osmf_macros::{Endpoint, Getters};
+
+use crate::convert::TryFromResponse;
+use crate::{ClientCore, Result};
+
+#[derive(Clone, Debug, Deserialize, Eq, Getters, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub struct SoftwareProduct {
+    #[serde(default, rename = "prodname")]
+    name: Option<Arc<str>>,
+    #[serde(default, rename = "prodid")]
+    id: Option<Arc<str>>,
+    #[serde(default)]
+    release: Option<Arc<str>>,
+    #[serde(default)]
+    vendor: Option<Arc<str>>,
+    #[serde(default, rename = "generalavailability")]
+    general_availability: Option<Arc<str>>,
+    #[serde(default, rename = "endofservice")]
+    end_of_service: Option<Arc<str>>,
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, Getters, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub struct SoftwareProductList {
+    items: Arc<[SoftwareProduct]>,
+}
+
+impl TryFromResponse for SoftwareProductList {
+    async fn try_from_response(value: reqwest::Response) -> Result<Self> {
+        let ResponseJson { items } = value.json().await?;
+
+        Ok(SoftwareProductList { items })
+    }
+}
+
+#[derive(Clone, Debug, Endpoint)]
+#[endpoint(method = get, path = "/zosmf/swmgmt/swi/{system}/{name}/products")]
+pub(super) struct SoftwareProductListBuilder<T>
+where
+    T: TryFromResponse,
+{
+    core: Arc<ClientCore>,
+
+    #[endpoint(path)]
+    name: Arc<str>,
+    #[endpoint(path)]
+    system: Arc<str>,
+
+    target_type: PhantomData<T>,
+}
+
+#[derive(Deserialize)]
+struct ResponseJson {
+    #[serde(rename = "products")]
+    items: Arc<[SoftwareProduct]>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::*;
+
+    use super::*;
+
+    #[test]
+    fn list_products() {
+        let zosmf = get_zosmf();
+
+        let manual_request = zosmf
+            .core
+            .client
+            .get("https://test.com/zosmf/swmgmt/swi/LOCAL/ZOS25/products")
+            .build()
+            .unwrap();
+
+        let request = SoftwareProductListBuilder::<SoftwareProductList>::new(
+            zosmf.core.clone(),
+            "ZOS25",
+            "LOCAL",
+        )
+        .get_request()
+        .unwrap();
+
+        assert_eq!(format!("{:?}", manual_request), format!("{:?}", request));
+    }
+
+    #[test]
+    fn deserialize_list() {
+        let ResponseJson { items } = serde_json::from_str(
+            r#"
+            {
+                "products": [
+                    {
+                        "prodname": "z/OS",
+                        "prodid": "5650-ZOS",
+                        "release": "02.05.00",
+                        "vendor": "IBM",
+                        "generalavailability": "2021-09-30",
+                        "endofservice": "2026-09-30"
+                    }
+                ]
+            }
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(items[0].name(), Some("z/OS"));
+        assert_eq!(items[0].id(), Some("5650-ZOS"));
+        assert_eq!(items[0].end_of_service(), Some("2026-09-30"));
+    }
+}
@@ -64,3 +64,46 @@ where
         system_variable_list: &builder.new_variables,
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{get_zosmf, GetJson};
+
+    use super::*;
+
+    #[test]
+    fn create() {
+        let zosmf = get_zosmf();
+
+        let json = r#"
+        {
+            "system-variable-list": [
+                {"name": "var1", "value": "value1", "description": "first"},
+                {"name": "var2", "value": "value2", "description": "second"}
+            ]
+        }
+        "#;
+        let manual_request = zosmf
+            .core
+            .client
+            .post("https://test.com/zosmf/variables/rest/1.0/systems/TESTPLEX.TESTNODE")
+            .json(&serde_json::from_str::<serde_json::Value>(json).unwrap())
+            .build()
+            .unwrap();
+
+        let request = VariableCreateBuilder::<()>::new(
+            zosmf.core.clone(),
+            "TESTPLEX",
+            "TESTNODE",
+            [
+                NewSystemVariable::new("var1", "value1", "first"),
+                NewSystemVariable::new("var2", "value2", "second"),
+            ],
+        )
+        .get_request()
+        .unwrap();
+
+        assert_eq!(format!("{:?}", manual_request), format!("{:?}", request));
+        assert_eq!(manual_request.json(), request.json());
+    }
+}
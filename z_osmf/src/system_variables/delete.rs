@@ -33,3 +33,35 @@ where
 {
     request_builder.json(&builder.variable_names)
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{get_zosmf, GetJson};
+
+    use super::*;
+
+    #[test]
+    fn delete() {
+        let zosmf = get_zosmf();
+
+        let manual_request = zosmf
+            .core
+            .client
+            .delete("https://test.com/zosmf/variables/rest/1.0/systems/TESTPLEX.TESTNODE")
+            .json(&serde_json::json!(["var1", "var2"]))
+            .build()
+            .unwrap();
+
+        let request = VariableDeleteBuilder::<()>::new(
+            zosmf.core.clone(),
+            "TESTPLEX",
+            "TESTNODE",
+            ["var1".to_string(), "var2".to_string()],
+        )
+        .get_request()
+        .unwrap();
+
+        assert_eq!(format!("{:?}", manual_request), format!("{:?}", request));
+        assert_eq!(manual_request.json(), request.json());
+    }
+}
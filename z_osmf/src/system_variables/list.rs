@@ -134,6 +134,7 @@ where
     let query: Arc<[_]> = builder
         .names
         .iter()
+        .flatten()
         .map(|name| ("var-name", name))
         .collect();
 
@@ -146,3 +147,74 @@ where
 {
     builder.system_id.as_ref().unwrap_or(&SystemId::Local)
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::*;
+
+    use super::*;
+
+    #[test]
+    fn local() {
+        let zosmf = get_zosmf();
+
+        let manual_request = zosmf
+            .core
+            .client
+            .get("https://test.com/zosmf/variables/rest/1.0/systems/local")
+            .build()
+            .unwrap();
+
+        let request = zosmf.system_variables().list().get_request().unwrap();
+
+        assert_eq!(format!("{:?}", manual_request), format!("{:?}", request));
+    }
+
+    #[test]
+    fn named_with_names() {
+        let zosmf = get_zosmf();
+
+        let manual_request = zosmf
+            .core
+            .client
+            .get("https://test.com/zosmf/variables/rest/1.0/systems/TESTPLEX.TESTNODE")
+            .query(&[
+                ("var-name", "var1"),
+                ("var-name", "var2"),
+                ("var-name", "var3"),
+            ])
+            .build()
+            .unwrap();
+
+        let request = zosmf
+            .system_variables()
+            .list()
+            .system_id(SystemId::named("TESTPLEX", "TESTNODE"))
+            .name("var1")
+            .names(&["var2", "var3"])
+            .get_request()
+            .unwrap();
+
+        assert_eq!(format!("{:?}", manual_request), format!("{:?}", request));
+    }
+
+    #[test]
+    fn deserialize_list() {
+        let ResponseJson { variables } = serde_json::from_str(
+            r#"
+            {
+                "system-variable-list": [
+                    {"name": "var1", "value": "value1", "description": "first"},
+                    {"name": "var2", "value": "value2"}
+                ]
+            }
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(variables.len(), 2);
+        assert_eq!(variables[0].description(), Some("first"));
+        assert_eq!(variables[1].value(), "value2");
+        assert_eq!(variables[1].description(), None);
+    }
+}
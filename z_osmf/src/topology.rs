@@ -0,0 +1,78 @@
+pub mod groups;
+pub mod sysplexes;
+pub mod systems;
+
+use crate::{ClientCore, Result};
+
+use self::groups::{TopologyGroupList, TopologyGroupListBuilder};
+use self::sysplexes::{TopologySysplexList, TopologySysplexListBuilder};
+use self::systems::{TopologySystemList, TopologySystemListBuilder};
+
+#[derive(Clone, Debug)]
+pub struct TopologyClient {
+    core: ClientCore,
+}
+
+/// # Topology
+impl TopologyClient {
+    pub(crate) fn new(core: ClientCore) -> Self {
+        TopologyClient { core }
+    }
+
+    /// # Examples
+    ///
+    /// List the groups of systems defined to z/OSMF:
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let groups = zosmf.topology().list_groups().await?;
+    ///
+    /// for group in groups.items().iter() {
+    ///     println!("{} {:?}", group.name(), group.description());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list_groups(&self) -> Result<TopologyGroupList> {
+        TopologyGroupListBuilder::new(self.core.clone())
+            .build()
+            .await
+    }
+
+    /// # Examples
+    ///
+    /// List the sysplexes defined to z/OSMF:
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let sysplexes = zosmf.topology().list_sysplexes().await?;
+    ///
+    /// for sysplex in sysplexes.items().iter() {
+    ///     println!("{} {:?}", sysplex.name(), sysplex.description());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list_sysplexes(&self) -> Result<TopologySysplexList> {
+        TopologySysplexListBuilder::new(self.core.clone())
+            .build()
+            .await
+    }
+
+    /// # Examples
+    ///
+    /// List the systems defined to z/OSMF:
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let systems = zosmf.topology().list_systems().await?;
+    ///
+    /// for system in systems.items().iter() {
+    ///     println!("{} {:?} {:?}", system.nickname(), system.sysplex(), system.url());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list_systems(&self) -> Result<TopologySystemList> {
+        TopologySystemListBuilder::new(self.core.clone())
+            .build()
+            .await
+    }
+}
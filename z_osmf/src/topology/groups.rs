@@ -0,0 +1,75 @@
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use z_osmf_macros::{Endpoint, Getters};
+
+use crate::convert::TryFromResponse;
+use crate::{ClientCore, Result};
+
+#[derive(Clone, Debug, Deserialize, Eq, Getters, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TopologyGroup {
+    #[serde(rename = "groupName")]
+    name: Arc<str>,
+    #[serde(default)]
+    description: Option<Arc<str>>,
+    #[serde(default)]
+    status: Option<Arc<str>>,
+    #[serde(default)]
+    url: Option<Arc<str>>,
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, Getters, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub struct TopologyGroupList {
+    items: Arc<[TopologyGroup]>,
+}
+
+impl TryFromResponse for TopologyGroupList {
+    async fn try_from_response(value: reqwest::Response) -> Result<Self> {
+        let ListJson { items } = value.json().await?;
+
+        Ok(TopologyGroupList { items })
+    }
+}
+
+#[derive(Clone, Debug, Endpoint)]
+#[endpoint(method = get, path = "/zosmf/resttopology/groups")]
+pub(super) struct TopologyGroupListBuilder<T>
+where
+    T: TryFromResponse,
+{
+    core: Arc<ClientCore>,
+
+    target_type: PhantomData<T>,
+}
+
+#[derive(Deserialize)]
+struct ListJson {
+    items: Arc<[TopologyGroup]>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::*;
+
+    use super::*;
+
+    #[test]
+    fn list_groups() {
+        let zosmf = get_zosmf();
+
+        let manual_request = zosmf
+            .core
+            .client
+            .get("https://test.com/zosmf/resttopology/groups")
+            .build()
+            .unwrap();
+
+        let request = TopologyGroupListBuilder::<()>::new(zosmf.core.clone())
+            .get_request()
+            .unwrap();
+
+        assert_eq!(format!("{:?}", manual_request), format!("{:?}", request));
+    }
+}
@@ -0,0 +1,94 @@
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use z_osmf_macros::{Endpoint, Getters};
+
+use crate::convert::TryFromResponse;
+use crate::{ClientCore, Result};
+
+#[derive(Clone, Debug, Deserialize, Eq, Getters, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TopologySysplex {
+    #[serde(rename = "sysplexName")]
+    name: Arc<str>,
+    #[serde(default)]
+    description: Option<Arc<str>>,
+    #[serde(default)]
+    status: Option<Arc<str>>,
+    #[serde(default)]
+    url: Option<Arc<str>>,
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, Getters, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub struct TopologySysplexList {
+    items: Arc<[TopologySysplex]>,
+}
+
+impl TryFromResponse for TopologySysplexList {
+    async fn try_from_response(value: reqwest::Response) -> Result<Self> {
+        let ListJson { items } = value.json().await?;
+
+        Ok(TopologySysplexList { items })
+    }
+}
+
+#[derive(Clone, Debug, Endpoint)]
+#[endpoint(method = get, path = "/zosmf/resttopology/sysplexes")]
+pub(super) struct TopologySysplexListBuilder<T>
+where
+    T: TryFromResponse,
+{
+    core: Arc<ClientCore>,
+
+    target_type: PhantomData<T>,
+}
+
+#[derive(Deserialize)]
+struct ListJson {
+    items: Arc<[TopologySysplex]>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::*;
+
+    use super::*;
+
+    #[test]
+    fn list_sysplexes() {
+        let zosmf = get_zosmf();
+
+        let manual_request = zosmf
+            .core
+            .client
+            .get("https://test.com/zosmf/resttopology/sysplexes")
+            .build()
+            .unwrap();
+
+        let request = TopologySysplexListBuilder::<()>::new(zosmf.core.clone())
+            .get_request()
+            .unwrap();
+
+        assert_eq!(format!("{:?}", manual_request), format!("{:?}", request));
+    }
+
+    #[test]
+    fn deserialize_list() {
+        let ListJson { items } = serde_json::from_str(
+            r#"
+            {
+                "items": [
+                    {"sysplexName": "PLEX1", "description": "Production sysplex"},
+                    {"sysplexName": "PLEX2"}
+                ]
+            }
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].description(), Some("Production sysplex"));
+        assert_eq!(items[1].name(), "PLEX2");
+    }
+}
@@ -0,0 +1,121 @@
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use z_osmf_macros::{Endpoint, Getters};
+
+use crate::convert::TryFromResponse;
+use crate::{ClientCore, Result};
+
+/// A system defined to z/OSMF, along with the z/OSMF instance that serves it.
+#[derive(Clone, Debug, Deserialize, Eq, Getters, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TopologySystem {
+    #[serde(rename = "systemNickName")]
+    nickname: Arc<str>,
+    #[serde(default, rename = "systemName")]
+    name: Option<Arc<str>>,
+    #[serde(default, rename = "sysplexName")]
+    sysplex: Option<Arc<str>>,
+    #[serde(default)]
+    group_names: Option<Arc<str>>,
+    #[serde(default)]
+    status: Option<Arc<str>>,
+    #[serde(default)]
+    url: Option<Arc<str>>,
+    #[serde(default, rename = "zosVR")]
+    zos_version: Option<Arc<str>>,
+    #[serde(default)]
+    jes_type: Option<Arc<str>>,
+    #[serde(default)]
+    cpc_name: Option<Arc<str>>,
+    #[serde(default)]
+    cpc_serial: Option<Arc<str>>,
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, Getters, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub struct TopologySystemList {
+    items: Arc<[TopologySystem]>,
+}
+
+impl TryFromResponse for TopologySystemList {
+    async fn try_from_response(value: reqwest::Response) -> Result<Self> {
+        let ListJson { items } = value.json().await?;
+
+        Ok(TopologySystemList { items })
+    }
+}
+
+#[derive(Clone, Debug, Endpoint)]
+#[endpoint(method = get, path = "/zosmf/resttopology/systems")]
+pub(super) struct TopologySystemListBuilder<T>
+where
+    T: TryFromResponse,
+{
+    core: Arc<ClientCore>,
+
+    target_type: PhantomData<T>,
+}
+
+#[derive(Deserialize)]
+struct ListJson {
+    items: Arc<[TopologySystem]>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::*;
+
+    use super::*;
+
+    #[test]
+    fn list_systems() {
+        let zosmf = get_zosmf();
+
+        let manual_request = zosmf
+            .core
+            .client
+            .get("https://test.com/zosmf/resttopology/systems")
+            .build()
+            .unwrap();
+
+        let request = TopologySystemListBuilder::<()>::new(zosmf.core.clone())
+            .get_request()
+            .unwrap();
+
+        assert_eq!(format!("{:?}", manual_request), format!("{:?}", request));
+    }
+
+    #[test]
+    fn deserialize_list() {
+        let ListJson { items } = serde_json::from_str(
+            r#"
+            {
+                "numRows": 2,
+                "items": [
+                    {
+                        "systemNickName": "SY1",
+                        "systemName": "SY1",
+                        "sysplexName": "PLEX1",
+                        "groupNames": "PROD",
+                        "zosVR": "z/OS 03.01.00",
+                        "jesType": "JES2",
+                        "cpcName": "CPC1",
+                        "cpcSerial": "0A1B2",
+                        "url": "https://sy1.my-company.com/zosmf/"
+                    },
+                    {"systemNickName": "SY2", "sysplexName": "PLEX1", "status": "inactive"}
+                ]
+            }
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].sysplex(), Some("PLEX1"));
+        assert_eq!(items[0].url(), Some("https://sy1.my-company.com/zosmf/"));
+        assert_eq!(items[1].nickname(), "SY2");
+        assert_eq!(items[1].status(), Some("inactive"));
+        assert_eq!(items[1].url(), None);
+    }
+}
@@ -0,0 +1,87 @@
+use std::sync::Arc;
+
+use crate::convert::TryFromResponse;
+use crate::Result;
+
+/// # Transacted
+///
+/// A result paired with the z/OSMF transaction ID of the request that produced it,
+/// returned by the `build_transacted` method of every request builder.
+///
+/// The transaction ID identifies the request in the z/OSMF server logs,
+/// which makes it useful when reporting a problem. Not every z/OSMF service returns one.
+///
+/// ```
+/// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+/// let jobs = zosmf.jobs().list().owner("IBMUSER").build_transacted().await?;
+///
+/// println!("transaction: {:?}", jobs.transaction_id());
+/// for job in jobs.inner().items().iter() {
+///     println!("{}", job.name());
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Transacted<T> {
+    inner: T,
+    transaction_id: Option<Arc<str>>,
+}
+
+impl<T> Transacted<T> {
+    pub fn inner(&self) -> &T {
+        &self.inner
+    }
+
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+
+    pub fn transaction_id(&self) -> Option<&str> {
+        self.transaction_id.as_deref()
+    }
+}
+
+impl<T> TryFromResponse for Transacted<T>
+where
+    T: TryFromResponse,
+{
+    async fn try_from_response(value: reqwest::Response) -> Result<Self> {
+        let transaction_id = value
+            .headers()
+            .get("X-IBM-Txid")
+            .map(|value| value.to_str())
+            .transpose()?
+            .map(|value| value.into());
+
+        let inner = T::try_from_response(value).await?;
+
+        Ok(Transacted {
+            inner,
+            transaction_id,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn try_from_response() {
+        let response = reqwest::Response::from(
+            http::Response::builder()
+                .header("X-IBM-Txid", "ZOSMFAD_5_1")
+                .body("")
+                .unwrap(),
+        );
+
+        let transacted = Transacted::<()>::try_from_response(response).await.unwrap();
+        assert_eq!(transacted.transaction_id(), Some("ZOSMFAD_5_1"));
+
+        let response = reqwest::Response::from(http::Response::new(""));
+
+        let transacted = Transacted::<()>::try_from_response(response).await.unwrap();
+        assert_eq!(transacted.transaction_id(), None);
+    }
+}
@@ -0,0 +1,218 @@
+pub mod send;
+pub mod start;
+
+mod receive;
+mod stop;
+
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use z_osmf_macros::Getters;
+
+use crate::convert::TryFromResponse;
+use crate::{ClientCore, Result};
+
+use self::receive::TsoReceiveBuilder;
+use self::send::TsoSendBuilder;
+use self::start::{TsoSession, TsoStartBuilder};
+use self::stop::TsoStopBuilder;
+
+#[derive(Clone, Debug)]
+pub struct TsoClient {
+    core: ClientCore,
+}
+
+/// # TSO/E Address Spaces
+impl TsoClient {
+    pub(crate) fn new(core: ClientCore) -> Self {
+        TsoClient { core }
+    }
+
+    /// # Examples
+    ///
+    /// Receive further messages from a TSO/E address space:
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let tso_response = zosmf
+    ///     .tso()
+    ///     .receive("ZOSMFAD-71-aabcaaaf")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn receive<K>(&self, servlet_key: K) -> Result<TsoResponse>
+    where
+        K: std::fmt::Display,
+    {
+        TsoReceiveBuilder::new(self.core.clone(), servlet_key)
+            .build()
+            .await
+    }
+
+    /// # Examples
+    ///
+    /// Run a TSO/E command:
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let tso_response = zosmf
+    ///     .tso()
+    ///     .send("ZOSMFAD-71-aabcaaaf", "LISTCAT LEVEL(IBMUSER)")
+    ///     .build()
+    ///     .await?;
+    ///
+    /// for message in tso_response.messages().iter() {
+    ///     if let z_osmf::tso::TsoMessage::Message(message) = message {
+    ///         println!("{}", message.data());
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn send<K, C>(&self, servlet_key: K, command: C) -> TsoSendBuilder<TsoResponse>
+    where
+        K: std::fmt::Display,
+        C: std::fmt::Display,
+    {
+        TsoSendBuilder::new(self.core.clone(), servlet_key, command)
+    }
+
+    /// # Examples
+    ///
+    /// Start a TSO/E address space:
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let tso_session = zosmf
+    ///     .tso()
+    ///     .start()
+    ///     .account("ACCT#")
+    ///     .logon_procedure("IZUFPROC")
+    ///     .build()
+    ///     .await?;
+    ///
+    /// println!("{}", tso_session.servlet_key());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn start(&self) -> TsoStartBuilder<TsoSession> {
+        TsoStartBuilder::new(self.core.clone())
+    }
+
+    /// # Examples
+    ///
+    /// Stop a TSO/E address space:
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// zosmf.tso().stop("ZOSMFAD-71-aabcaaaf").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn stop<K>(&self, servlet_key: K) -> Result<()>
+    where
+        K: std::fmt::Display,
+    {
+        TsoStopBuilder::new(self.core.clone(), servlet_key)
+            .build()
+            .await
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub enum TsoMessage {
+    #[serde(rename = "TSO MESSAGE")]
+    Message(TsoMessageData),
+    #[serde(rename = "TSO PROMPT")]
+    Prompt(TsoPromptData),
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, Getters, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub struct TsoMessageData {
+    version: Arc<str>,
+    data: Arc<str>,
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, Getters, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub struct TsoPromptData {
+    version: Arc<str>,
+    #[getter(copy)]
+    #[serde(deserialize_with = "de_true_false", serialize_with = "ser_true_false")]
+    hidden: bool,
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, Getters, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub struct TsoResponse {
+    #[serde(rename = "servletKey")]
+    servlet_key: Arc<str>,
+    #[serde(default, rename = "tsoData")]
+    messages: Arc<[TsoMessage]>,
+    #[getter(copy)]
+    #[serde(default)]
+    timeout: bool,
+}
+
+impl TryFromResponse for TsoResponse {
+    async fn try_from_response(value: reqwest::Response) -> Result<Self> {
+        Ok(value.json().await?)
+    }
+}
+
+fn de_true_false<'de, D>(deserializer: D) -> std::result::Result<bool, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let s = String::deserialize(deserializer)?;
+
+    match s.as_str() {
+        "TRUE" => Ok(true),
+        "FALSE" => Ok(false),
+        _ => Err(serde::de::Error::unknown_variant(&s, &["TRUE", "FALSE"])),
+    }
+}
+
+fn ser_true_false<S>(v: &bool, serializer: S) -> std::result::Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    serializer.serialize_str(if *v { "TRUE" } else { "FALSE" })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize_response() {
+        let response: TsoResponse = serde_json::from_str(
+            r#"
+            {
+                "servletKey": "ZOSMFAD-71-aabcaaaf",
+                "ver": "0100",
+                "tsoData": [
+                    {"TSO MESSAGE": {"VERSION": "0100", "DATA": "IKJ56455I ZOSMFAD LOGON IN PROGRESS"}},
+                    {"TSO PROMPT": {"VERSION": "0100", "HIDDEN": "FALSE"}}
+                ],
+                "reused": false,
+                "timeout": false
+            }
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(response.servlet_key(), "ZOSMFAD-71-aabcaaaf");
+        assert!(!response.timeout());
+        assert_eq!(
+            response.messages(),
+            &[
+                TsoMessage::Message(TsoMessageData {
+                    version: "0100".into(),
+                    data: "IKJ56455I ZOSMFAD LOGON IN PROGRESS".into(),
+                }),
+                TsoMessage::Prompt(TsoPromptData {
+                    version: "0100".into(),
+                    hidden: false,
+                }),
+            ][..]
+        );
+    }
+}
@@ -0,0 +1,94 @@
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use serde::Serialize;
+use z_osmf_macros::Endpoint;
+
+use crate::convert::TryFromResponse;
+use crate::ClientCore;
+
+#[derive(Clone, Debug, Endpoint)]
+#[endpoint(method = put, path = "/zosmf/tsoApp/tso/{servlet_key}")]
+pub struct TsoSendBuilder<T>
+where
+    T: TryFromResponse,
+{
+    core: Arc<ClientCore>,
+
+    #[endpoint(path)]
+    servlet_key: Arc<str>,
+    #[endpoint(builder_fn = build_body)]
+    command: Arc<str>,
+    #[endpoint(query = "readReply")]
+    read_reply: Option<bool>,
+
+    target_type: PhantomData<T>,
+}
+
+#[derive(Serialize)]
+struct RequestJson<'a> {
+    #[serde(rename = "TSO RESPONSE")]
+    response: ResponseData<'a>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "UPPERCASE")]
+struct ResponseData<'a> {
+    version: &'static str,
+    data: &'a str,
+}
+
+fn build_body<T>(
+    request_builder: reqwest::RequestBuilder,
+    builder: &TsoSendBuilder<T>,
+) -> reqwest::RequestBuilder
+where
+    T: TryFromResponse,
+{
+    request_builder.json(&RequestJson {
+        response: ResponseData {
+            version: "0100",
+            data: &builder.command,
+        },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{get_zosmf, GetJson};
+
+    #[test]
+    fn send_command() {
+        let zosmf = get_zosmf();
+
+        let json: serde_json::Value = serde_json::from_str(
+            r#"
+        {
+            "TSO RESPONSE": {
+                "VERSION": "0100",
+                "DATA": "LISTCAT LEVEL(IBMUSER)"
+            }
+        }
+        "#,
+        )
+        .unwrap();
+        let manual_request = zosmf
+            .core
+            .client
+            .put("https://test.com/zosmf/tsoApp/tso/ZOSMFAD-71-aabcaaaf")
+            .query(&[("readReply", "false")])
+            .json(&json)
+            .build()
+            .unwrap();
+
+        let request = zosmf
+            .tso()
+            .send("ZOSMFAD-71-aabcaaaf", "LISTCAT LEVEL(IBMUSER)")
+            .read_reply(false)
+            .get_request()
+            .unwrap();
+
+        assert_eq!(format!("{:?}", manual_request), format!("{:?}", request));
+        assert_eq!(manual_request.json(), request.json());
+    }
+}
@@ -0,0 +1,116 @@
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use z_osmf_macros::{Endpoint, Getters};
+
+use crate::convert::TryFromResponse;
+use crate::{ClientCore, Result};
+
+use super::TsoMessage;
+
+#[derive(Clone, Debug, Deserialize, Eq, Getters, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub struct TsoSession {
+    #[serde(rename = "servletKey")]
+    servlet_key: Arc<str>,
+    #[serde(rename = "queueID")]
+    queue_id: Option<Arc<str>>,
+    #[getter(copy)]
+    #[serde(default)]
+    reused: bool,
+    #[serde(default, rename = "tsoData")]
+    messages: Arc<[TsoMessage]>,
+}
+
+impl TryFromResponse for TsoSession {
+    async fn try_from_response(value: reqwest::Response) -> Result<Self> {
+        Ok(value.json().await?)
+    }
+}
+
+#[derive(Clone, Debug, Endpoint)]
+#[endpoint(method = post, path = "/zosmf/tsoApp/tso")]
+pub struct TsoStartBuilder<T>
+where
+    T: TryFromResponse,
+{
+    core: Arc<ClientCore>,
+
+    #[endpoint(query = "proc")]
+    logon_procedure: Option<Arc<str>>,
+    #[endpoint(query = "chset")]
+    character_set: Option<Arc<str>>,
+    #[endpoint(query = "cpage")]
+    code_page: Option<Arc<str>>,
+    #[endpoint(query = "rows")]
+    rows: Option<i32>,
+    #[endpoint(query = "cols")]
+    columns: Option<i32>,
+    #[endpoint(query = "acct")]
+    account: Option<Arc<str>>,
+    #[endpoint(query = "group")]
+    group: Option<Arc<str>>,
+    #[endpoint(query = "rsize")]
+    region_size: Option<i32>,
+
+    target_type: PhantomData<T>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::*;
+
+    #[test]
+    fn minimal_request() {
+        let zosmf = get_zosmf();
+
+        let manual_request = zosmf
+            .core
+            .client
+            .post("https://test.com/zosmf/tsoApp/tso")
+            .build()
+            .unwrap();
+
+        let request = zosmf.tso().start().get_request().unwrap();
+
+        assert_eq!(format!("{:?}", manual_request), format!("{:?}", request));
+    }
+
+    #[test]
+    fn maximal_request() {
+        let zosmf = get_zosmf();
+
+        let manual_request = zosmf
+            .core
+            .client
+            .post("https://test.com/zosmf/tsoApp/tso")
+            .query(&[
+                ("proc", "IZUFPROC"),
+                ("chset", "697"),
+                ("cpage", "1047"),
+                ("rows", "24"),
+                ("cols", "80"),
+                ("acct", "ACCT#"),
+                ("group", "GROUP1"),
+                ("rsize", "50000"),
+            ])
+            .build()
+            .unwrap();
+
+        let request = zosmf
+            .tso()
+            .start()
+            .logon_procedure("IZUFPROC")
+            .character_set("697")
+            .code_page("1047")
+            .rows(24)
+            .columns(80)
+            .account("ACCT#")
+            .group("GROUP1")
+            .region_size(50000)
+            .get_request()
+            .unwrap();
+
+        assert_eq!(format!("{:?}", manual_request), format!("{:?}", request));
+    }
+}
@@ -0,0 +1,21 @@
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use z_osmf_macros::Endpoint;
+
+use crate::convert::TryFromResponse;
+use crate::ClientCore;
+
+#[derive(Clone, Debug, Endpoint)]
+#[endpoint(method = delete, path = "/zosmf/tsoApp/tso/{servlet_key}")]
+pub(super) struct TsoStopBuilder<T>
+where
+    T: TryFromResponse,
+{
+    core: Arc<ClientCore>,
+
+    #[endpoint(path)]
+    servlet_key: Arc<str>,
+
+    target_type: PhantomData<T>,
+}
@@ -1,17 +1,88 @@
 use std::num::NonZeroU32;
 use std::str::FromStr;
 
+use percent_encoding::{AsciiSet, CONTROLS};
 use reqwest::header::HeaderValue;
 use serde::{Deserialize, Serialize};
 
 use crate::{Error, Result};
 
+/// The characters escaped in a path field: those not allowed in a URL path,
+/// so that `/` still separates the directories of a file path.
+const PATH_ENCODE_SET: &AsciiSet = &CONTROLS
+    .add(b' ')
+    .add(b'"')
+    .add(b'#')
+    .add(b'%')
+    .add(b'<')
+    .add(b'>')
+    .add(b'?')
+    .add(b'[')
+    .add(b'\\')
+    .add(b']')
+    .add(b'^')
+    .add(b'`')
+    .add(b'{')
+    .add(b'|')
+    .add(b'}');
+
+/// Percent-encode a path field, like a dataset name or file path, for the URL of a request.
+///
+/// Called by the `Endpoint` derive, so it is unused when only endpoints without path fields are enabled.
+#[allow(dead_code)]
+pub(crate) fn encode_path(value: &str) -> String {
+    percent_encoding::utf8_percent_encode(value, PATH_ENCODE_SET).to_string()
+}
+
 #[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
 pub enum RecordRange {
     StartCount(u32, NonZeroU32),
     StartEnd(Option<u32>, u32),
 }
 
+impl RecordRange {
+    /// Create a range of the records from `start` to `end`, inclusive and counted from zero.
+    ///
+    /// Returns [`Error::InvalidValue`] if `start` is greater than `end`.
+    pub fn new(start: u32, end: u32) -> Result<Self> {
+        if start > end {
+            return Err(Error::InvalidValue(format!(
+                "record range start {} is greater than end {}",
+                start, end
+            )));
+        }
+
+        Ok(RecordRange::StartEnd(Some(start), end))
+    }
+
+    /// Create a range of the first `n` records.
+    ///
+    /// Returns [`Error::InvalidValue`] if `n` is zero.
+    pub fn first(n: u32) -> Result<Self> {
+        let count = NonZeroU32::new(n)
+            .ok_or_else(|| Error::InvalidValue("record range must include a record".into()))?;
+
+        Ok(RecordRange::StartCount(0, count))
+    }
+
+    /// The first record of the range, or `None` for a range of the last records.
+    pub fn start(&self) -> Option<u32> {
+        match self {
+            RecordRange::StartCount(start, _) => Some(*start),
+            RecordRange::StartEnd(start, _) => *start,
+        }
+    }
+
+    /// The last record of the range, or `None` for a range of the last records.
+    pub fn end(&self) -> Option<u32> {
+        match self {
+            RecordRange::StartCount(start, count) => Some(start.saturating_add(count.get() - 1)),
+            RecordRange::StartEnd(Some(_), end) => Some(*end),
+            RecordRange::StartEnd(None, _) => None,
+        }
+    }
+}
+
 impl From<RecordRange> for HeaderValue {
     fn from(value: RecordRange) -> Self {
         match value {
@@ -33,7 +104,8 @@ impl FromStr for RecordRange {
         }
 
         if let Some((start, end)) = s.split_once('-') {
-            return Ok(RecordRange::StartEnd(Some(start.parse()?), end.parse()?));
+            return RecordRange::new(start.parse()?, end.parse()?)
+                .map_err(|_| Error::RecordRange(s.to_string()));
         }
 
         if let Some((start, count)) = s.split_once(',') {
@@ -48,6 +120,19 @@ impl FromStr for RecordRange {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_encode_path() {
+        assert_eq!(encode_path("SYS1.PARMLIB"), "SYS1.PARMLIB");
+        assert_eq!(encode_path("(SMFPRM00)"), "(SMFPRM00)");
+        assert_eq!(encode_path("/-(VOL001)"), "/-(VOL001)");
+        assert_eq!(encode_path("IBMUSER.#DATA.$A@"), "IBMUSER.%23DATA.$A@");
+        assert_eq!(
+            encode_path("/u/ibmuser/my file?100%.txt"),
+            "/u/ibmuser/my%20file%3F100%25.txt"
+        );
+        assert_eq!(encode_path("/u/ibmuser/café"), "/u/ibmuser/caf%C3%A9");
+    }
+
     #[test]
     fn test_record_range_into_header_value() {
         let header_value: HeaderValue = RecordRange::StartEnd(Some(0), 249).into();
@@ -86,5 +171,32 @@ mod tests {
         assert!(RecordRange::from_str("0,SENSE").is_err());
 
         assert!(RecordRange::from_str("NONSENSE").is_err());
+
+        assert!(RecordRange::from_str("249-0").is_err());
+    }
+
+    #[test]
+    fn test_record_range_constructors() {
+        let record_range = RecordRange::new(10, 19).unwrap();
+        assert_eq!(record_range, RecordRange::StartEnd(Some(10), 19));
+        assert_eq!(record_range.start(), Some(10));
+        assert_eq!(record_range.end(), Some(19));
+
+        assert!(RecordRange::new(5, 5).is_ok());
+        assert!(matches!(
+            RecordRange::new(19, 10),
+            Err(Error::InvalidValue(_))
+        ));
+
+        let record_range = RecordRange::first(250).unwrap();
+        assert_eq!(HeaderValue::from(record_range), "0,250");
+        assert_eq!(record_range.start(), Some(0));
+        assert_eq!(record_range.end(), Some(249));
+
+        assert!(matches!(RecordRange::first(0), Err(Error::InvalidValue(_))));
+
+        let record_range = RecordRange::StartEnd(None, 249);
+        assert_eq!(record_range.start(), None);
+        assert_eq!(record_range.end(), None);
     }
 }
@@ -7,6 +7,7 @@ pub mod delete;
 pub mod list;
 pub mod properties;
 pub mod start;
+pub mod variables;
 
 use archive::{WorkflowArchive, WorkflowArchiveBuilder};
 use archived_workflows::{ArchivedWorkflowList, ArchivedWorkflowListBuilder};
@@ -21,6 +22,7 @@ use self::delete::WorkflowDeleteBuilder;
 use self::list::{WorkflowList, WorkflowListBuilder};
 use self::properties::{WorkflowProperties, WorkflowPropertiesBuilder};
 use self::start::WorkflowStartBuilder;
+use self::variables::WorkflowVariablesUpdateBuilder;
 
 /// # Workflows
 #[derive(Clone, Debug)]
@@ -124,6 +126,22 @@ impl WorkflowsClient {
     /// # Ok(())
     /// # }
     /// ```
+    ///
+    /// Resume a z/OSMF Workflow at a specific step, running only that step:
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// use z_osmf::workflows::start::WorkflowStartResolveVariableConflict;
+    ///
+    /// zosmf.workflows()
+    ///     .start("d043b5f1-adab-48e7-b7c3-d41cd95fa4b0")
+    ///     .step_name("define_dataset")
+    ///     .perform_subsequent(false)
+    ///     .resolve_conflict_by_using(WorkflowStartResolveVariableConflict::ExistingValue)
+    ///     .build()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
     pub fn start<K>(&self, key: K) -> WorkflowStartBuilder<()>
     where
         K: std::fmt::Display,
@@ -131,6 +149,32 @@ impl WorkflowsClient {
         WorkflowStartBuilder::new(self.core.clone(), key)
     }
 
+    /// # Examples
+    ///
+    /// Update the variables of a z/OSMF Workflow.
+    /// Instance variables defined with a prefix must be named with that prefix included:
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// use z_osmf::workflows::create::WorkflowVariableOverride;
+    ///
+    /// zosmf.workflows()
+    ///     .update_variables("d043b5f1-adab-48e7-b7c3-d41cd95fa4b0")
+    ///     .variables([
+    ///         WorkflowVariableOverride::new("st_group", "SYSPLEX1"),
+    ///         WorkflowVariableOverride::new("ZOSMF_SAMPLE.dsname", "IBMUSER.SAMPLE"),
+    ///     ])
+    ///     .build()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn update_variables<K>(&self, key: K) -> WorkflowVariablesUpdateBuilder<()>
+    where
+        K: std::fmt::Display,
+    {
+        WorkflowVariablesUpdateBuilder::new(self.core.clone(), key)
+    }
+
     /// # Examples
     ///
     /// Cancel execution of a z/OSMF Workflow:
@@ -276,19 +320,27 @@ impl WorkflowsClient {
 }
 
 #[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[non_exhaustive]
 pub enum WorkflowAccess {
     Private,
     Public,
     Restricted,
+    /// Any other access type reported by z/OSMF.
+    #[serde(other)]
+    Unknown,
 }
 
 #[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
 #[serde(rename_all = "kebab-case")]
+#[non_exhaustive]
 pub enum WorkflowStatus {
     AutomationInProgress,
     Canceled,
     Complete,
     InProgress,
+    /// Any other status reported by z/OSMF.
+    #[serde(other)]
+    Unknown,
 }
 
 #[derive(Clone, Debug)]
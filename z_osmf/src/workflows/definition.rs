@@ -107,10 +107,7 @@ impl std::ops::Deref for WorkflowDefinitionVariables {
 
 impl TryFromResponse for WorkflowDefinitionVariables {
     async fn try_from_response(value: reqwest::Response) -> Result<Self> {
-        let text: String = value.text().await?;
-        std::fs::write("output.json", &text).unwrap();
-
-        Ok(serde_json::from_str(&text).unwrap())
+        Ok(value.json().await?)
     }
 }
 
@@ -232,3 +229,17 @@ where
         None => request_builder,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn variables_malformed_response() {
+        let response = reqwest::Response::from(http::Response::new("{\"variables\":"));
+
+        assert!(WorkflowDefinitionVariables::try_from_response(response)
+            .await
+            .is_err());
+    }
+}
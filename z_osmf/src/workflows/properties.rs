@@ -39,8 +39,8 @@ pub struct WorkflowProperties {
     id: Arc<str>,
     #[serde(rename = "workflowVersion")]
     version: Arc<str>,
-    #[serde(rename = "workflowDefinitionFileMD5Value")]
-    definition_file_hash: Arc<str>,
+    #[serde(default, rename = "workflowDefinitionFileMD5Value")]
+    definition_file_hash: Option<Arc<str>>,
     vendor: Arc<str>,
     owner: Arc<str>,
     #[serde(rename = "workflowArchiveSAFID")]
@@ -56,19 +56,23 @@ pub struct WorkflowProperties {
     percent_complete: i32,
     is_callable: Option<Arc<str>>,
     #[getter(copy)]
-    contains_parallel_steps: bool,
+    #[serde(default)]
+    contains_parallel_steps: Option<bool>,
     #[getter(copy)]
-    scope: WorkflowScope,
+    #[serde(default)]
+    scope: Option<WorkflowScope>,
     #[getter(copy)]
     #[serde(rename = "statusName")]
     status: WorkflowStatus,
     #[getter(copy)]
-    delete_completed_jobs: bool,
+    #[serde(default)]
+    delete_completed_jobs: Option<bool>,
     automation_status: Option<WorkflowAutomationStatus>,
     #[getter(copy)]
     auto_delete_on_completion: Option<bool>,
     #[getter(copy)]
-    access: WorkflowAccess,
+    #[serde(default)]
+    access: Option<WorkflowAccess>,
     account_info: Option<Arc<str>>,
     job_statement: Option<Arc<str>>,
     template_id: Option<Arc<str>>,
@@ -82,7 +86,8 @@ pub struct WorkflowProperties {
     template_name: Option<Arc<str>>,
     global_variable_group: Option<Arc<str>>,
     #[getter(copy)]
-    is_instance_variable_without_prefix: bool,
+    #[serde(default)]
+    is_instance_variable_without_prefix: Option<bool>,
     software_type: Option<Arc<str>>,
 }
 
@@ -232,6 +237,7 @@ pub enum WorkflowScope {
 }
 
 #[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[allow(clippy::large_enum_variant)]
 #[serde(untagged)]
 pub enum WorkflowStep {
     Calling(WorkflowStepCalling),
@@ -319,7 +325,6 @@ pub struct WorkflowStepJobInfoStatus {
     return_code: Option<Arc<str>>,
     #[serde(rename = "jobname")]
     name: Arc<str>,
-    #[getter(copy)]
     status: Option<JobStatus>,
     owner: Arc<str>,
     subsystem: Option<Arc<str>>,
@@ -389,6 +394,7 @@ impl std::ops::Deref for WorkflowStepRest {
 }
 
 #[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[non_exhaustive]
 pub enum WorkflowStepStatus {
     Unassigned,
     Assigned,
@@ -406,6 +412,9 @@ pub enum WorkflowStepStatus {
     Conflicts,
     #[serde(rename = "Condition Not Satisfied")]
     ConditionNotSatisfied,
+    /// Any other status reported by z/OSMF.
+    #[serde(other)]
+    Unknown,
 }
 
 #[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
@@ -755,4 +764,35 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn parse_older_response() -> anyhow::Result<()> {
+        let json_text: &str = r#"
+        {
+            "workflowName": "Configure the product",
+            "workflowKey": "7a2263a7-7c91-40b4-8892-2a4342a222c3",
+            "workflowDescription": "Sample workflow",
+            "workflowID": "programExecutionSample",
+            "workflowVersion": "1.0",
+            "vendor": "IBM",
+            "owner": "zosmfad",
+            "system": "PLEX1.SY1",
+            "category": "configuration",
+            "statusName": "complete",
+            "percentComplete": 100,
+            "automationStatus": null,
+            "someFutureField": "ignored"
+        }
+        "#;
+
+        let properties = serde_json::from_str::<WorkflowProperties>(json_text)?;
+
+        assert_eq!(properties.status(), WorkflowStatus::Complete);
+        assert_eq!(properties.definition_file_hash(), None);
+        assert_eq!(properties.contains_parallel_steps(), None);
+        assert_eq!(properties.scope(), None);
+        assert_eq!(properties.access(), None);
+
+        Ok(())
+    }
 }
@@ -58,11 +58,17 @@ impl std::fmt::Display for WorkflowStartResolveVariableConflict {
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
 struct RequestJson<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
     resolve_conflict_by_using: Option<WorkflowStartResolveVariableConflict>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     step_name: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     perform_subsequent: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     notification_url: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     target_systemuid: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     target_systempwd: Option<&'a str>,
 }
 
@@ -84,3 +90,58 @@ where
 
     request_builder.json(&json)
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{get_zosmf, GetJson};
+
+    use super::*;
+
+    #[test]
+    fn resume_from_step() {
+        let zosmf = get_zosmf();
+
+        let json: serde_json::Value = serde_json::from_str(
+            r#"
+        {
+            "resolveConflictByUsing": "existingValue",
+            "stepName": "define_dataset",
+            "performSubsequent": false
+        }
+        "#,
+        )
+        .unwrap();
+        let manual_request = zosmf
+            .core
+            .client
+            .put("https://test.com/zosmf/workflow/rest/1.0/workflows/d043b5f1-adab-48e7-b7c3-d41cd95fa4b0/operations/start")
+            .json(&json)
+            .build()
+            .unwrap();
+
+        let request = zosmf
+            .workflows()
+            .start("d043b5f1-adab-48e7-b7c3-d41cd95fa4b0")
+            .step_name("define_dataset")
+            .perform_subsequent(false)
+            .resolve_conflict_by_using(WorkflowStartResolveVariableConflict::ExistingValue)
+            .get_request()
+            .unwrap();
+
+        assert_eq!(format!("{:?}", manual_request), format!("{:?}", request));
+        assert_eq!(manual_request.json(), request.json());
+    }
+
+    #[test]
+    fn minimal_request() {
+        let zosmf = get_zosmf();
+
+        let request = zosmf
+            .workflows()
+            .start("d043b5f1-adab-48e7-b7c3-d41cd95fa4b0")
+            .get_request()
+            .unwrap();
+
+        assert_eq!(request.json(), Some(serde_json::json!({})));
+    }
+}
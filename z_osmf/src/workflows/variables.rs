@@ -0,0 +1,87 @@
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use serde::Serialize;
+use z_osmf_macros::Endpoint;
+
+use crate::convert::TryFromResponse;
+use crate::ClientCore;
+
+use super::create::WorkflowVariableOverride;
+
+#[derive(Clone, Debug, Endpoint)]
+#[endpoint(method = put, path = "/zosmf/workflow/rest/1.0/workflows/{key}/variables")]
+pub struct WorkflowVariablesUpdateBuilder<T>
+where
+    T: TryFromResponse,
+{
+    core: Arc<ClientCore>,
+
+    #[endpoint(path)]
+    key: Arc<str>,
+    #[endpoint(builder_fn = build_body)]
+    variables: Option<Arc<[WorkflowVariableOverride]>>,
+
+    target_type: PhantomData<T>,
+}
+
+#[derive(Serialize)]
+struct RequestJson<'a> {
+    variables: &'a [WorkflowVariableOverride],
+}
+
+fn build_body<T>(
+    request_builder: reqwest::RequestBuilder,
+    builder: &WorkflowVariablesUpdateBuilder<T>,
+) -> reqwest::RequestBuilder
+where
+    T: TryFromResponse,
+{
+    request_builder.json(&RequestJson {
+        variables: builder.variables.as_deref().unwrap_or_default(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{get_zosmf, GetJson};
+
+    use super::*;
+
+    #[test]
+    fn update_variables() {
+        let zosmf = get_zosmf();
+
+        let json: serde_json::Value = serde_json::from_str(
+            r#"
+        {
+            "variables": [
+                { "name": "st_group", "value": "SYSPLEX1" },
+                { "name": "ZOSMF_SAMPLE.dsname", "value": "IBMUSER.SAMPLE" }
+            ]
+        }
+        "#,
+        )
+        .unwrap();
+        let manual_request = zosmf
+            .core
+            .client
+            .put("https://test.com/zosmf/workflow/rest/1.0/workflows/d043b5f1-adab-48e7-b7c3-d41cd95fa4b0/variables")
+            .json(&json)
+            .build()
+            .unwrap();
+
+        let request = zosmf
+            .workflows()
+            .update_variables("d043b5f1-adab-48e7-b7c3-d41cd95fa4b0")
+            .variables([
+                WorkflowVariableOverride::new("st_group", "SYSPLEX1"),
+                WorkflowVariableOverride::new("ZOSMF_SAMPLE.dsname", "IBMUSER.SAMPLE"),
+            ])
+            .get_request()
+            .unwrap();
+
+        assert_eq!(format!("{:?}", manual_request), format!("{:?}", request));
+        assert_eq!(manual_request.json(), request.json());
+    }
+}
@@ -0,0 +1,206 @@
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+use crate::{AuthToken, Error, Result};
+
+/// The connection details of a z/OSMF profile, merged with its parents and the base profile.
+#[derive(Debug, PartialEq)]
+pub(crate) struct ZoweProfile {
+    pub(crate) url: String,
+    pub(crate) credentials: ZoweCredentials,
+    pub(crate) reject_unauthorized: bool,
+}
+
+#[derive(Debug, PartialEq)]
+pub(crate) enum ZoweCredentials {
+    Password { user: String, password: String },
+    Token(AuthToken),
+}
+
+/// Resolve a profile of a Zowe team configuration, or the default `zosmf` profile.
+pub(crate) fn resolve_profile(config: &str, profile_name: Option<&str>) -> Result<ZoweProfile> {
+    let config: ConfigJson = serde_json::from_str(config)?;
+
+    let profile_name = match profile_name {
+        Some(profile_name) => profile_name,
+        None => config.defaults.get("zosmf").ok_or_else(|| {
+            Error::InvalidValue("zowe config has no default zosmf profile".into())
+        })?,
+    };
+
+    let mut properties = HashMap::new();
+
+    if let Some(base) = config.defaults.get("base") {
+        let mut profiles = &config.profiles;
+        for name in base.split('.') {
+            match profiles.get(name) {
+                Some(profile) => {
+                    properties.extend(profile.properties.clone());
+                    profiles = &profile.profiles;
+                }
+                None => break,
+            }
+        }
+    }
+
+    let mut profiles = &config.profiles;
+    for name in profile_name.split('.') {
+        let profile = profiles.get(name).ok_or_else(|| {
+            Error::InvalidValue(format!("zowe profile not found: {}", profile_name))
+        })?;
+
+        properties.extend(profile.properties.clone());
+        profiles = &profile.profiles;
+    }
+
+    let property = |name: &str| match properties.get(name) {
+        Some(serde_json::Value::String(value)) => Some(value.clone()),
+        Some(serde_json::Value::Number(value)) => Some(value.to_string()),
+        _ => None,
+    };
+
+    let host = property("host")
+        .ok_or_else(|| Error::InvalidValue(format!("zowe profile {} has no host", profile_name)))?;
+    let protocol = property("protocol").unwrap_or_else(|| "https".to_string());
+    let url = match property("port") {
+        Some(port) => format!("{}://{}:{}", protocol, host, port),
+        None => format!("{}://{}", protocol, host),
+    };
+
+    let credentials = match (
+        property("user"),
+        property("password"),
+        property("tokenType"),
+        property("tokenValue"),
+    ) {
+        (Some(user), Some(password), _, _) => ZoweCredentials::Password { user, password },
+        (_, _, Some(token_type), Some(token_value)) if token_type == "jwtToken" => {
+            ZoweCredentials::Token(AuthToken::jwt(token_value))
+        }
+        (_, _, Some(token_type), Some(token_value)) if token_type == "LtpaToken2" => {
+            ZoweCredentials::Token(AuthToken::ltpa2(token_value))
+        }
+        _ => {
+            return Err(Error::InvalidValue(format!(
+                "zowe profile {} has no user and password or token; \
+                 credentials kept in the secure store are not supported",
+                profile_name
+            )))
+        }
+    };
+
+    let reject_unauthorized = !matches!(
+        properties.get("rejectUnauthorized"),
+        Some(serde_json::Value::Bool(false))
+    );
+
+    Ok(ZoweProfile {
+        url,
+        credentials,
+        reject_unauthorized,
+    })
+}
+
+#[derive(Deserialize)]
+struct ConfigJson {
+    #[serde(default)]
+    profiles: HashMap<String, ProfileJson>,
+    #[serde(default)]
+    defaults: HashMap<String, String>,
+}
+
+#[derive(Deserialize)]
+struct ProfileJson {
+    #[serde(default)]
+    properties: HashMap<String, serde_json::Value>,
+    #[serde(default)]
+    profiles: HashMap<String, ProfileJson>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONFIG: &str = r#"
+    {
+        "$schema": "./zowe.schema.json",
+        "profiles": {
+            "lpar1": {
+                "properties": {
+                    "host": "lpar1.my-company.com"
+                },
+                "profiles": {
+                    "zosmf": {
+                        "type": "zosmf",
+                        "properties": {
+                            "port": 10443
+                        },
+                        "secure": []
+                    }
+                }
+            },
+            "lpar2": {
+                "type": "zosmf",
+                "properties": {
+                    "host": "lpar2.my-company.com",
+                    "tokenType": "jwtToken",
+                    "tokenValue": "eyJhbGciOiJSUzI1NiJ9"
+                }
+            },
+            "base": {
+                "type": "base",
+                "properties": {
+                    "user": "IBMUSER",
+                    "password": "SYS1",
+                    "rejectUnauthorized": false
+                },
+                "secure": []
+            }
+        },
+        "defaults": {
+            "zosmf": "lpar1.zosmf",
+            "base": "base"
+        },
+        "autoStore": true
+    }
+    "#;
+
+    #[test]
+    fn default_profile() {
+        assert_eq!(
+            resolve_profile(CONFIG, None).unwrap(),
+            ZoweProfile {
+                url: "https://lpar1.my-company.com:10443".to_string(),
+                credentials: ZoweCredentials::Password {
+                    user: "IBMUSER".to_string(),
+                    password: "SYS1".to_string()
+                },
+                reject_unauthorized: false,
+            }
+        );
+    }
+
+    #[test]
+    fn named_profile() {
+        let profile = resolve_profile(CONFIG, Some("lpar2")).unwrap();
+
+        assert_eq!(profile.url, "https://lpar2.my-company.com");
+        assert!(matches!(
+            profile.credentials,
+            ZoweCredentials::Password { .. }
+        ));
+
+        let config = CONFIG.replace(r#""password": "SYS1","#, "");
+        let profile = resolve_profile(&config, Some("lpar2")).unwrap();
+        assert_eq!(
+            profile.credentials,
+            ZoweCredentials::Token(AuthToken::jwt("eyJhbGciOiJSUzI1NiJ9"))
+        );
+
+        assert!(matches!(
+            resolve_profile(CONFIG, Some("lpar3")),
+            Err(Error::InvalidValue(_))
+        ));
+    }
+}
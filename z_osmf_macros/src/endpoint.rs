@@ -34,6 +34,34 @@ impl From<Endpoint> for proc_macro::TokenStream {
 
                 #( #setter_fns )*
 
+                /// Add a header to the request, after every header set by this builder.
+                pub fn with_header<K, V>(self, name: K, value: V) -> Self
+                where
+                    K: std::fmt::Display,
+                    V: std::fmt::Display,
+                {
+                    let mut new = self;
+                    std::sync::Arc::make_mut(&mut new.core)
+                        .headers
+                        .push((name.to_string().into(), value.to_string().into()));
+
+                    new
+                }
+
+                /// Add a query parameter to the request, after every parameter set by this builder.
+                pub fn with_query<K, V>(self, key: K, value: V) -> Self
+                where
+                    K: std::fmt::Display,
+                    V: std::fmt::Display,
+                {
+                    let mut new = self;
+                    std::sync::Arc::make_mut(&mut new.core)
+                        .queries
+                        .push((key.to_string().into(), value.to_string().into()));
+
+                    new
+                }
+
                 #get_response_fn
 
                 pub async fn build(self) -> crate::Result<T> {
@@ -41,6 +69,13 @@ impl From<Endpoint> for proc_macro::TokenStream {
 
                     self.get_response().await?.try_into_target().await
                 }
+
+                /// Send the request like `build`, keeping the z/OSMF transaction ID of the response.
+                pub async fn build_transacted(self) -> crate::Result<crate::Transacted<T>> {
+                    use crate::convert::TryIntoTarget;
+
+                    self.get_response().await?.try_into_target().await
+                }
             }
         }
         .into()
@@ -56,6 +91,7 @@ pub(crate) struct Endpoint {
 
     method: syn::Ident,
     path: String,
+    response_fn: Option<syn::ExprPath>,
 }
 
 impl Endpoint {
@@ -108,16 +144,34 @@ impl Endpoint {
 
     fn get_response_fn(&self) -> TokenStream {
         let Endpoint {
-            data, method, path, ..
+            ident,
+            data,
+            method,
+            path,
+            response_fn,
+            ..
         } = &self;
 
+        let response_builder = response_fn.as_ref().map(|response_fn| {
+            quote! {
+                let response = response.map(|mut response| {
+                    #response_fn(&mut response, self);
+
+                    response
+                });
+            }
+        });
+
+        let span_name = ident.to_string();
+
         let fields = data.as_ref().take_struct().unwrap();
 
         let path_builders: Vec<_> = fields.iter().map(|f| f.path_builder()).collect();
         let request_builders: Vec<_> = fields.iter().map(|f| f.request_builder()).collect();
 
         quote! {
-            fn get_request(&self) -> crate::Result<reqwest::Request> {
+            /// Build the request, authenticated with the client's current token, without sending it.
+            pub fn get_request(&self) -> crate::Result<reqwest::Request> {
                 let path = {
                     #( #path_builders )*
 
@@ -130,21 +184,64 @@ impl Endpoint {
 
                 #( #request_builders )*
 
-                let read = self.core.token.read().map_err(|err| crate::Error::RwLockPoisonError(err.to_string()))?;
-                if let Some(ref token) = *read {
+                if let Some(ref token) = self.core.token()? {
                     request_builder = request_builder.headers(token.into());
                 }
 
-                Ok(request_builder.build()?)
+                for (name, value) in self.core.headers.iter() {
+                    request_builder = request_builder.header(name.as_ref(), value.as_ref());
+                }
+
+                for (key, value) in self.core.queries.iter() {
+                    request_builder = request_builder.query(&[(key.as_ref(), value.as_ref())]);
+                }
+
+                request_builder.build().map_err(crate::Error::RequestBuild)
             }
 
-            async fn get_response(&self) -> crate::Result<reqwest::Response> {
-                use crate::error::CheckStatus;
+            /// Build the request without sending it, to inspect it or send it some other way.
+            pub fn into_request_parts(
+                self,
+            ) -> crate::Result<(
+                reqwest::Method,
+                reqwest::Url,
+                reqwest::header::HeaderMap,
+                Option<reqwest::Body>,
+            )> {
+                let mut request = self.get_request()?;
+                let body = request.body_mut().take();
+
+                Ok((
+                    request.method().clone(),
+                    request.url().clone(),
+                    std::mem::take(request.headers_mut()),
+                    body,
+                ))
+            }
 
+            /// Send the request and return the raw response, failing on an error status.
+            pub async fn get_response(&self) -> crate::Result<reqwest::Response> {
                 let request = self.get_request()?;
-                let response = self.core.client.execute(request).await?;
 
-                response.check_status().await
+                #[cfg(feature = "tracing")]
+                let response = {
+                    let span = tracing::info_span!(
+                        #span_name,
+                        method = %request.method(),
+                        path = request.url().path(),
+                        status = tracing::field::Empty,
+                        transaction_id = tracing::field::Empty,
+                    );
+
+                    self.core.execute_in_span(request, span).await
+                };
+
+                #[cfg(not(feature = "tracing"))]
+                let response = self.core.execute(request).await;
+
+                #response_builder
+
+                response
             }
         }
     }
@@ -181,12 +278,12 @@ impl EndpointField {
                 builder_fn: Some(builder_fn),
                 ..
             } => Some(quote! {
-                let #ident = urlencoding::encode(&#builder_fn(self).to_string()).into_owned();
+                let #ident = crate::utils::encode_path(&#builder_fn(self).to_string());
             }),
             EndpointField {
                 ident: Some(ident), ..
             } => Some(quote! {
-                let #ident = urlencoding::encode(&self.#ident.to_string()).into_owned();
+                let #ident = crate::utils::encode_path(&self.#ident.to_string());
             }),
             _ => None,
         }
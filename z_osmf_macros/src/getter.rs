@@ -50,6 +50,8 @@ pub(crate) struct GetterField {
     skip: bool,
     #[darling(default)]
     copy: bool,
+    #[darling(default)]
+    iter: bool,
 }
 
 impl GetterField {
@@ -66,6 +68,47 @@ impl GetterField {
                     self.#ident
                 }
             }),
+            GetterField {
+                iter: true,
+                ident: Some(ident),
+                ty,
+                ..
+            } => {
+                let (inner_ty, optional) = match extract_optional_type(ty) {
+                    Some(inner_ty) => (inner_ty, true),
+                    None => (ty.clone(), false),
+                };
+                let item_ty = match extract_pointer_type(&inner_ty)
+                    .unwrap_or_else(|| vec_to_slice_type(&inner_ty))
+                {
+                    syn::Type::Slice(slice) => slice.elem,
+                    _ => {
+                        return Some(
+                            syn::Error::new_spanned(
+                                ty,
+                                "`#[getter(iter)]` requires an `Arc<[T]>` or `Vec<T>` field",
+                            )
+                            .to_compile_error(),
+                        )
+                    }
+                };
+
+                let body = if optional {
+                    quote! {
+                        self.#ident.iter().flat_map(|items| items.iter())
+                    }
+                } else {
+                    quote! {
+                        self.#ident.iter()
+                    }
+                };
+
+                Some(quote! {
+                    pub fn #ident(&self) -> impl Iterator<Item = &#item_ty> + '_ {
+                        #body
+                    }
+                })
+            }
             GetterField {
                 ident: Some(ident),
                 ty,